@@ -0,0 +1,472 @@
+use crate::config::app_config::AppConfig;
+use crate::handler::auth_handler::authenticate_handler::AuthenticateHandlerImpl;
+use crate::handler::health_handler::get_liveness_handler::GetLivenessHandlerImpl;
+use crate::handler::health_handler::get_readiness_handler::GetReadinessHandlerImpl;
+use crate::handler::oauth_handler::create_oauth_client_handler::CreateOAuthClientHandlerImpl;
+use crate::handler::oauth_handler::introspect_token_handler::IntrospectTokenHandlerImpl;
+use crate::handler::oauth_handler::issue_token_handler::IssueTokenHandlerImpl;
+use crate::handler::oidc_handler::finish_oidc_login_handler::FinishOidcLoginHandlerImpl;
+use crate::handler::oidc_handler::start_oidc_login_handler::StartOidcLoginHandlerImpl;
+use crate::handler::passkey_handler::finish_passkey_authentication_handler::FinishPasskeyAuthenticationHandlerImpl;
+use crate::handler::passkey_handler::finish_passkey_registration_handler::FinishPasskeyRegistrationHandlerImpl;
+use crate::handler::passkey_handler::start_passkey_authentication_handler::StartPasskeyAuthenticationHandlerImpl;
+use crate::handler::passkey_handler::start_passkey_registration_handler::StartPasskeyRegistrationHandlerImpl;
+use crate::handler::personal_access_token_handler::create_personal_access_token_handler::CreatePersonalAccessTokenHandlerImpl;
+use crate::handler::personal_access_token_handler::list_personal_access_tokens_handler::ListPersonalAccessTokensHandlerImpl;
+use crate::handler::personal_access_token_handler::revoke_personal_access_token_handler::RevokePersonalAccessTokenHandlerImpl;
+use crate::handler::token_handler::get_jwks_handler::GetJwksHandlerImpl;
+use crate::handler::user_handler::create_user_handler::CreateUserHandlerImpl;
+use crate::handler::user_handler::change_user_status_handler::ChangeUserStatusHandlerImpl;
+use crate::handler::user_handler::delete_user_handler::DeleteUserHandlerImpl;
+use crate::handler::user_handler::get_current_user_handler::GetCurrentUserHandlerImpl;
+use crate::handler::user_handler::get_user_by_id_handler::GetUserByIdHandlerImpl;
+use crate::handler::user_handler::get_user_status_history_handler::GetUserStatusHistoryHandlerImpl;
+use crate::handler::user_handler::list_users_handler::ListUsersHandlerImpl;
+use crate::handler::user_handler::purge_deleted_users_handler::PurgeDeletedUsersHandlerImpl;
+use crate::handler::user_handler::restore_user_handler::RestoreUserHandlerImpl;
+use crate::handler::user_handler::update_user_handler::UpdateUserHandlerImpl;
+use crate::repository::oauth_client_repository::in_memory_oauth_client_repository::InMemoryOAuthClientRepository;
+use crate::repository::oidc_authorization_repository::in_memory_oidc_authorization_repository::InMemoryOidcAuthorizationRepository;
+use crate::repository::oidc_identity_repository::in_memory_oidc_identity_repository::InMemoryOidcIdentityRepository;
+use crate::repository::passkey_repository::in_memory_passkey_repository::InMemoryPasskeyRepository;
+use crate::repository::personal_access_token_repository::in_memory_personal_access_token_repository::InMemoryPersonalAccessTokenRepository;
+use crate::repository::user_repository::in_memory_user_repository::InMemoryUserRepository;
+use crate::repository::user_status_history_repository::in_memory_user_status_history_repository::InMemoryUserStatusHistoryRepository;
+use crate::repository::webauthn_challenge_repository::in_memory_webauthn_challenge_repository::InMemoryWebauthnChallengeRepository;
+use crate::model::user_model::UserPublic;
+use crate::router::AppState;
+use crate::service::auth_service::authenticate_service::AuthenticateServiceImpl;
+use crate::service::oauth_service::create_oauth_client_service::CreateOAuthClientServiceImpl;
+use crate::service::oauth_service::introspect_token_service::IntrospectTokenServiceImpl;
+use crate::service::oauth_service::issue_client_credentials_token_service::IssueClientCredentialsTokenServiceImpl;
+use crate::service::oidc_service::finish_oidc_login_service::FinishOidcLoginServiceImpl;
+use crate::service::oidc_service::start_oidc_login_service::StartOidcLoginServiceImpl;
+use crate::service::passkey_service::finish_passkey_authentication_service::FinishPasskeyAuthenticationServiceImpl;
+use crate::service::passkey_service::finish_passkey_registration_service::FinishPasskeyRegistrationServiceImpl;
+use crate::service::passkey_service::start_passkey_authentication_service::StartPasskeyAuthenticationServiceImpl;
+use crate::service::passkey_service::start_passkey_registration_service::StartPasskeyRegistrationServiceImpl;
+use crate::service::personal_access_token_service::create_personal_access_token_service::CreatePersonalAccessTokenServiceImpl;
+use crate::service::personal_access_token_service::list_personal_access_tokens_service::ListPersonalAccessTokensServiceImpl;
+use crate::service::personal_access_token_service::revoke_personal_access_token_service::RevokePersonalAccessTokenServiceImpl;
+use crate::service::health_service::check_readiness_service::CheckReadinessServiceImpl;
+use crate::service::token_service::get_jwks_service::GetJwksServiceImpl;
+use crate::service::user_service::bootstrap_admin_service::{BootstrapAdminService, BootstrapAdminServiceImpl};
+use crate::service::user_service::create_user_service::CreateUserServiceImpl;
+use crate::service::user_service::change_user_status_service::ChangeUserStatusServiceImpl;
+use crate::service::user_service::delete_user_service::DeleteUserServiceImpl;
+use crate::service::user_service::get_current_user_service::GetCurrentUserServiceImpl;
+use crate::service::user_service::get_user_by_id_service::GetUserByIdServiceImpl;
+use crate::service::user_service::get_user_status_history_service::GetUserStatusHistoryServiceImpl;
+use crate::service::user_service::list_users_service::ListUsersServiceImpl;
+use crate::service::user_service::purge_deleted_users_service::PurgeDeletedUsersServiceImpl;
+use crate::service::user_service::restore_user_service::RestoreUserServiceImpl;
+use crate::service::user_service::set_user_role_service::SetUserRoleServiceImpl;
+use crate::service::user_service::update_user_service::UpdateUserServiceImpl;
+use crate::util::clock::SystemClock;
+use crate::util::encryptor::BcryptEncryptor;
+use crate::util::health_check::{ShutdownHealthCheck, SigningKeyHealthCheck, UserStoreHealthCheck};
+use crate::util::key_ring::KeyRing;
+use crate::util::metrics::prometheus_handle;
+use crate::util::oidc::ReqwestOidcClient;
+use crate::util::shutdown::ShutdownSignal;
+use crate::util::app_error::AppError;
+use crate::util::token_generator::SecureTokenGenerator;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// The in-memory stores behind the app. Shared so the CLI, the admin bootstrap and tests act
+/// on the same data the router serves.
+#[derive(Clone, Default)]
+pub struct Repositories {
+    pub user: InMemoryUserRepository,
+    pub user_status_history: InMemoryUserStatusHistoryRepository,
+    pub passkey: InMemoryPasskeyRepository,
+    pub webauthn_challenge: InMemoryWebauthnChallengeRepository,
+    pub oidc_authorization: InMemoryOidcAuthorizationRepository,
+    pub oidc_identity: InMemoryOidcIdentityRepository,
+    pub oauth_client: InMemoryOAuthClientRepository,
+    pub personal_access_token: InMemoryPersonalAccessTokenRepository,
+}
+
+fn encryptor(config: &AppConfig) -> BcryptEncryptor {
+    BcryptEncryptor {
+        cost: config.hashing.bcrypt_cost,
+    }
+}
+
+pub fn build_create_user_service(config: &AppConfig, repositories: &Repositories) -> CreateUserServiceImpl {
+    CreateUserServiceImpl {
+        create_user_repository: Box::new(repositories.user.clone()),
+        find_user_by_email_repository: Box::new(repositories.user.clone()),
+        find_deleted_user_by_email_repository: Box::new(repositories.user.clone()),
+        encryptor: Box::new(encryptor(config)),
+        uuid_generator: config.id_strategy.generator(),
+        retention_config: config.retention.clone(),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    }
+}
+
+pub fn build_set_user_role_service(config: &AppConfig, repositories: &Repositories) -> SetUserRoleServiceImpl {
+    SetUserRoleServiceImpl {
+        find_user_by_email_repository: Box::new(repositories.user.clone()),
+        update_user_repository: Box::new(repositories.user.clone()),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    }
+}
+
+pub fn build_bootstrap_admin_service(config: &AppConfig, repositories: &Repositories) -> BootstrapAdminServiceImpl {
+    BootstrapAdminServiceImpl {
+        find_user_by_email_repository: Box::new(repositories.user.clone()),
+        find_deleted_user_by_email_repository: Box::new(repositories.user.clone()),
+        create_user_service: Box::new(build_create_user_service(config, repositories)),
+        retention_config: config.retention.clone(),
+        email_config: config.email,
+    }
+}
+
+/// What `serve` did with `config.bootstrap` before accepting traffic.
+#[derive(Debug)]
+pub enum BootstrapAdminOutcome {
+    NotConfigured,
+    Created(UserPublic),
+    /// The email already belongs to an account, which was left as it is.
+    EmailTaken,
+}
+
+pub async fn bootstrap_admin(config: &AppConfig, repositories: &Repositories) -> Result<BootstrapAdminOutcome, AppError> {
+    let (Some(email), Some(password)) = (&config.bootstrap.admin_email, &config.bootstrap.admin_password) else {
+        return Ok(BootstrapAdminOutcome::NotConfigured);
+    };
+
+    let created = build_bootstrap_admin_service(config, repositories)
+        .execute(email.clone(), password.clone())
+        .await?;
+
+    Ok(match created {
+        Some(admin) => BootstrapAdminOutcome::Created(admin),
+        None => BootstrapAdminOutcome::EmailTaken,
+    })
+}
+
+/// Wires handlers, services and the in-memory repositories into the router state.
+pub fn build_app_state(config: &AppConfig, key_ring: KeyRing, repositories: &Repositories) -> AppState {
+    let user_repository = repositories.user.clone();
+    let user_status_history_repository = repositories.user_status_history.clone();
+    let passkey_repository = repositories.passkey.clone();
+    let webauthn_challenge_repository = repositories.webauthn_challenge.clone();
+    let oidc_authorization_repository = repositories.oidc_authorization.clone();
+    let oidc_identity_repository = repositories.oidc_identity.clone();
+    let oauth_client_repository = repositories.oauth_client.clone();
+    let personal_access_token_repository = repositories.personal_access_token.clone();
+    let http_client = reqwest::Client::new();
+    let encryptor = encryptor(config);
+
+    let create_user_service = build_create_user_service(config, repositories);
+
+    let start_passkey_registration_service = StartPasskeyRegistrationServiceImpl {
+        find_user_by_email_repository: Box::new(user_repository.clone()),
+        find_passkeys_by_user_id_repository: Box::new(passkey_repository.clone()),
+        create_webauthn_challenge_repository: Box::new(webauthn_challenge_repository.clone()),
+        encryptor: Box::new(encryptor),
+        uuid_generator: config.id_strategy.generator(),
+        token_generator: Box::new(SecureTokenGenerator),
+        webauthn_config: config.webauthn.clone(),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    };
+
+    let finish_passkey_registration_service = FinishPasskeyRegistrationServiceImpl {
+        take_webauthn_challenge_repository: Box::new(webauthn_challenge_repository.clone()),
+        find_passkey_by_id_repository: Box::new(passkey_repository.clone()),
+        create_passkey_repository: Box::new(passkey_repository.clone()),
+        webauthn_config: config.webauthn.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let start_passkey_authentication_service = StartPasskeyAuthenticationServiceImpl {
+        find_user_by_email_repository: Box::new(user_repository.clone()),
+        find_passkeys_by_user_id_repository: Box::new(passkey_repository.clone()),
+        create_webauthn_challenge_repository: Box::new(webauthn_challenge_repository.clone()),
+        uuid_generator: config.id_strategy.generator(),
+        token_generator: Box::new(SecureTokenGenerator),
+        webauthn_config: config.webauthn.clone(),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    };
+
+    let finish_passkey_authentication_service = FinishPasskeyAuthenticationServiceImpl {
+        take_webauthn_challenge_repository: Box::new(webauthn_challenge_repository.clone()),
+        find_passkey_by_id_repository: Box::new(passkey_repository.clone()),
+        update_passkey_repository: Box::new(passkey_repository.clone()),
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        token_issuer: Box::new(key_ring.clone()),
+        uuid_generator: config.id_strategy.generator(),
+        webauthn_config: config.webauthn.clone(),
+        token_config: config.token.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let start_oidc_login_service = StartOidcLoginServiceImpl {
+        create_oidc_authorization_repository: Box::new(oidc_authorization_repository.clone()),
+        oidc_client: Box::new(ReqwestOidcClient {
+            http_client: http_client.clone(),
+        }),
+        token_generator: Box::new(SecureTokenGenerator),
+        oidc_config: config.oidc.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let finish_oidc_login_service = FinishOidcLoginServiceImpl {
+        take_oidc_authorization_repository: Box::new(oidc_authorization_repository.clone()),
+        find_oidc_identity_by_subject_repository: Box::new(oidc_identity_repository.clone()),
+        create_oidc_identity_repository: Box::new(oidc_identity_repository.clone()),
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        find_user_by_email_repository: Box::new(user_repository.clone()),
+        create_user_repository: Box::new(user_repository.clone()),
+        oidc_client: Box::new(ReqwestOidcClient { http_client }),
+        encryptor: Box::new(encryptor),
+        uuid_generator: config.id_strategy.generator(),
+        token_generator: Box::new(SecureTokenGenerator),
+        token_issuer: Box::new(key_ring.clone()),
+        oidc_config: config.oidc.clone(),
+        token_config: config.token.clone(),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    };
+
+    let create_oauth_client_service = CreateOAuthClientServiceImpl {
+        create_oauth_client_repository: Box::new(oauth_client_repository.clone()),
+        encryptor: Box::new(encryptor),
+        uuid_generator: config.id_strategy.generator(),
+        token_generator: Box::new(SecureTokenGenerator),
+        clock: Box::new(SystemClock),
+    };
+
+    let issue_client_credentials_token_service = IssueClientCredentialsTokenServiceImpl {
+        find_oauth_client_by_id_repository: Box::new(oauth_client_repository.clone()),
+        encryptor: Box::new(encryptor),
+        uuid_generator: config.id_strategy.generator(),
+        token_issuer: Box::new(key_ring.clone()),
+        token_config: config.token.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let introspect_token_service = IntrospectTokenServiceImpl {
+        find_oauth_client_by_id_repository: Box::new(oauth_client_repository.clone()),
+        encryptor: Box::new(encryptor),
+        token_issuer: Box::new(key_ring.clone()),
+    };
+
+    let get_jwks_service = GetJwksServiceImpl {
+        token_issuer: Box::new(key_ring.clone()),
+    };
+
+    let shutdown = ShutdownSignal::default();
+
+    let check_readiness_service = CheckReadinessServiceImpl {
+        health_checks: vec![
+            Arc::new(ShutdownHealthCheck {
+                shutdown: shutdown.clone(),
+            }),
+            Arc::new(SigningKeyHealthCheck {
+                key_ring: key_ring.clone(),
+            }),
+            Arc::new(UserStoreHealthCheck {
+                find_user_by_id_repository: Box::new(user_repository.clone()),
+            }),
+        ],
+        timeout: Duration::from_millis(config.health.check_timeout_ms),
+    };
+
+    let authenticate_service = AuthenticateServiceImpl {
+        find_personal_access_token_by_hash_repository: Box::new(personal_access_token_repository.clone()),
+        update_personal_access_token_repository: Box::new(personal_access_token_repository.clone()),
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        token_issuer: Box::new(key_ring),
+        clock: Box::new(SystemClock),
+    };
+
+    let get_current_user_service = GetCurrentUserServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+    };
+
+    let get_user_by_id_service = GetUserByIdServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+    };
+
+    let list_users_service = ListUsersServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        list_users_repository: Box::new(user_repository.clone()),
+    };
+
+    let update_user_service = UpdateUserServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        find_user_by_email_repository: Box::new(user_repository.clone()),
+        find_deleted_user_by_email_repository: Box::new(user_repository.clone()),
+        update_user_repository: Box::new(user_repository.clone()),
+        encryptor: Box::new(encryptor),
+        retention_config: config.retention.clone(),
+        email_config: config.email,
+        clock: Box::new(SystemClock),
+    };
+
+    let delete_user_service = DeleteUserServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        soft_delete_user_repository: Box::new(user_repository.clone()),
+        clock: Box::new(SystemClock),
+    };
+
+    let restore_user_service = RestoreUserServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        find_deleted_user_by_id_repository: Box::new(user_repository.clone()),
+        find_user_by_email_repository: Box::new(user_repository.clone()),
+        update_user_repository: Box::new(user_repository.clone()),
+        retention_config: config.retention.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let purge_deleted_users_service = PurgeDeletedUsersServiceImpl {
+        find_users_to_purge_repository: Box::new(user_repository.clone()),
+        purge_deleted_user_repository: Box::new(user_repository.clone()),
+        delete_passkeys_by_user_id_repository: Box::new(passkey_repository.clone()),
+        delete_personal_access_tokens_by_user_id_repository: Box::new(personal_access_token_repository.clone()),
+        delete_oidc_identities_by_user_id_repository: Box::new(oidc_identity_repository.clone()),
+        retention_config: config.retention.clone(),
+        clock: Box::new(SystemClock),
+    };
+
+    let change_user_status_service = ChangeUserStatusServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        update_user_repository: Box::new(user_repository.clone()),
+        create_user_status_change_repository: Box::new(user_status_history_repository.clone()),
+        uuid_generator: config.id_strategy.generator(),
+        clock: Box::new(SystemClock),
+    };
+
+    let get_user_status_history_service = GetUserStatusHistoryServiceImpl {
+        find_user_by_id_repository: Box::new(user_repository.clone()),
+        find_user_status_changes_by_user_id_repository: Box::new(user_status_history_repository),
+    };
+
+    let create_personal_access_token_service = CreatePersonalAccessTokenServiceImpl {
+        create_personal_access_token_repository: Box::new(personal_access_token_repository.clone()),
+        uuid_generator: config.id_strategy.generator(),
+        token_generator: Box::new(SecureTokenGenerator),
+        clock: Box::new(SystemClock),
+    };
+
+    let list_personal_access_tokens_service = ListPersonalAccessTokensServiceImpl {
+        find_personal_access_tokens_by_user_id_repository: Box::new(personal_access_token_repository.clone()),
+    };
+
+    let revoke_personal_access_token_service = RevokePersonalAccessTokenServiceImpl {
+        find_personal_access_token_by_id_repository: Box::new(personal_access_token_repository.clone()),
+        update_personal_access_token_repository: Box::new(personal_access_token_repository),
+        clock: Box::new(SystemClock),
+    };
+
+    AppState {
+        create_user_handler: Arc::new(CreateUserHandlerImpl {
+            create_user_service: Box::new(create_user_service),
+        }),
+        start_passkey_registration_handler: Arc::new(StartPasskeyRegistrationHandlerImpl {
+            start_passkey_registration_service: Box::new(start_passkey_registration_service),
+        }),
+        finish_passkey_registration_handler: Arc::new(FinishPasskeyRegistrationHandlerImpl {
+            finish_passkey_registration_service: Box::new(finish_passkey_registration_service),
+        }),
+        start_passkey_authentication_handler: Arc::new(StartPasskeyAuthenticationHandlerImpl {
+            start_passkey_authentication_service: Box::new(start_passkey_authentication_service),
+        }),
+        finish_passkey_authentication_handler: Arc::new(FinishPasskeyAuthenticationHandlerImpl {
+            finish_passkey_authentication_service: Box::new(finish_passkey_authentication_service),
+        }),
+        start_oidc_login_handler: Arc::new(StartOidcLoginHandlerImpl {
+            start_oidc_login_service: Box::new(start_oidc_login_service),
+        }),
+        finish_oidc_login_handler: Arc::new(FinishOidcLoginHandlerImpl {
+            finish_oidc_login_service: Box::new(finish_oidc_login_service),
+        }),
+        create_oauth_client_handler: Arc::new(CreateOAuthClientHandlerImpl {
+            create_oauth_client_service: Box::new(create_oauth_client_service),
+        }),
+        issue_token_handler: Arc::new(IssueTokenHandlerImpl {
+            issue_client_credentials_token_service: Box::new(issue_client_credentials_token_service),
+        }),
+        introspect_token_handler: Arc::new(IntrospectTokenHandlerImpl {
+            introspect_token_service: Box::new(introspect_token_service),
+        }),
+        get_jwks_handler: Arc::new(GetJwksHandlerImpl {
+            get_jwks_service: Box::new(get_jwks_service),
+        }),
+        get_liveness_handler: Arc::new(GetLivenessHandlerImpl),
+        get_readiness_handler: Arc::new(GetReadinessHandlerImpl {
+            check_readiness_service: Box::new(check_readiness_service),
+        }),
+        authenticate_handler: Arc::new(AuthenticateHandlerImpl {
+            authenticate_service: Box::new(authenticate_service),
+        }),
+        get_current_user_handler: Arc::new(GetCurrentUserHandlerImpl {
+            get_current_user_service: Box::new(get_current_user_service),
+        }),
+        get_user_by_id_handler: Arc::new(GetUserByIdHandlerImpl {
+            get_user_by_id_service: Box::new(get_user_by_id_service),
+        }),
+        list_users_handler: Arc::new(ListUsersHandlerImpl {
+            list_users_service: Box::new(list_users_service),
+        }),
+        update_user_handler: Arc::new(UpdateUserHandlerImpl {
+            update_user_service: Box::new(update_user_service),
+        }),
+        delete_user_handler: Arc::new(DeleteUserHandlerImpl {
+            delete_user_service: Box::new(delete_user_service),
+        }),
+        restore_user_handler: Arc::new(RestoreUserHandlerImpl {
+            restore_user_service: Box::new(restore_user_service),
+        }),
+        purge_deleted_users_handler: Arc::new(PurgeDeletedUsersHandlerImpl {
+            purge_deleted_users_service: Box::new(purge_deleted_users_service),
+        }),
+        change_user_status_handler: Arc::new(ChangeUserStatusHandlerImpl {
+            change_user_status_service: Box::new(change_user_status_service),
+        }),
+        get_user_status_history_handler: Arc::new(GetUserStatusHistoryHandlerImpl {
+            get_user_status_history_service: Box::new(get_user_status_history_service),
+        }),
+        create_personal_access_token_handler: Arc::new(CreatePersonalAccessTokenHandlerImpl {
+            create_personal_access_token_service: Box::new(create_personal_access_token_service),
+        }),
+        list_personal_access_tokens_handler: Arc::new(ListPersonalAccessTokensHandlerImpl {
+            list_personal_access_tokens_service: Box::new(list_personal_access_tokens_service),
+        }),
+        revoke_personal_access_token_handler: Arc::new(RevokePersonalAccessTokenHandlerImpl {
+            revoke_personal_access_token_service: Box::new(revoke_personal_access_token_service),
+        }),
+        api_key: config.api_key.clone(),
+        request_id_generator: Arc::from(config.id_strategy.generator()),
+        metrics_handle: prometheus_handle(),
+        shutdown,
+        deprecations: Arc::new(config.api.deprecations.clone()),
+        docs_ui_enabled: config.docs.ui_enabled,
+        secure_cookies: config.oidc.redirect_uri.starts_with("https://"),
+    }
+}
+
+/// Runs the purge of soft-deleted users in the background, like the signing key rotation.
+pub fn spawn_user_purge(state: &AppState, interval_seconds: u64) -> JoinHandle<()> {
+    let purge_deleted_users_handler = state.purge_deleted_users_handler.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_seconds));
+
+        loop {
+            interval.tick().await;
+
+            // Failures are already logged by AppError::server_error; the next tick retries.
+            let _ = purge_deleted_users_handler.execute().await;
+        }
+    })
+}
@@ -0,0 +1,19 @@
+use crate::cli::MigrateAction;
+use crate::config::app_config::AppConfig;
+
+/// The only store today is in-memory and starts empty on every run, so there is never a
+/// schema to migrate. The commands exist so deploy scripts can call them unconditionally
+/// and keep working once a database-backed store lands.
+pub fn run(action: &MigrateAction, config: &AppConfig) -> String {
+    let report = match action {
+        MigrateAction::Up => "in-memory store: nothing to apply",
+        MigrateAction::Down => "in-memory store: nothing to revert",
+        MigrateAction::Status => "in-memory store: 0 applied, 0 pending",
+    };
+
+    if config.database.url.is_some() {
+        format!("{} (DATABASE_URL is set but not used yet)", report)
+    } else {
+        report.to_string()
+    }
+}
@@ -0,0 +1,86 @@
+pub mod migrate;
+pub mod password;
+pub mod serve;
+pub mod user_command;
+
+use crate::bootstrap::Repositories;
+use crate::config::app_config::AppConfig;
+use crate::model::email_model::Email;
+use crate::model::user_model::UserRole;
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "axum-api-starter server and operator tools")]
+pub struct Cli {
+    /// Defaults to `serve`.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start the HTTP server, creating the bootstrap admin first when one is configured.
+    Serve,
+    /// Apply, revert or inspect storage migrations.
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+    /// Manage users without going through the HTTP API.
+    User {
+        #[command(subcommand)]
+        command: UserCommand,
+    },
+    /// Prompt for a password and print its bcrypt hash with the configured cost.
+    HashPassword,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateAction {
+    /// Apply pending migrations.
+    Up,
+    /// Revert the last applied migration.
+    Down,
+    /// List applied and pending migrations.
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UserCommand {
+    /// Create an admin account. The password is prompted for, never passed as an argument.
+    CreateAdmin {
+        #[arg(long, value_parser = Email::parse)]
+        email: Email,
+    },
+    /// Change the role of an existing account.
+    SetRole {
+        #[arg(long, value_parser = Email::parse)]
+        email: Email,
+        /// `admin` or `user`.
+        #[arg(long, value_parser = parse_role)]
+        role: UserRole,
+    },
+}
+
+fn parse_role(value: &str) -> Result<UserRole, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "admin" => Ok(UserRole::Admin),
+        "user" => Ok(UserRole::User),
+        _ => Err("expected admin or user".to_string()),
+    }
+}
+
+/// Runs the selected command; the error is the message to print before exiting non-zero.
+pub async fn run(cli: Cli, config: AppConfig) -> Result<(), String> {
+    let repositories = Repositories::default();
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve::run(config, repositories).await,
+        Command::Migrate { action } => {
+            println!("{}", migrate::run(&action, &config));
+            Ok(())
+        }
+        Command::User { command } => user_command::run(command, &config, &repositories).await,
+        Command::HashPassword => password::hash_password(&config),
+    }
+}
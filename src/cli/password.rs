@@ -0,0 +1,40 @@
+use crate::config::app_config::AppConfig;
+use crate::util::encryptor::{BcryptEncryptor, Encryptor};
+use crate::util::secret::Secret;
+
+/// Asks twice without echoing, so a typo cannot lock the operator out of a fresh account.
+pub fn prompt_new_password() -> Result<Secret<String>, String> {
+    let password =
+        Secret::new(rpassword::prompt_password("Password: ").map_err(|e| e.to_string())?);
+    let confirmation =
+        Secret::new(rpassword::prompt_password("Confirm password: ").map_err(|e| e.to_string())?);
+
+    check_new_password(&password, &confirmation)?;
+    Ok(password)
+}
+
+pub fn check_new_password(
+    password: &Secret<String>,
+    confirmation: &Secret<String>,
+) -> Result<(), String> {
+    if password.expose().is_empty() {
+        return Err("Password must not be empty".to_string());
+    }
+
+    if password.expose() != confirmation.expose() {
+        return Err("Passwords do not match".to_string());
+    }
+
+    Ok(())
+}
+
+pub fn hash_password(config: &AppConfig) -> Result<(), String> {
+    let password = prompt_new_password()?;
+    let hash = BcryptEncryptor {
+        cost: config.hashing.bcrypt_cost,
+    }
+    .encrypt(password.expose())?;
+
+    println!("{}", hash);
+    Ok(())
+}
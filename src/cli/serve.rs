@@ -0,0 +1,163 @@
+use crate::bootstrap::{BootstrapAdminOutcome, Repositories, bootstrap_admin, build_app_state, spawn_user_purge};
+use crate::config::app_config::AppConfig;
+use crate::router::create_router;
+use crate::util::clock::SystemClock;
+use crate::util::key_ring::KeyRing;
+use crate::util::shutdown::ShutdownSignal;
+use axum::Router;
+use std::future::{Future, IntoFuture};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+const USER_PURGE_INTERVAL_SECONDS: u64 = 3600;
+
+pub async fn run(config: AppConfig, repositories: Repositories) -> Result<(), String> {
+    if config.token.signing_secret.is_none() {
+        tracing::warn!(
+            "SIGNING_KEY_SECRET not set; signing keys are random per process, so tokens do not survive a restart and replicas reject each other's tokens"
+        );
+    }
+
+    match bootstrap_admin(&config, &repositories)
+        .await
+        .map_err(|e| format!("failed to bootstrap the admin account: {}", e.detail))?
+    {
+        BootstrapAdminOutcome::NotConfigured => {}
+        BootstrapAdminOutcome::Created(admin) => {
+            tracing::info!(user_id = %admin.id, "bootstrap admin created");
+        }
+        BootstrapAdminOutcome::EmailTaken => {
+            tracing::warn!("BOOTSTRAP_ADMIN_EMAIL already belongs to an account; left it unchanged");
+        }
+    }
+
+    let key_ring = KeyRing::new(config.token.clone(), SystemClock)
+        .map_err(|e| format!("failed to create signing keys: {}", e))?;
+    let rotation = key_ring.spawn_rotation();
+
+    let state = build_app_state(&config, key_ring, &repositories);
+    let purge = spawn_user_purge(&state, USER_PURGE_INTERVAL_SECONDS);
+    let shutdown = state.shutdown.clone();
+
+    let app = create_router(state);
+
+    let address = config.server.address();
+    let listener = TcpListener::bind(address)
+        .await
+        .map_err(|e| format!("failed to bind {}: {}", address, e))?;
+    tracing::info!(profile = config.profile.as_str(), %address, "listening");
+
+    let result = serve(
+        listener,
+        app,
+        shutdown,
+        ShutdownTimings {
+            pre_drain_delay: Duration::from_secs(config.server.shutdown_delay_seconds),
+            drain_timeout: Duration::from_secs(config.server.shutdown_timeout_seconds),
+        },
+        termination_signal(),
+    )
+    .await;
+
+    rotation.abort();
+    purge.abort();
+    // Repositories are in-memory and hold no connections; a pooled backend closes here,
+    // after the last request has finished with it. Traces are flushed by the caller.
+    drop(repositories);
+
+    result
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ShutdownTimings {
+    /// Time between failing readiness and closing the listener.
+    pub pre_drain_delay: Duration,
+    /// Time in-flight requests get once the listener is closed.
+    pub drain_timeout: Duration,
+}
+
+/// Serves `app` until `signal` resolves, then fails readiness while still serving for
+/// `pre_drain_delay`, stops accepting connections and waits up to `drain_timeout` for
+/// in-flight requests before giving up on them.
+pub async fn serve(
+    listener: TcpListener,
+    app: Router,
+    shutdown: ShutdownSignal,
+    timings: ShutdownTimings,
+    signal: impl Future<Output = ()>,
+) -> Result<(), String> {
+    let (stop_accepting, stopped) = oneshot::channel::<()>();
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            let _ = stopped.await;
+        })
+        .into_future();
+    let mut server = std::pin::pin!(server);
+
+    tokio::select! {
+        result = &mut server => return result.map_err(|e| e.to_string()),
+        _ = signal => {}
+    }
+
+    // Requests keep being served while load balancers notice the failed readiness check,
+    // so none are sent to a listener that is already closed.
+    tracing::info!(
+        pre_drain_delay_ms = timings.pre_drain_delay.as_millis() as u64,
+        "shutting down, failing readiness"
+    );
+    shutdown.begin();
+
+    tokio::select! {
+        result = &mut server => return result.map_err(|e| e.to_string()),
+        _ = tokio::time::sleep(timings.pre_drain_delay) => {}
+    }
+
+    tracing::info!(
+        drain_timeout_ms = timings.drain_timeout.as_millis() as u64,
+        "closing the listener, draining in-flight requests"
+    );
+    let _ = stop_accepting.send(());
+
+    match tokio::time::timeout(timings.drain_timeout, server).await {
+        Ok(result) => {
+            tracing::info!("all requests drained");
+            result.map_err(|e| e.to_string())
+        }
+        Err(_) => {
+            tracing::warn!("drain deadline reached, dropping remaining connections");
+            Ok(())
+        }
+    }
+}
+
+/// Resolves on SIGINT (Ctrl+C) or, on Unix, SIGTERM as sent by orchestrators.
+async fn termination_signal() {
+    let interrupt = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            tracing::error!("failed to listen for SIGINT: {}", err);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(err) => {
+                tracing::error!("failed to listen for SIGTERM: {}", err);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = interrupt => {}
+        _ = terminate => {}
+    }
+}
@@ -0,0 +1,62 @@
+use crate::bootstrap::{Repositories, build_create_user_service, build_set_user_role_service};
+use crate::cli::UserCommand;
+use crate::cli::password::prompt_new_password;
+use crate::config::app_config::AppConfig;
+use crate::model::email_model::Email;
+use crate::model::user_model::{UserForCreation, UserPublic, UserRole};
+use crate::service::user_service::create_user_service::CreateUserService;
+use crate::service::user_service::set_user_role_service::SetUserRoleService;
+use crate::util::app_error::AppError;
+use crate::util::secret::Secret;
+
+pub async fn run(
+    command: UserCommand,
+    config: &AppConfig,
+    repositories: &Repositories,
+) -> Result<(), String> {
+    eprintln!(
+        "warning: users are kept in memory, so this change is lost when the command exits; \
+         set BOOTSTRAP_ADMIN_EMAIL and BOOTSTRAP_ADMIN_PASSWORD to create the first admin of `serve`"
+    );
+
+    match command {
+        UserCommand::CreateAdmin { email } => {
+            let password = prompt_new_password()?;
+            let admin = create_admin(
+                &build_create_user_service(config, repositories),
+                email,
+                password,
+            )
+            .await
+            .map_err(|e| e.detail)?;
+
+            println!("Created admin {} ({})", admin.email, admin.id);
+        }
+        UserCommand::SetRole { email, role } => {
+            let user = build_set_user_role_service(config, repositories)
+                .execute(email, role)
+                .await
+                .map_err(|e| e.detail)?;
+
+            println!("{} now has role {:?}", user.email, user.role);
+        }
+    }
+
+    Ok(())
+}
+
+/// Goes through the regular signup service, so admins get the same email checks as
+/// everyone else; only the role differs, which the HTTP signup refuses.
+pub async fn create_admin(
+    create_user_service: &(dyn CreateUserService + Send + Sync),
+    email: Email,
+    password: Secret<String>,
+) -> Result<UserPublic, AppError> {
+    create_user_service
+        .execute(UserForCreation {
+            email,
+            password,
+            role: UserRole::Admin,
+        })
+        .await
+}
@@ -0,0 +1,463 @@
+use crate::config::config_error::ConfigError;
+use crate::config::source::{ConfigSources, SETTINGS};
+use crate::model::email_model::{Email, EmailConfig};
+use crate::util::deprecation::{Deprecation, parse_deprecations};
+use crate::util::oidc::OidcConfig;
+use crate::util::retention::{EmailReusePolicy, RetentionConfig};
+use crate::util::secret::Secret;
+use crate::util::token_issuer::TokenConfig;
+use crate::util::uuid_generator::IdStrategy;
+use crate::util::webauthn::WebauthnConfig;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use tracing::Level;
+
+/// Named set of defaults. The profile only changes defaults and validation rules; any
+/// setting can still be overridden explicitly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Profile {
+    Dev,
+    Test,
+    Prod,
+}
+
+impl Profile {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "dev" => Some(Profile::Dev),
+            "test" => Some(Profile::Test),
+            "prod" => Some(Profile::Prod),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub host: IpAddr,
+    pub port: u16,
+    /// How long readiness reports down after SIGINT/SIGTERM while connections are still
+    /// accepted, so load balancers stop routing here before the listener closes.
+    pub shutdown_delay_seconds: u64,
+    /// How long in-flight requests may take to finish once the listener is closed.
+    pub shutdown_timeout_seconds: u64,
+}
+
+impl ServerConfig {
+    pub fn address(&self) -> SocketAddr {
+        SocketAddr::new(self.host, self.port)
+    }
+}
+
+/// How log lines are rendered: `Text` for people reading a terminal, `Json` for a log
+/// pipeline that indexes span fields.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(LogFormat::Text),
+            "json" => Some(LogFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogConfig {
+    pub level: Level,
+    pub format: LogFormat,
+}
+
+/// Trace export over OTLP/HTTP. The variable names are the standard OpenTelemetry ones, so
+/// platform defaults injected into the environment apply as they do for other services.
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// Base URL of the collector, e.g. `http://collector:4318`. Export is off when unset.
+    pub otlp_endpoint: Option<String>,
+    /// Share of new traces that are recorded, from 0.0 to 1.0. Requests that arrive with a
+    /// `traceparent` follow the caller's sampling decision instead.
+    pub sample_ratio: f64,
+    pub service_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct HealthConfig {
+    /// Time each readiness check gets before it is reported down.
+    pub check_timeout_ms: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ApiConfig {
+    /// Versions or routes answered with `Deprecation`/`Sunset` headers.
+    pub deprecations: Vec<Deprecation>,
+}
+
+/// `/openapi.json` is always served; the Swagger UI page at `/docs` is optional.
+#[derive(Debug, Clone)]
+pub struct DocsConfig {
+    pub ui_enabled: bool,
+}
+
+/// Connection settings for a persistent store. The repositories are still in-memory, so
+/// nothing connects yet; the values are only validated.
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+    /// Usually carries credentials, hence a secret.
+    pub url: Option<Secret<String>>,
+    pub max_connections: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct HashingConfig {
+    pub bcrypt_cost: u32,
+}
+
+/// Admin account `serve` creates before accepting traffic when no account holds the email,
+/// so a fresh store has someone who can manage it. Both fields are set together or not at all.
+#[derive(Debug, Clone, Default)]
+pub struct BootstrapConfig {
+    pub admin_email: Option<Email>,
+    pub admin_password: Option<Secret<String>>,
+}
+
+/// Everything the application reads at startup. Built by `load` from, in increasing
+/// precedence: profile defaults, the TOML config file, `.env` and environment variables.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub profile: Profile,
+    pub server: ServerConfig,
+    pub log: LogConfig,
+    pub telemetry: TelemetryConfig,
+    pub health: HealthConfig,
+    pub api: ApiConfig,
+    pub docs: DocsConfig,
+    pub database: DatabaseConfig,
+    pub hashing: HashingConfig,
+    pub bootstrap: BootstrapConfig,
+    pub email: EmailConfig,
+    pub token: TokenConfig,
+    pub webauthn: WebauthnConfig,
+    pub oidc: OidcConfig,
+    pub retention: RetentionConfig,
+    pub id_strategy: IdStrategy,
+    pub api_key: Option<Secret<String>>,
+}
+
+impl AppConfig {
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::from_sources(&ConfigSources::from_process()?)
+    }
+
+    pub fn from_sources(sources: &ConfigSources) -> Result<Self, ConfigError> {
+        let (settings, mut errors) = sources.merge();
+
+        let profile = match settings.get("APP_PROFILE") {
+            Some(setting) => Profile::parse(&setting.value).unwrap_or_else(|| {
+                errors.push(format!(
+                    "APP_PROFILE={:?} from {}: expected dev, test or prod",
+                    setting.value, setting.source
+                ));
+                Profile::Dev
+            }),
+            None => Profile::Dev,
+        };
+
+        let mut config = Self::defaults(profile);
+
+        // Walk the table rather than the map so errors always come out in the same order.
+        for (_, key) in SETTINGS {
+            if let Some(setting) = settings.get(key)
+                && let Err(e) = config.apply(key, &setting.value)
+            {
+                errors.push(format!(
+                    "{}={:?} from {}: {}",
+                    key, setting.value, setting.source, e
+                ));
+            }
+        }
+
+        errors.extend(config.validate());
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError::new(errors))
+        }
+    }
+
+    pub fn defaults(profile: Profile) -> Self {
+        Self {
+            profile,
+            server: ServerConfig {
+                host: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                port: 3000,
+                // Orchestrators take a few seconds to notice failed readiness; locally
+                // there is nothing to wait for.
+                shutdown_delay_seconds: match profile {
+                    Profile::Prod => 5,
+                    Profile::Dev | Profile::Test => 0,
+                },
+                shutdown_timeout_seconds: 30,
+            },
+            log: LogConfig {
+                level: match profile {
+                    Profile::Dev => Level::DEBUG,
+                    Profile::Test => Level::WARN,
+                    Profile::Prod => Level::INFO,
+                },
+                format: match profile {
+                    Profile::Prod => LogFormat::Json,
+                    Profile::Dev | Profile::Test => LogFormat::Text,
+                },
+            },
+            telemetry: TelemetryConfig {
+                otlp_endpoint: None,
+                sample_ratio: 1.0,
+                service_name: "axum-api-starter".to_string(),
+            },
+            health: HealthConfig {
+                check_timeout_ms: 2000,
+            },
+            api: ApiConfig::default(),
+            docs: DocsConfig {
+                ui_enabled: profile != Profile::Prod,
+            },
+            database: DatabaseConfig {
+                url: None,
+                max_connections: 10,
+            },
+            hashing: HashingConfig {
+                // The minimum cost keeps test suites fast; it is rejected in prod.
+                bcrypt_cost: match profile {
+                    Profile::Dev => 10,
+                    Profile::Test => 4,
+                    Profile::Prod => bcrypt::DEFAULT_COST,
+                },
+            },
+            bootstrap: BootstrapConfig::default(),
+            email: EmailConfig::default(),
+            token: TokenConfig {
+                issuer: "axum-api-starter".to_string(),
+                access_token_ttl_seconds: 900,
+                signing_key_rotation_seconds: 86_400,
+                signing_secret: None,
+            },
+            webauthn: WebauthnConfig {
+                rp_id: "localhost".to_string(),
+                rp_name: "axum-api-starter".to_string(),
+                origin: "http://localhost:3000".to_string(),
+            },
+            oidc: OidcConfig {
+                issuer_url: "http://localhost:8080".to_string(),
+                client_id: "axum-api-starter".to_string(),
+                client_secret: None,
+                redirect_uri: "http://localhost:3000/v1/auth/oidc/callback".to_string(),
+                scopes: vec!["openid".to_string(), "email".to_string()],
+            },
+            retention: RetentionConfig {
+                restore_window_days: 30,
+                email_reuse: EmailReusePolicy::Immediate,
+            },
+            id_strategy: IdStrategy::UuidV7,
+            api_key: None,
+        }
+    }
+
+    fn apply(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "SERVER_HOST" => {
+                self.server.host = value
+                    .parse()
+                    .map_err(|_| "expected an IP address".to_string())?
+            }
+            "SERVER_PORT" => self.server.port = parse_number(value)?,
+            "SHUTDOWN_DELAY_SECONDS" => self.server.shutdown_delay_seconds = parse_number(value)?,
+            "SHUTDOWN_TIMEOUT_SECONDS" => {
+                self.server.shutdown_timeout_seconds = parse_number(value)?
+            }
+            "LOG_LEVEL" => {
+                self.log.level = value
+                    .parse()
+                    .map_err(|_| "expected trace, debug, info, warn or error".to_string())?
+            }
+            "LOG_FORMAT" => {
+                self.log.format =
+                    LogFormat::parse(value).ok_or_else(|| "expected text or json".to_string())?
+            }
+            "OTEL_EXPORTER_OTLP_ENDPOINT" => self.telemetry.otlp_endpoint = optional(value),
+            "OTEL_TRACES_SAMPLER_ARG" => {
+                self.telemetry.sample_ratio = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| "expected a number between 0.0 and 1.0".to_string())?
+            }
+            "OTEL_SERVICE_NAME" => self.telemetry.service_name = value.to_string(),
+            "HEALTH_CHECK_TIMEOUT_MS" => self.health.check_timeout_ms = parse_number(value)?,
+            "API_DEPRECATIONS" => self.api.deprecations = parse_deprecations(value)?,
+            "API_DOCS_UI_ENABLED" => self.docs.ui_enabled = parse_bool(value)?,
+            "DATABASE_URL" => self.database.url = optional(value).map(Secret::new),
+            "DATABASE_MAX_CONNECTIONS" => self.database.max_connections = parse_number(value)?,
+            "BCRYPT_COST" => self.hashing.bcrypt_cost = parse_number(value)?,
+            "BOOTSTRAP_ADMIN_EMAIL" => {
+                self.bootstrap.admin_email = optional(value).map(|email| Email::parse(&email)).transpose()?
+            }
+            "BOOTSTRAP_ADMIN_PASSWORD" => self.bootstrap.admin_password = optional(value).map(Secret::new),
+            "EMAIL_LOWERCASE_LOCAL_PART" => self.email.lowercase_local_part = parse_bool(value)?,
+            "TOKEN_ISSUER" => self.token.issuer = value.to_string(),
+            "ACCESS_TOKEN_TTL_SECONDS" => {
+                self.token.access_token_ttl_seconds = parse_number(value)?
+            }
+            "SIGNING_KEY_ROTATION_SECONDS" => {
+                self.token.signing_key_rotation_seconds = parse_number(value)?
+            }
+            "SIGNING_KEY_SECRET" => self.token.signing_secret = optional(value).map(Secret::new),
+            "WEBAUTHN_RP_ID" => self.webauthn.rp_id = value.to_string(),
+            "WEBAUTHN_RP_NAME" => self.webauthn.rp_name = value.to_string(),
+            "WEBAUTHN_ORIGIN" => self.webauthn.origin = value.to_string(),
+            "OIDC_ISSUER_URL" => self.oidc.issuer_url = value.to_string(),
+            "OIDC_CLIENT_ID" => self.oidc.client_id = value.to_string(),
+            "OIDC_CLIENT_SECRET" => self.oidc.client_secret = optional(value).map(Secret::new),
+            "OIDC_REDIRECT_URI" => self.oidc.redirect_uri = value.to_string(),
+            "USER_RESTORE_WINDOW_DAYS" => self.retention.restore_window_days = parse_number(value)?,
+            "DELETED_EMAIL_REUSE" => {
+                self.retention.email_reuse = EmailReusePolicy::parse(value)
+                    .ok_or_else(|| "expected immediate or after_purge".to_string())?
+            }
+            "ID_STRATEGY" => {
+                self.id_strategy = IdStrategy::parse(value)
+                    .ok_or_else(|| "expected uuid_v4, uuid_v7 or ulid".to_string())?
+            }
+            "API_KEY" => self.api_key = optional(value).map(Secret::new),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Rules that span settings or depend on the profile, checked after every layer applied.
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if !(4..=31).contains(&self.hashing.bcrypt_cost) {
+            errors.push("BCRYPT_COST must be between 4 and 31".to_string());
+        } else if self.profile == Profile::Prod && self.hashing.bcrypt_cost < 10 {
+            errors.push("BCRYPT_COST must be at least 10 in the prod profile".to_string());
+        }
+
+        if let Some(url) = &self.database.url
+            && !url.expose().contains("://")
+        {
+            errors.push("DATABASE_URL must be a URL such as postgres://host/db".to_string());
+        }
+
+        if !(0.0..=1.0).contains(&self.telemetry.sample_ratio) {
+            errors.push("OTEL_TRACES_SAMPLER_ARG must be between 0.0 and 1.0".to_string());
+        }
+
+        if let Some(endpoint) = &self.telemetry.otlp_endpoint
+            && !endpoint.starts_with("http://")
+            && !endpoint.starts_with("https://")
+        {
+            errors.push("OTEL_EXPORTER_OTLP_ENDPOINT must be an http or https URL".to_string());
+        }
+
+        if self.telemetry.service_name.trim().is_empty() {
+            errors.push("OTEL_SERVICE_NAME must not be empty".to_string());
+        }
+
+        if self.health.check_timeout_ms == 0 {
+            errors.push("HEALTH_CHECK_TIMEOUT_MS must be at least 1".to_string());
+        }
+
+        if self.database.max_connections == 0 {
+            errors.push("DATABASE_MAX_CONNECTIONS must be at least 1".to_string());
+        }
+
+        match (&self.bootstrap.admin_email, &self.bootstrap.admin_password) {
+            (Some(_), None) => errors.push(
+                "BOOTSTRAP_ADMIN_PASSWORD must be set together with BOOTSTRAP_ADMIN_EMAIL".to_string(),
+            ),
+            (None, Some(_)) => errors.push(
+                "BOOTSTRAP_ADMIN_EMAIL must be set together with BOOTSTRAP_ADMIN_PASSWORD".to_string(),
+            ),
+            _ => {}
+        }
+
+        if self.token.issuer.trim().is_empty() {
+            errors.push("TOKEN_ISSUER must not be empty".to_string());
+        }
+
+        if self.token.access_token_ttl_seconds <= 0 {
+            errors.push("ACCESS_TOKEN_TTL_SECONDS must be positive".to_string());
+        }
+
+        if self.token.signing_key_rotation_seconds <= 0 {
+            errors.push("SIGNING_KEY_ROTATION_SECONDS must be positive".to_string());
+        }
+
+        match &self.token.signing_secret {
+            Some(secret) if secret.expose().len() < 32 => {
+                errors.push("SIGNING_KEY_SECRET must be at least 32 characters".to_string());
+            }
+            None if self.profile == Profile::Prod => {
+                errors.push("SIGNING_KEY_SECRET must be set in the prod profile".to_string());
+            }
+            _ => {}
+        }
+
+        if self.retention.restore_window_days < 0 {
+            errors.push("USER_RESTORE_WINDOW_DAYS must not be negative".to_string());
+        }
+
+        for (key, url) in [
+            ("WEBAUTHN_ORIGIN", &self.webauthn.origin),
+            ("OIDC_ISSUER_URL", &self.oidc.issuer_url),
+            ("OIDC_REDIRECT_URI", &self.oidc.redirect_uri),
+        ] {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(format!("{} must be an http or https URL", key));
+            } else if self.profile == Profile::Prod && !url.starts_with("https://") {
+                errors.push(format!("{} must use https in the prod profile", key));
+            }
+        }
+
+        errors
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| "expected a whole number in range".to_string())
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err("expected true or false".to_string()),
+    }
+}
+
+/// Empty values clear optional settings, so `.env` can blank out a secret from the file.
+fn optional(value: &str) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
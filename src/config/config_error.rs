@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// Every problem found while loading the configuration, reported together so a bad deploy
+/// can be fixed in one pass instead of one restart per typo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub errors: Vec<String>,
+}
+
+impl ConfigError {
+    pub fn new(errors: Vec<String>) -> Self {
+        Self { errors }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid configuration ({} problem(s)):",
+            self.errors.len()
+        )?;
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,201 @@
+use crate::config::config_error::ConfigError;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::ErrorKind;
+
+pub const DEFAULT_CONFIG_FILE: &str = "config.toml";
+pub const DOTENV_FILE: &str = ".env";
+
+/// Every known setting as `(path in the TOML file, environment variable)`. The environment
+/// variable name is the canonical key used in error messages.
+pub const SETTINGS: &[(&str, &str)] = &[
+    ("profile", "APP_PROFILE"),
+    ("server.host", "SERVER_HOST"),
+    ("server.port", "SERVER_PORT"),
+    ("server.shutdown_delay_seconds", "SHUTDOWN_DELAY_SECONDS"),
+    ("server.shutdown_timeout_seconds", "SHUTDOWN_TIMEOUT_SECONDS"),
+    ("log.level", "LOG_LEVEL"),
+    ("log.format", "LOG_FORMAT"),
+    ("telemetry.otlp_endpoint", "OTEL_EXPORTER_OTLP_ENDPOINT"),
+    ("telemetry.sample_ratio", "OTEL_TRACES_SAMPLER_ARG"),
+    ("telemetry.service_name", "OTEL_SERVICE_NAME"),
+    ("health.check_timeout_ms", "HEALTH_CHECK_TIMEOUT_MS"),
+    ("api.deprecations", "API_DEPRECATIONS"),
+    ("docs.ui_enabled", "API_DOCS_UI_ENABLED"),
+    ("database.url", "DATABASE_URL"),
+    ("database.max_connections", "DATABASE_MAX_CONNECTIONS"),
+    ("hashing.bcrypt_cost", "BCRYPT_COST"),
+    ("bootstrap.admin_email", "BOOTSTRAP_ADMIN_EMAIL"),
+    ("bootstrap.admin_password", "BOOTSTRAP_ADMIN_PASSWORD"),
+    ("email.lowercase_local_part", "EMAIL_LOWERCASE_LOCAL_PART"),
+    ("token.issuer", "TOKEN_ISSUER"),
+    ("token.access_token_ttl_seconds", "ACCESS_TOKEN_TTL_SECONDS"),
+    (
+        "token.signing_key_rotation_seconds",
+        "SIGNING_KEY_ROTATION_SECONDS",
+    ),
+    ("token.signing_secret", "SIGNING_KEY_SECRET"),
+    ("webauthn.rp_id", "WEBAUTHN_RP_ID"),
+    ("webauthn.rp_name", "WEBAUTHN_RP_NAME"),
+    ("webauthn.origin", "WEBAUTHN_ORIGIN"),
+    ("oidc.issuer_url", "OIDC_ISSUER_URL"),
+    ("oidc.client_id", "OIDC_CLIENT_ID"),
+    ("oidc.client_secret", "OIDC_CLIENT_SECRET"),
+    ("oidc.redirect_uri", "OIDC_REDIRECT_URI"),
+    ("retention.restore_window_days", "USER_RESTORE_WINDOW_DAYS"),
+    ("retention.deleted_email_reuse", "DELETED_EMAIL_REUSE"),
+    ("id_strategy", "ID_STRATEGY"),
+    ("api_key", "API_KEY"),
+];
+
+/// The layer a setting was read from, in increasing precedence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Source {
+    File,
+    DotEnv,
+    Environment,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File => write!(f, "config file"),
+            Source::DotEnv => write!(f, ".env"),
+            Source::Environment => write!(f, "environment"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setting {
+    pub value: String,
+    pub source: Source,
+}
+
+/// Raw contents of each configuration layer. Built from the process by `from_process`, or
+/// by hand in tests so loading never depends on the machine it runs on.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSources {
+    /// Contents of the TOML config file, if there is one.
+    pub file: Option<String>,
+    pub dotenv: Vec<(String, String)>,
+    pub env: HashMap<String, String>,
+}
+
+impl ConfigSources {
+    /// Reads the file named by `CONFIG_FILE` (or `config.toml` when it exists), `.env` and
+    /// the process environment. A missing `.env` or default config file is not an error.
+    pub fn from_process() -> Result<Self, ConfigError> {
+        let env: HashMap<String, String> = std::env::vars().collect();
+
+        let file = match env.get("CONFIG_FILE") {
+            Some(path) => Some(
+                std::fs::read_to_string(path)
+                    .map_err(|e| ConfigError::new(vec![format!("CONFIG_FILE {}: {}", path, e)]))?,
+            ),
+            None => match std::fs::read_to_string(DEFAULT_CONFIG_FILE) {
+                Ok(contents) => Some(contents),
+                Err(e) if e.kind() == ErrorKind::NotFound => None,
+                Err(e) => {
+                    return Err(ConfigError::new(vec![format!(
+                        "{}: {}",
+                        DEFAULT_CONFIG_FILE, e
+                    )]));
+                }
+            },
+        };
+
+        let dotenv = match dotenvy::from_path_iter(DOTENV_FILE) {
+            Ok(entries) => entries
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| ConfigError::new(vec![format!("{}: {}", DOTENV_FILE, e)]))?,
+            Err(_) => Vec::new(),
+        };
+
+        Ok(Self { file, dotenv, env })
+    }
+
+    /// Merges the layers keyed by environment variable name; later layers win. Unknown keys
+    /// in the TOML file are reported, while unknown variables are ignored because the
+    /// environment and `.env` are shared with other tools.
+    pub fn merge(&self) -> (HashMap<&'static str, Setting>, Vec<String>) {
+        let mut settings = HashMap::new();
+        let mut errors = Vec::new();
+
+        if let Some(contents) = &self.file {
+            match contents.parse::<toml::Table>() {
+                Ok(table) => {
+                    let mut entries = Vec::new();
+                    flatten("", &table, &mut entries, &mut errors);
+                    for (path, value) in entries {
+                        match SETTINGS.iter().find(|(toml_path, _)| *toml_path == path) {
+                            Some((_, key)) => {
+                                settings.insert(
+                                    *key,
+                                    Setting {
+                                        value,
+                                        source: Source::File,
+                                    },
+                                );
+                            }
+                            None => errors.push(format!("config file: unknown setting `{}`", path)),
+                        }
+                    }
+                }
+                Err(e) => errors.push(format!("config file: {}", e.message())),
+            }
+        }
+
+        let dotenv = self
+            .dotenv
+            .iter()
+            .map(|(name, value)| (name, value, Source::DotEnv));
+        let env = self
+            .env
+            .iter()
+            .map(|(name, value)| (name, value, Source::Environment));
+
+        for (name, value, source) in dotenv.chain(env) {
+            if let Some((_, key)) = SETTINGS.iter().find(|(_, key)| key == name) {
+                settings.insert(
+                    *key,
+                    Setting {
+                        value: value.clone(),
+                        source,
+                    },
+                );
+            }
+        }
+
+        (settings, errors)
+    }
+}
+
+/// Turns nested tables into `section.key` paths with the scalar rendered as a string, so
+/// every layer is parsed by the same code.
+fn flatten(
+    prefix: &str,
+    table: &toml::Table,
+    entries: &mut Vec<(String, String)>,
+    errors: &mut Vec<String>,
+) {
+    for (name, value) in table {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+
+        match value {
+            toml::Value::Table(nested) => flatten(&path, nested, entries, errors),
+            toml::Value::String(text) => entries.push((path, text.clone())),
+            toml::Value::Integer(number) => entries.push((path, number.to_string())),
+            toml::Value::Float(number) => entries.push((path, number.to_string())),
+            toml::Value::Boolean(flag) => entries.push((path, flag.to_string())),
+            toml::Value::Datetime(_) | toml::Value::Array(_) => errors.push(format!(
+                "config file: `{}` must be a string or a number",
+                path
+            )),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::service::auth_service::authenticate_service::AuthenticateService;
+use crate::model::principal_model::Principal;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::HttpResponse;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuthenticateHandler {
+    async fn execute(
+        &self,
+        bearer_token: String
+    ) -> Result<Principal, HttpResponse<AppError>>;
+}
+
+pub struct AuthenticateHandlerImpl {
+    pub authenticate_service: Box<dyn AuthenticateService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl AuthenticateHandler for AuthenticateHandlerImpl {
+    async fn execute(
+        &self,
+        bearer_token: String
+    ) -> Result<Principal, HttpResponse<AppError>> {
+
+        self
+            .authenticate_service
+            .execute(bearer_token)
+            .await
+            .map_err(HttpResponse::from)
+    }
+}
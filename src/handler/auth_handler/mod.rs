@@ -0,0 +1 @@
+pub mod authenticate_handler;
@@ -0,0 +1,25 @@
+use crate::model::health_model::{HealthStatus, Liveness};
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+/// Answers as long as the process can serve a request at all. It checks no dependency, so
+/// an outage elsewhere never gets the instance restarted.
+#[async_trait]
+pub trait GetLivenessHandler {
+    async fn execute(&self) -> HttpResponse<Liveness>;
+}
+
+pub struct GetLivenessHandlerImpl;
+
+#[async_trait]
+impl GetLivenessHandler for GetLivenessHandlerImpl {
+    async fn execute(&self) -> HttpResponse<Liveness> {
+        HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Alive".to_string(),
+            content: Liveness {
+                status: HealthStatus::Up,
+            },
+        }
+    }
+}
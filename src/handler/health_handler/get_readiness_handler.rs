@@ -0,0 +1,35 @@
+use crate::model::health_model::{HealthStatus, Readiness};
+use crate::service::health_service::check_readiness_service::CheckReadinessService;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+/// 503 when a critical dependency is down, so load balancers and Kubernetes stop routing
+/// to the instance until it recovers.
+#[async_trait]
+pub trait GetReadinessHandler {
+    async fn execute(&self) -> HttpResponse<Readiness>;
+}
+
+pub struct GetReadinessHandlerImpl {
+    pub check_readiness_service: Box<dyn CheckReadinessService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetReadinessHandler for GetReadinessHandlerImpl {
+    async fn execute(&self) -> HttpResponse<Readiness> {
+        let readiness = self.check_readiness_service.execute().await;
+
+        match readiness.status {
+            HealthStatus::Up => HttpResponse {
+                status: HttpStatus::Ok,
+                detail: "Ready".to_string(),
+                content: readiness,
+            },
+            HealthStatus::Down => HttpResponse {
+                status: HttpStatus::ServiceUnavailable,
+                detail: "Not ready".to_string(),
+                content: readiness,
+            },
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod get_liveness_handler;
+pub mod get_readiness_handler;
@@ -1 +1,8 @@
 pub mod user_handler;
+pub mod passkey_handler;
+pub mod oidc_handler;
+pub mod oauth_handler;
+pub mod token_handler;
+pub mod auth_handler;
+pub mod personal_access_token_handler;
+pub mod health_handler;
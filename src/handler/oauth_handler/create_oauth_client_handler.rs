@@ -0,0 +1,38 @@
+use crate::service::oauth_service::create_oauth_client_service::CreateOAuthClientService;
+use crate::model::oauth_model::{OAuthClientCreated, OAuthClientForCreation};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateOAuthClientHandler {
+    async fn execute(
+        &self,
+        client_for_creation: OAuthClientForCreation
+    ) -> Result<HttpResponse<OAuthClientCreated>, HttpResponse<AppError>>;
+}
+
+pub struct CreateOAuthClientHandlerImpl {
+    pub create_oauth_client_service: Box<dyn CreateOAuthClientService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl CreateOAuthClientHandler for CreateOAuthClientHandlerImpl {
+    async fn execute(
+        &self,
+        client_for_creation: OAuthClientForCreation
+    ) -> Result<HttpResponse<OAuthClientCreated>, HttpResponse<AppError>> {
+
+        let created_client = self
+            .create_oauth_client_service
+            .execute(client_for_creation)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Created,
+            detail: "Client created, store the secret now: it is not shown again".to_string(),
+            content: created_client,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::service::oauth_service::introspect_token_service::IntrospectTokenService;
+use crate::model::oauth_model::{IntrospectionRequest, IntrospectionResponse};
+use crate::util::client_credentials::resolve_client_credentials;
+use crate::util::oauth_error::OAuthError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IntrospectTokenHandler {
+    async fn execute(
+        &self,
+        authorization: Option<String>,
+        introspection_request: IntrospectionRequest
+    ) -> Result<IntrospectionResponse, OAuthError>;
+}
+
+pub struct IntrospectTokenHandlerImpl {
+    pub introspect_token_service: Box<dyn IntrospectTokenService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl IntrospectTokenHandler for IntrospectTokenHandlerImpl {
+    async fn execute(
+        &self,
+        authorization: Option<String>,
+        introspection_request: IntrospectionRequest
+    ) -> Result<IntrospectionResponse, OAuthError> {
+
+        let credentials = resolve_client_credentials(
+            authorization.as_deref(),
+            introspection_request.client_id,
+            introspection_request.client_secret,
+        )?;
+
+        self.introspect_token_service
+            .execute(credentials, introspection_request.token)
+            .await
+    }
+}
@@ -0,0 +1,45 @@
+use crate::service::oauth_service::issue_client_credentials_token_service::IssueClientCredentialsTokenService;
+use crate::model::oauth_model::{OAuthTokenRequest, OAuthTokenResponse};
+use crate::util::client_credentials::resolve_client_credentials;
+use crate::util::oauth_error::{OAuthError, OAuthErrorCode};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IssueTokenHandler {
+    async fn execute(
+        &self,
+        authorization: Option<String>,
+        token_request: OAuthTokenRequest
+    ) -> Result<OAuthTokenResponse, OAuthError>;
+}
+
+pub struct IssueTokenHandlerImpl {
+    pub issue_client_credentials_token_service: Box<dyn IssueClientCredentialsTokenService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl IssueTokenHandler for IssueTokenHandlerImpl {
+    async fn execute(
+        &self,
+        authorization: Option<String>,
+        token_request: OAuthTokenRequest
+    ) -> Result<OAuthTokenResponse, OAuthError> {
+
+        if token_request.grant_type != "client_credentials" {
+            return Err(OAuthError::new(
+                OAuthErrorCode::UnsupportedGrantType,
+                format!("Grant type {} is not supported", token_request.grant_type),
+            ));
+        }
+
+        let credentials = resolve_client_credentials(
+            authorization.as_deref(),
+            token_request.client_id,
+            token_request.client_secret,
+        )?;
+
+        self.issue_client_credentials_token_service
+            .execute(credentials, token_request.scope)
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_oauth_client_handler;
+pub mod issue_token_handler;
+pub mod introspect_token_handler;
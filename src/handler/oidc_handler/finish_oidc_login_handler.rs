@@ -0,0 +1,41 @@
+use crate::service::oidc_service::finish_oidc_login_service::FinishOidcLoginService;
+use crate::model::oidc_model::OidcCallback;
+use crate::model::token_model::UserSession;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FinishOidcLoginHandler {
+    async fn execute(
+        &self,
+        callback: OidcCallback,
+        bound_state: Option<String>
+    ) -> Result<HttpResponse<UserSession>, HttpResponse<AppError>>;
+}
+
+pub struct FinishOidcLoginHandlerImpl {
+    pub finish_oidc_login_service: Box<dyn FinishOidcLoginService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishOidcLoginHandler for FinishOidcLoginHandlerImpl {
+    async fn execute(
+        &self,
+        callback: OidcCallback,
+        bound_state: Option<String>
+    ) -> Result<HttpResponse<UserSession>, HttpResponse<AppError>> {
+
+        let authenticated_user = self
+            .finish_oidc_login_service
+            .execute(callback, bound_state)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Authenticated successfully".to_string(),
+            content: authenticated_user,
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod start_oidc_login_handler;
+pub mod finish_oidc_login_handler;
@@ -0,0 +1,32 @@
+use crate::service::oidc_service::start_oidc_login_service::StartOidcLoginService;
+use crate::model::oidc_model::OidcLoginStart;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StartOidcLoginHandler {
+    async fn execute(&self) -> Result<HttpResponse<OidcLoginStart>, HttpResponse<AppError>>;
+}
+
+pub struct StartOidcLoginHandlerImpl {
+    pub start_oidc_login_service: Box<dyn StartOidcLoginService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartOidcLoginHandler for StartOidcLoginHandlerImpl {
+    async fn execute(&self) -> Result<HttpResponse<OidcLoginStart>, HttpResponse<AppError>> {
+
+        let login_start = self
+            .start_oidc_login_service
+            .execute()
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Redirect the user to the authorization URL".to_string(),
+            content: login_start,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::service::passkey_service::finish_passkey_authentication_service::FinishPasskeyAuthenticationService;
+use crate::model::passkey_model::PasskeyAuthenticationFinish;
+use crate::model::token_model::UserSession;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FinishPasskeyAuthenticationHandler {
+    async fn execute(
+        &self,
+        authentication_finish: PasskeyAuthenticationFinish
+    ) -> Result<HttpResponse<UserSession>, HttpResponse<AppError>>;
+}
+
+pub struct FinishPasskeyAuthenticationHandlerImpl {
+    pub finish_passkey_authentication_service: Box<dyn FinishPasskeyAuthenticationService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishPasskeyAuthenticationHandler for FinishPasskeyAuthenticationHandlerImpl {
+    async fn execute(
+        &self,
+        authentication_finish: PasskeyAuthenticationFinish
+    ) -> Result<HttpResponse<UserSession>, HttpResponse<AppError>> {
+
+        let authenticated_user = self
+            .finish_passkey_authentication_service
+            .execute(authentication_finish)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Authenticated successfully".to_string(),
+            content: authenticated_user,
+        })
+    }
+}
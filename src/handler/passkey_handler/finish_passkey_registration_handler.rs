@@ -0,0 +1,38 @@
+use crate::service::passkey_service::finish_passkey_registration_service::FinishPasskeyRegistrationService;
+use crate::model::passkey_model::{PasskeyPublic, PasskeyRegistrationFinish};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FinishPasskeyRegistrationHandler {
+    async fn execute(
+        &self,
+        registration_finish: PasskeyRegistrationFinish
+    ) -> Result<HttpResponse<PasskeyPublic>, HttpResponse<AppError>>;
+}
+
+pub struct FinishPasskeyRegistrationHandlerImpl {
+    pub finish_passkey_registration_service: Box<dyn FinishPasskeyRegistrationService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishPasskeyRegistrationHandler for FinishPasskeyRegistrationHandlerImpl {
+    async fn execute(
+        &self,
+        registration_finish: PasskeyRegistrationFinish
+    ) -> Result<HttpResponse<PasskeyPublic>, HttpResponse<AppError>> {
+
+        let registered_passkey = self
+            .finish_passkey_registration_service
+            .execute(registration_finish)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Created,
+            detail: "Passkey registered successfully".to_string(),
+            content: registered_passkey,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod start_passkey_registration_handler;
+pub mod finish_passkey_registration_handler;
+pub mod start_passkey_authentication_handler;
+pub mod finish_passkey_authentication_handler;
@@ -0,0 +1,38 @@
+use crate::service::passkey_service::start_passkey_authentication_service::StartPasskeyAuthenticationService;
+use crate::model::passkey_model::{PasskeyAuthenticationOptions, PasskeyAuthenticationStart};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StartPasskeyAuthenticationHandler {
+    async fn execute(
+        &self,
+        authentication_start: PasskeyAuthenticationStart
+    ) -> Result<HttpResponse<PasskeyAuthenticationOptions>, HttpResponse<AppError>>;
+}
+
+pub struct StartPasskeyAuthenticationHandlerImpl {
+    pub start_passkey_authentication_service: Box<dyn StartPasskeyAuthenticationService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartPasskeyAuthenticationHandler for StartPasskeyAuthenticationHandlerImpl {
+    async fn execute(
+        &self,
+        authentication_start: PasskeyAuthenticationStart
+    ) -> Result<HttpResponse<PasskeyAuthenticationOptions>, HttpResponse<AppError>> {
+
+        let authentication_options = self
+            .start_passkey_authentication_service
+            .execute(authentication_start)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Passkey authentication started".to_string(),
+            content: authentication_options,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use crate::service::passkey_service::start_passkey_registration_service::StartPasskeyRegistrationService;
+use crate::model::passkey_model::{PasskeyRegistrationOptions, PasskeyRegistrationStart};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait StartPasskeyRegistrationHandler {
+    async fn execute(
+        &self,
+        registration_start: PasskeyRegistrationStart
+    ) -> Result<HttpResponse<PasskeyRegistrationOptions>, HttpResponse<AppError>>;
+}
+
+pub struct StartPasskeyRegistrationHandlerImpl {
+    pub start_passkey_registration_service: Box<dyn StartPasskeyRegistrationService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartPasskeyRegistrationHandler for StartPasskeyRegistrationHandlerImpl {
+    async fn execute(
+        &self,
+        registration_start: PasskeyRegistrationStart
+    ) -> Result<HttpResponse<PasskeyRegistrationOptions>, HttpResponse<AppError>> {
+
+        let registration_options = self
+            .start_passkey_registration_service
+            .execute(registration_start)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Passkey registration started".to_string(),
+            content: registration_options,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use crate::service::personal_access_token_service::create_personal_access_token_service::CreatePersonalAccessTokenService;
+use crate::model::personal_access_token_model::{PersonalAccessTokenCreated, PersonalAccessTokenForCreation};
+use crate::model::principal_model::Principal;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreatePersonalAccessTokenHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_for_creation: PersonalAccessTokenForCreation
+    ) -> Result<HttpResponse<PersonalAccessTokenCreated>, HttpResponse<AppError>>;
+}
+
+pub struct CreatePersonalAccessTokenHandlerImpl {
+    pub create_personal_access_token_service: Box<dyn CreatePersonalAccessTokenService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl CreatePersonalAccessTokenHandler for CreatePersonalAccessTokenHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_for_creation: PersonalAccessTokenForCreation
+    ) -> Result<HttpResponse<PersonalAccessTokenCreated>, HttpResponse<AppError>> {
+
+        let created_token = self
+            .create_personal_access_token_service
+            .execute(principal, token_for_creation)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Created,
+            detail: "Personal access token created successfully, it will not be shown again".to_string(),
+            content: created_token,
+        })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::service::personal_access_token_service::list_personal_access_tokens_service::ListPersonalAccessTokensService;
+use crate::model::personal_access_token_model::PersonalAccessTokenPublic;
+use crate::model::principal_model::Principal;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ListPersonalAccessTokensHandler {
+    async fn execute(
+        &self,
+        principal: Principal
+    ) -> Result<HttpResponse<Vec<PersonalAccessTokenPublic>>, HttpResponse<AppError>>;
+}
+
+pub struct ListPersonalAccessTokensHandlerImpl {
+    pub list_personal_access_tokens_service: Box<dyn ListPersonalAccessTokensService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ListPersonalAccessTokensHandler for ListPersonalAccessTokensHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal
+    ) -> Result<HttpResponse<Vec<PersonalAccessTokenPublic>>, HttpResponse<AppError>> {
+
+        let tokens = self
+            .list_personal_access_tokens_service
+            .execute(principal)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Personal access tokens listed successfully".to_string(),
+            content: tokens,
+        })
+    }
+}
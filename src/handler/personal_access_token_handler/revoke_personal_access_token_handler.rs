@@ -0,0 +1,41 @@
+use crate::service::personal_access_token_service::revoke_personal_access_token_service::RevokePersonalAccessTokenService;
+use crate::model::personal_access_token_model::PersonalAccessTokenPublic;
+use crate::model::principal_model::Principal;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RevokePersonalAccessTokenHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_id: String
+    ) -> Result<HttpResponse<PersonalAccessTokenPublic>, HttpResponse<AppError>>;
+}
+
+pub struct RevokePersonalAccessTokenHandlerImpl {
+    pub revoke_personal_access_token_service: Box<dyn RevokePersonalAccessTokenService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl RevokePersonalAccessTokenHandler for RevokePersonalAccessTokenHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_id: String
+    ) -> Result<HttpResponse<PersonalAccessTokenPublic>, HttpResponse<AppError>> {
+
+        let revoked_token = self
+            .revoke_personal_access_token_service
+            .execute(principal, token_id)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Personal access token revoked successfully".to_string(),
+            content: revoked_token,
+        })
+    }
+}
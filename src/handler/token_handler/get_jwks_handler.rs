@@ -0,0 +1,25 @@
+use crate::service::token_service::get_jwks_service::GetJwksService;
+use crate::model::token_model::Jwks;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::HttpResponse;
+use async_trait::async_trait;
+
+/// Serves the bare JWKS document (RFC 7517), which is what JWT libraries fetch.
+#[async_trait]
+pub trait GetJwksHandler {
+    async fn execute(&self) -> Result<Jwks, HttpResponse<AppError>>;
+}
+
+pub struct GetJwksHandlerImpl {
+    pub get_jwks_service: Box<dyn GetJwksService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetJwksHandler for GetJwksHandlerImpl {
+    async fn execute(&self) -> Result<Jwks, HttpResponse<AppError>> {
+        self.get_jwks_service
+            .execute()
+            .await
+            .map_err(HttpResponse::from)
+    }
+}
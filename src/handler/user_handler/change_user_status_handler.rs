@@ -0,0 +1,46 @@
+use crate::service::user_service::change_user_status_service::ChangeUserStatusService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic, UserStatus};
+use crate::model::user_status_model::UserStatusChangeRequest;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ChangeUserStatusHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        status: UserStatus,
+        change_request: UserStatusChangeRequest
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct ChangeUserStatusHandlerImpl {
+    pub change_user_status_service: Box<dyn ChangeUserStatusService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ChangeUserStatusHandler for ChangeUserStatusHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        status: UserStatus,
+        change_request: UserStatusChangeRequest
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let updated_user = self
+            .change_user_status_service
+            .execute(principal, user_id, status, change_request)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User status changed successfully".to_string(),
+            content: updated_user,
+        })
+    }
+}
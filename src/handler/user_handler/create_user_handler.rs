@@ -1,16 +1,15 @@
 use crate::service::user_service::create_user_service::CreateUserService;
-use crate::model::user_model::{UserForCreation, UserPublic};
-use crate::util::app_error::{AppError, ErrorType};
+use crate::model::user_model::{UserForCreation, UserPublic, UserRole};
+use crate::util::app_error::AppError;
 use crate::util::http_status_code::{HttpResponse, HttpStatus};
 use async_trait::async_trait;
-use axum::Json;
 
 #[async_trait]
 pub trait CreateUserHandler {
     async fn execute(
         &self,
         user_for_creation: UserForCreation
-    ) -> Result<Json<HttpResponse<UserPublic>>, Json<HttpResponse<AppError>>>;
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
 }
 
 pub struct CreateUserHandlerImpl {
@@ -22,29 +21,26 @@ impl CreateUserHandler for CreateUserHandlerImpl {
     async fn execute(
         &self,
         user_for_creation: UserForCreation
-    ) -> Result<Json<HttpResponse<UserPublic>>, Json<HttpResponse<AppError>>> {
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        // Public signup must never mint admins; operators configure `BOOTSTRAP_ADMIN_EMAIL`.
+        if user_for_creation.role == UserRole::Admin {
+            return Err(HttpResponse::from(AppError::forbidden(
+                "Admin accounts cannot be created through signup",
+            )));
+        }
 
         let created_user = self
             .create_user_service
             .execute(user_for_creation)
             .await
-            .map_err(|err| {
- 
-                Json(HttpResponse {
-                    status: match err.error_type {
-                        ErrorType::ClientError => HttpStatus::BadRequest,
-                        ErrorType::ServerError => HttpStatus::InternalServerError,
-                    },
-                    detail: err.detail.clone(),
-                    content: err,
-                })
-            })?;
+            .map_err(HttpResponse::from)?;
 
 
-        Ok(Json(HttpResponse {
+        Ok(HttpResponse {
             status: HttpStatus::Created,
             detail: "User created successfully".to_string(),
             content: created_user,
-        }))
+        })
     }
-}
\ No newline at end of file
+}
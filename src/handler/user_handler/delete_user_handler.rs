@@ -0,0 +1,43 @@
+use crate::service::user_service::delete_user_service::DeleteUserService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait DeleteUserHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct DeleteUserHandlerImpl {
+    pub delete_user_service: Box<dyn DeleteUserService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl DeleteUserHandler for DeleteUserHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let deleted_user = self
+            .delete_user_service
+            .execute(principal, user_id, if_match)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User deleted successfully".to_string(),
+            content: deleted_user,
+        })
+    }
+}
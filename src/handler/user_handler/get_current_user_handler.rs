@@ -0,0 +1,39 @@
+use crate::service::user_service::get_current_user_service::GetCurrentUserService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::UserPublic;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetCurrentUserHandler {
+    async fn execute(
+        &self,
+        principal: Principal
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct GetCurrentUserHandlerImpl {
+    pub get_current_user_service: Box<dyn GetCurrentUserService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetCurrentUserHandler for GetCurrentUserHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let user = self
+            .get_current_user_service
+            .execute(principal)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User found".to_string(),
+            content: user,
+        })
+    }
+}
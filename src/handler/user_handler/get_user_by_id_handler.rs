@@ -0,0 +1,41 @@
+use crate::service::user_service::get_user_by_id_service::GetUserByIdService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetUserByIdHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct GetUserByIdHandlerImpl {
+    pub get_user_by_id_service: Box<dyn GetUserByIdService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetUserByIdHandler for GetUserByIdHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let user = self
+            .get_user_by_id_service
+            .execute(principal, user_id)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User found".to_string(),
+            content: user,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use crate::model::user_model::UserId;
+use crate::service::user_service::get_user_status_history_service::GetUserStatusHistoryService;
+use crate::model::principal_model::Principal;
+use crate::model::user_status_model::UserStatusChange;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetUserStatusHistoryHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<Vec<UserStatusChange>>, HttpResponse<AppError>>;
+}
+
+pub struct GetUserStatusHistoryHandlerImpl {
+    pub get_user_status_history_service: Box<dyn GetUserStatusHistoryService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetUserStatusHistoryHandler for GetUserStatusHistoryHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<Vec<UserStatusChange>>, HttpResponse<AppError>> {
+
+        let history = self
+            .get_user_status_history_service
+            .execute(principal, user_id)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User status history listed successfully".to_string(),
+            content: history,
+        })
+    }
+}
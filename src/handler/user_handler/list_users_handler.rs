@@ -0,0 +1,42 @@
+use crate::service::user_service::list_users_service::ListUsersService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::UserPublic;
+use crate::model::user_query_model::{Page, UserListParams};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ListUsersHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        params: UserListParams
+    ) -> Result<HttpResponse<Page<UserPublic>>, HttpResponse<AppError>>;
+}
+
+pub struct ListUsersHandlerImpl {
+    pub list_users_service: Box<dyn ListUsersService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ListUsersHandler for ListUsersHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        params: UserListParams
+    ) -> Result<HttpResponse<Page<UserPublic>>, HttpResponse<AppError>> {
+
+        let users = self
+            .list_users_service
+            .execute(principal, params)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Users listed successfully".to_string(),
+            content: users,
+        })
+    }
+}
@@ -1 +1,10 @@
-pub mod create_user_handler;
\ No newline at end of file
+pub mod create_user_handler;
+pub mod get_current_user_handler;
+pub mod get_user_by_id_handler;
+pub mod list_users_handler;
+pub mod update_user_handler;
+pub mod delete_user_handler;
+pub mod change_user_status_handler;
+pub mod get_user_status_history_handler;
+pub mod restore_user_handler;
+pub mod purge_deleted_users_handler;
@@ -0,0 +1,32 @@
+use crate::service::user_service::purge_deleted_users_service::PurgeDeletedUsersService;
+use crate::model::user_model::UserPurgeResult;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait PurgeDeletedUsersHandler {
+    async fn execute(&self) -> Result<HttpResponse<UserPurgeResult>, HttpResponse<AppError>>;
+}
+
+pub struct PurgeDeletedUsersHandlerImpl {
+    pub purge_deleted_users_service: Box<dyn PurgeDeletedUsersService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl PurgeDeletedUsersHandler for PurgeDeletedUsersHandlerImpl {
+    async fn execute(&self) -> Result<HttpResponse<UserPurgeResult>, HttpResponse<AppError>> {
+
+        let purge_result = self
+            .purge_deleted_users_service
+            .execute()
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "Deleted users purged successfully".to_string(),
+            content: purge_result,
+        })
+    }
+}
@@ -0,0 +1,41 @@
+use crate::service::user_service::restore_user_service::RestoreUserService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RestoreUserHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct RestoreUserHandlerImpl {
+    pub restore_user_service: Box<dyn RestoreUserService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl RestoreUserHandler for RestoreUserHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let restored_user = self
+            .restore_user_service
+            .execute(principal, user_id)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User restored successfully".to_string(),
+            content: restored_user,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::service::user_service::update_user_service::UpdateUserService;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserForUpdate, UserId, UserPublic};
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait UpdateUserHandler {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+        user_for_update: UserForUpdate
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>>;
+}
+
+pub struct UpdateUserHandlerImpl {
+    pub update_user_service: Box<dyn UpdateUserService + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl UpdateUserHandler for UpdateUserHandlerImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+        user_for_update: UserForUpdate
+    ) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+
+        let updated_user = self
+            .update_user_service
+            .execute(principal, user_id, if_match, user_for_update)
+            .await
+            .map_err(HttpResponse::from)?;
+
+        Ok(HttpResponse {
+            status: HttpStatus::Ok,
+            detail: "User updated successfully".to_string(),
+            content: updated_user,
+        })
+    }
+}
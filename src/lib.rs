@@ -3,4 +3,10 @@ pub mod model;
 pub mod service;
 pub mod repository;
 pub mod handler;
-pub mod util;
\ No newline at end of file
+pub mod util;
+pub mod router;
+pub mod bootstrap;
+pub mod cli;
+pub mod config;
+pub mod middleware;
+pub mod openapi;
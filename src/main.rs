@@ -1,16 +1,42 @@
-use axum::{Router, routing::get};
-use tracing_subscriber::FmtSubscriber;
+use axum_api_starter::cli::{Cli, run};
+use axum_api_starter::config::app_config::AppConfig;
+use axum_api_starter::util::{logging, telemetry};
+use clap::Parser;
+use opentelemetry::trace::TracerProvider;
 
 #[tokio::main]
 async fn main() {
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(tracing::Level::INFO)
-        .finish();
+    let cli = Cli::parse();
 
-    tracing::subscriber::set_global_default(subscriber).expect("failed to set subscriber");
+    // Logging is configured from the config itself, so a bad config can only go to stderr.
+    let config = AppConfig::load().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-    let app = Router::new().route("/", get(|| async { "Hello, World!" }));
+    let tracer_provider = telemetry::tracer_provider(&config.telemetry).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    });
 
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    logging::init(
+        &config.log,
+        tracer_provider
+            .as_ref()
+            .map(|provider| provider.tracer(config.telemetry.service_name.clone())),
+    );
+
+    let result = run(cli, config).await;
+
+    // Flush spans still waiting in the batch before the process goes away.
+    if let Some(provider) = tracer_provider
+        && let Err(err) = provider.shutdown()
+    {
+        eprintln!("failed to flush traces: {}", err);
+    }
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
 }
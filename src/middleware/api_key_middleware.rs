@@ -0,0 +1,29 @@
+use crate::router::AppState;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::HttpResponse;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use subtle::ConstantTimeEq;
+
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Guards administrative routes with the static `API_KEY`. Without a configured key
+/// those routes are closed.
+pub async fn require_api_key(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let provided = request
+        .headers()
+        .get(API_KEY_HEADER)
+        .map(|value| value.as_bytes());
+
+    let authorized = match (&state.api_key, provided) {
+        (Some(expected), Some(provided)) => bool::from(expected.expose().as_bytes().ct_eq(provided)),
+        _ => false,
+    };
+
+    if !authorized {
+        return HttpResponse::from(AppError::unauthorized("Invalid API key")).into_response();
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,26 @@
+use crate::model::principal_model::Principal;
+use crate::router::AppState;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::HttpResponse;
+use axum::extract::FromRequestParts;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+
+/// Resolves the caller from `Authorization: Bearer`, accepting both access tokens issued by
+/// this API and personal access tokens. Routes check scopes on the resulting principal.
+impl FromRequestParts<AppState> for Principal {
+    type Rejection = HttpResponse<AppError>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let bearer_token = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| HttpResponse::from(AppError::unauthorized("Missing bearer token")))?;
+
+        state.authenticate_handler.execute(bearer_token.to_string()).await
+    }
+}
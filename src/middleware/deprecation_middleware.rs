@@ -0,0 +1,51 @@
+use crate::router::AppState;
+use crate::util::deprecation::find_deprecation;
+use crate::util::metrics::record_deprecated_request;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::HeaderValue;
+use axum::http::header::{HeaderName, LINK};
+use axum::middleware::Next;
+use axum::response::Response;
+
+const DEPRECATION: HeaderName = HeaderName::from_static("deprecation");
+const SUNSET: HeaderName = HeaderName::from_static("sunset");
+
+/// Adds `Deprecation`, `Sunset` and `Link` headers to routes covered by `API_DEPRECATIONS`,
+/// and counts and logs their use so it is clear who still calls them before they go away.
+/// Installed as a route layer so it sees the matched template.
+pub async fn mark_deprecated(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_default();
+
+    let Some(deprecation) = find_deprecation(&state.deprecations, &route).cloned() else {
+        return next.run(request).await;
+    };
+
+    let method = request.method().to_string();
+    let mut response = next.run(request).await;
+
+    record_deprecated_request(&method, &route);
+    tracing::info!(
+        %method,
+        %route,
+        deprecated_by = %deprecation.route_prefix,
+        "deprecated route used"
+    );
+
+    let headers = response.headers_mut();
+    let values = [
+        (DEPRECATION, Some(deprecation.deprecation_header())),
+        (SUNSET, deprecation.sunset_header()),
+        (LINK, deprecation.link_header()),
+    ];
+    for (name, value) in values {
+        if let Some(value) = value.and_then(|value| HeaderValue::from_str(&value).ok()) {
+            headers.append(name, value);
+        }
+    }
+
+    response
+}
@@ -0,0 +1,6 @@
+pub mod api_key_middleware;
+pub mod authentication;
+pub mod request_span_middleware;
+pub mod request_id_middleware;
+pub mod metrics_middleware;
+pub mod deprecation_middleware;
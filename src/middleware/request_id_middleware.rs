@@ -0,0 +1,36 @@
+use crate::router::AppState;
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::HttpResponse;
+use crate::util::request_id::{self, REQUEST_ID_HEADER, is_valid_request_id};
+use axum::extract::{Request, State};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+
+/// Gives every request an id: the caller's `X-Request-Id` when it is valid, otherwise a new
+/// one. The id is available to the rest of the request through `current_request_id` and is
+/// echoed back in the response header.
+pub async fn assign_request_id(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let provided = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map(str::to_string);
+
+    let request_id = match provided {
+        Some(request_id) => request_id,
+        None => match state.request_id_generator.generate() {
+            Ok(request_id) => request_id,
+            Err(err) => return HttpResponse::from(AppError::server_error(err)).into_response(),
+        },
+    };
+
+    let mut response = request_id::scope(request_id.clone(), next.run(request)).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
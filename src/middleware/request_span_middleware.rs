@@ -0,0 +1,49 @@
+use crate::util::request_id::current_request_id;
+use crate::util::telemetry::extract_trace_context;
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::time::Instant;
+use tracing::Instrument;
+use tracing::field::Empty;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Runs each request inside a `request` span so that everything logged while handling it,
+/// including service and repository spans, carries the method, path and request id. Status
+/// and latency are recorded on the span once the response is ready. A W3C `traceparent`
+/// from the caller makes the span a child of the caller's trace when spans are exported.
+pub async fn trace_request(request: Request, next: Next) -> Response {
+    let span = tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        request_id = Empty,
+        status = Empty,
+        latency_ms = Empty,
+    );
+
+    // Only fails when spans are not exported, in which case there is no trace to join.
+    let _ = span.set_parent(extract_trace_context(request.headers()));
+
+    if let Some(request_id) = current_request_id() {
+        span.record("request_id", request_id);
+    }
+
+    let started_at = Instant::now();
+    let response = next.run(request).instrument(span.clone()).await;
+    let latency_ms = started_at.elapsed().as_millis() as u64;
+
+    let status = response.status();
+    span.record("status", status.as_u16());
+    span.record("latency_ms", latency_ms);
+
+    span.in_scope(|| {
+        if status.is_server_error() {
+            tracing::error!("request failed");
+        } else {
+            tracing::info!("request completed");
+        }
+    });
+
+    response
+}
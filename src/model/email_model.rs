@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+const MAX_EMAIL_LENGTH: usize = 254;
+
+/// Validated email address: trimmed, with the domain lowercased in its ASCII (punycode) form.
+/// The local part keeps its case; services pass every incoming address through
+/// `EmailConfig::normalize` so lookups and stored values follow the configured rule. Only
+/// `Email::parse` builds one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "String", into = "String")]
+#[schema(value_type = String, format = Email)]
+pub struct Email(String);
+
+impl Email {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+
+        let (local_part, domain) = raw
+            .rsplit_once('@')
+            .ok_or_else(|| format!("Invalid email {}: missing @", raw))?;
+
+        if local_part.is_empty()
+            || local_part.len() > MAX_LOCAL_PART_LENGTH
+            || local_part.contains('@')
+            || local_part.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Err(format!("Invalid email {}: malformed local part", raw));
+        }
+
+        let domain = idna::domain_to_ascii_strict(domain)
+            .map_err(|_| format!("Invalid email {}: malformed domain", raw))?;
+
+        if !domain.contains('.') {
+            return Err(format!("Invalid email {}: domain must be fully qualified", raw));
+        }
+
+        let email = format!("{}@{}", local_part, domain);
+
+        if email.len() > MAX_EMAIL_LENGTH {
+            return Err(format!("Invalid email {}: too long", raw));
+        }
+
+        Ok(Email(email))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// How the local part of an address is compared. RFC 5321 makes it case-sensitive, but
+/// almost no mail provider treats it that way, so lowercasing is the default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmailConfig {
+    pub lowercase_local_part: bool,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            lowercase_local_part: true,
+        }
+    }
+}
+
+impl EmailConfig {
+    pub fn normalize(&self, email: Email) -> Email {
+        if !self.lowercase_local_part {
+            return email;
+        }
+
+        // The domain is already lowercase, so lowercasing the whole address is enough.
+        Email(email.0.to_lowercase())
+    }
+}
+
+impl TryFrom<String> for Email {
+    type Error = String;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        Email::parse(&raw)
+    }
+}
+
+impl From<Email> for String {
+    fn from(email: Email) -> Self {
+        email.0
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
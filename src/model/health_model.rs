@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, ToSchema)]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Outcome of one readiness check. `error` explains a `Down` status, including a timeout.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    pub critical: bool,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// `status` is `Down` only when a critical component is down; failing non-critical
+/// components are listed but keep the instance in rotation.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct Readiness {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct Liveness {
+    pub status: HealthStatus,
+}
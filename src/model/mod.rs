@@ -1 +1,11 @@
+pub mod email_model;
 pub mod user_model;
+pub mod passkey_model;
+pub mod oidc_model;
+pub mod token_model;
+pub mod oauth_model;
+pub mod principal_model;
+pub mod personal_access_token_model;
+pub mod user_query_model;
+pub mod user_status_model;
+pub mod health_model;
@@ -0,0 +1,110 @@
+use crate::util::secret::{Secret, expose};
+use axum::Json;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct OAuthClient {
+    pub id: String,
+    pub name: String,
+    pub secret_hash: Secret<String>,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct OAuthClientForCreation {
+    pub name: String,
+    pub scopes: Vec<String>,
+}
+
+/// Returned once on creation: the plaintext secret is never stored.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct OAuthClientCreated {
+    pub client_id: String,
+    #[serde(serialize_with = "expose")]
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ClientCredentials {
+    pub client_id: String,
+    pub client_secret: Secret<String>,
+}
+
+/// RFC 6749, section 4.4.2
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct OAuthTokenRequest {
+    pub grant_type: String,
+    pub scope: Option<String>,
+    pub client_id: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub client_secret: Option<Secret<String>>,
+}
+
+/// RFC 6749, section 5.1
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct OAuthTokenResponse {
+    #[serde(serialize_with = "expose")]
+    #[schema(value_type = String)]
+    pub access_token: Secret<String>,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub scope: String,
+}
+
+impl IntoResponse for OAuthTokenResponse {
+    fn into_response(self) -> Response {
+        (
+            [(header::CACHE_CONTROL, "no-store"), (header::PRAGMA, "no-cache")],
+            Json(self),
+        )
+            .into_response()
+    }
+}
+
+/// RFC 7662, section 2.1
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct IntrospectionRequest {
+    #[schema(value_type = String)]
+    pub token: Secret<String>,
+    pub token_type_hint: Option<String>,
+    pub client_id: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub client_secret: Option<Secret<String>>,
+}
+
+/// RFC 7662, section 2.2
+#[derive(Debug, Serialize, Deserialize, Clone, Default, ToSchema)]
+pub struct IntrospectionResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+impl IntoResponse for IntrospectionResponse {
+    fn into_response(self) -> Response {
+        ([(header::CACHE_CONTROL, "no-store")], Json(self)).into_response()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::model::user_model::UserId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use utoipa::IntoParams;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OidcAuthorization {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OidcIdentity {
+    pub id: String,
+    pub user_id: UserId,
+    pub issuer: String,
+    pub subject: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct OidcLoginStart {
+    pub authorization_url: String,
+    /// Goes to the browser in the `STATE_COOKIE` cookie, not in the body.
+    #[serde(skip)]
+    pub state: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OidcCallback {
+    pub state: String,
+    pub code: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
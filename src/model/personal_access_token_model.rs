@@ -0,0 +1,68 @@
+use crate::model::user_model::UserId;
+use crate::util::secret::{Secret, expose};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "pat_";
+pub const MAX_PERSONAL_ACCESS_TOKEN_DAYS: i64 = 365;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PersonalAccessToken {
+    pub id: String,
+    pub user_id: UserId,
+    pub name: String,
+    pub token_hash: Secret<String>,
+    pub scopes: Vec<String>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl PersonalAccessToken {
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PersonalAccessTokenForCreation {
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_in_days: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PersonalAccessTokenPublic {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<PersonalAccessToken> for PersonalAccessTokenPublic {
+    fn from(token: PersonalAccessToken) -> Self {
+        PersonalAccessTokenPublic {
+            id: token.id,
+            name: token.name,
+            scopes: token.scopes,
+            expires_at: token.expires_at,
+            created_at: token.created_at,
+            last_used_at: token.last_used_at,
+            revoked_at: token.revoked_at,
+        }
+    }
+}
+
+/// Returned once on creation: only the hash of `token` is stored.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PersonalAccessTokenCreated {
+    #[serde(serialize_with = "expose")]
+    #[schema(value_type = String)]
+    pub token: Secret<String>,
+    pub personal_access_token: PersonalAccessTokenPublic,
+}
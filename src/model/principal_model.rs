@@ -0,0 +1,38 @@
+use crate::model::user_model::UserId;
+use crate::util::app_error::AppError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum CredentialKind {
+    AccessToken,
+    PersonalAccessToken,
+}
+
+/// Who is calling: a user (through a login token or a personal access token) or an OAuth client.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Principal {
+    pub user_id: Option<UserId>,
+    pub client_id: Option<String>,
+    pub scopes: Vec<String>,
+    pub credential: CredentialKind,
+}
+
+impl Principal {
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|granted| granted == scope)
+    }
+
+    pub fn require_scope(&self, scope: &str) -> Result<(), AppError> {
+        if !self.has_scope(scope) {
+            return Err(AppError::forbidden(format!("Missing scope {}", scope)));
+        }
+
+        Ok(())
+    }
+
+    pub fn require_user(&self) -> Result<&UserId, AppError> {
+        self.user_id
+            .as_ref()
+            .ok_or_else(|| AppError::forbidden("This operation requires a user token"))
+    }
+}
@@ -0,0 +1,58 @@
+use axum::Json;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use crate::model::user_model::UserPublic;
+use crate::util::secret::{Secret, expose};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccessTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub client_id: Option<String>,
+    pub scope: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: String,
+}
+
+impl AccessTokenClaims {
+    pub fn scopes(&self) -> Vec<&str> {
+        self.scope.split_whitespace().collect()
+    }
+}
+
+/// Public signing key as published in `/.well-known/jwks.json` (RFC 8037 for Ed25519).
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct Jwk {
+    pub kty: String,
+    pub crv: String,
+    pub alg: String,
+    #[serde(rename = "use")]
+    pub key_use: String,
+    pub kid: String,
+    pub x: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+impl IntoResponse for Jwks {
+    fn into_response(self) -> Response {
+        ([(header::CACHE_CONTROL, "public, max-age=300")], Json(self)).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UserSession {
+    pub user: UserPublic,
+    #[serde(serialize_with = "expose")]
+    #[schema(value_type = String)]
+    pub access_token: Secret<String>,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub scope: String,
+}
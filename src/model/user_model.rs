@@ -1,35 +1,145 @@
+use crate::model::email_model::Email;
+use crate::util::secret::Secret;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Opaque user identifier. Its format follows the `IdStrategy` in use when the user was
+/// created, so it is only ever compared, never parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct UserId(String);
+
+impl UserId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for UserId {
+    fn from(id: String) -> Self {
+        UserId(id)
+    }
+}
+
+impl From<&str> for UserId {
+    fn from(id: &str) -> Self {
+        UserId(id.to_string())
+    }
+}
+
+impl From<UserId> for String {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
 pub enum UserRole {
     Admin,
     User,
-    Inactive
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Account lifecycle, independent from what the role allows.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub enum UserStatus {
+    Active,
+    Suspended,
+    Deactivated,
+    PendingVerification,
+}
+
+impl UserStatus {
+    pub fn allows_sign_in(&self) -> bool {
+        matches!(self, UserStatus::Active | UserStatus::PendingVerification)
+    }
+
+    /// Transitions an admin may make. `PendingVerification` is only ever a starting state,
+    /// so no transition leads into it.
+    pub fn can_change_to(&self, target: &UserStatus) -> bool {
+        match target {
+            UserStatus::Suspended => {
+                matches!(self, UserStatus::Active | UserStatus::PendingVerification)
+            }
+            UserStatus::Deactivated => matches!(
+                self,
+                UserStatus::Active | UserStatus::Suspended | UserStatus::PendingVerification
+            ),
+            UserStatus::Active => matches!(self, UserStatus::Suspended | UserStatus::Deactivated),
+            UserStatus::PendingVerification => false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
 pub struct User {
-    pub id: String,
-    pub email: String,
-    pub password: String, 
+    pub id: UserId,
+    pub email: Email,
+    pub password: Secret<String>,
     pub role: UserRole,
+    pub status: UserStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Bumped by the repository on every write; exposed to clients as the ETag.
+    pub version: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct UserForCreation {
-    pub email: String,
-    pub password: String,
+    pub email: Email,
+    #[schema(value_type = String, format = Password)]
+    pub password: Secret<String>,
     pub role: UserRole,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Partial update: fields left out keep their current value.
+#[derive(Debug, Deserialize, Clone, Default, ToSchema)]
+pub struct UserForUpdate {
+    pub email: Option<Email>,
+    #[schema(value_type = Option<String>, format = Password)]
+    pub password: Option<Secret<String>>,
+    pub role: Option<UserRole>,
+    /// Required when users change their own email or password.
+    #[schema(value_type = Option<String>, format = Password)]
+    pub current_password: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct UserPublic {
-    pub id: String,
-    pub email: String,
+    pub id: UserId,
+    pub email: Email,
     pub role: UserRole,
+    pub status: UserStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
-}
\ No newline at end of file
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub version: u64,
+}
+
+impl From<User> for UserPublic {
+    fn from(user: User) -> Self {
+        UserPublic {
+            id: user.id,
+            email: user.email,
+            role: user.role,
+            status: user.status,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+            deleted_at: user.deleted_at,
+            version: user.version,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UserPurgeResult {
+    pub purged_users: usize,
+}
@@ -0,0 +1,164 @@
+use crate::model::user_model::{User, UserId, UserRole};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+use utoipa::IntoParams;
+
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// Query string accepted by `GET /v1/users`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UserListParams {
+    /// Page size, 20 by default and at most 100.
+    pub limit: Option<usize>,
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+    pub role: Option<UserRole>,
+    /// Case-insensitive.
+    pub email_prefix: Option<String>,
+    /// Inclusive.
+    pub created_from: Option<DateTime<Utc>>,
+    /// Exclusive.
+    pub created_to: Option<DateTime<Utc>>,
+    /// `created_at` or `email`, prefixed with `-` for descending order.
+    pub sort: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum UserSortField {
+    CreatedAt,
+    Email,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct UserSort {
+    pub field: UserSortField,
+    pub direction: SortDirection,
+}
+
+impl Default for UserSort {
+    fn default() -> Self {
+        UserSort {
+            field: UserSortField::CreatedAt,
+            direction: SortDirection::Asc,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub enum UserSortValue {
+    CreatedAt(DateTime<Utc>),
+    Email(String),
+}
+
+impl UserSort {
+    /// Whitelisted sort fields, optionally prefixed with `-` for descending order.
+    pub fn parse(sort: &str) -> Result<Self, String> {
+        let (direction, field) = match sort.strip_prefix('-') {
+            Some(field) => (SortDirection::Desc, field),
+            None => (SortDirection::Asc, sort),
+        };
+
+        let field = match field {
+            "created_at" => UserSortField::CreatedAt,
+            "email" => UserSortField::Email,
+            _ => return Err(format!("Unsupported sort field {}, use created_at or email", field)),
+        };
+
+        Ok(UserSort { field, direction })
+    }
+
+    pub fn value_of(&self, user: &User) -> UserSortValue {
+        match self.field {
+            UserSortField::CreatedAt => UserSortValue::CreatedAt(user.created_at),
+            UserSortField::Email => UserSortValue::Email(user.email.to_string()),
+        }
+    }
+
+    /// Total order over (sort value, id); the id breaks ties so keysets never skip rows.
+    pub fn compare(&self, a: (&UserSortValue, &UserId), b: (&UserSortValue, &UserId)) -> Ordering {
+        let ordering = a
+            .0
+            .partial_cmp(b.0)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.1.cmp(b.1));
+
+        match self.direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UserFilter {
+    pub role: Option<UserRole>,
+    pub email_prefix: Option<String>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+}
+
+impl UserFilter {
+    /// `created_from` is inclusive and `created_to` exclusive. The email prefix ignores case.
+    pub fn matches(&self, user: &User) -> bool {
+        self.role.as_ref().is_none_or(|role| *role == user.role)
+            && self.email_prefix.as_ref().is_none_or(|prefix| {
+                user.email.as_str().starts_with(&prefix.to_lowercase())
+            })
+            && self.created_from.is_none_or(|from| user.created_at >= from)
+            && self.created_to.is_none_or(|to| user.created_at < to)
+    }
+}
+
+/// Position after the last row of a page. Clients receive it base64url-encoded and must
+/// treat it as opaque.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserCursor {
+    pub sort: UserSort,
+    pub value: UserSortValue,
+    pub id: UserId,
+}
+
+impl UserCursor {
+    pub fn encode(&self) -> Result<String, String> {
+        let json = serde_json::to_vec(self).map_err(|err| err.to_string())?;
+
+        Ok(URL_SAFE_NO_PAD.encode(json))
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self, String> {
+        URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .ok_or_else(|| "Invalid cursor".to_string())
+    }
+}
+
+/// Validated listing query handed to repositories. Backends return at most `limit + 1`
+/// users matching `filter`, ordered by `sort` and strictly after `after`.
+#[derive(Debug, Clone)]
+pub struct UserListQuery {
+    pub limit: usize,
+    pub after: Option<UserCursor>,
+    pub filter: UserFilter,
+    pub sort: UserSort,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
@@ -0,0 +1,21 @@
+use crate::model::user_model::{UserId, UserStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// One entry of a user's status history. `changed_by` is the acting user.
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UserStatusChange {
+    pub id: String,
+    pub user_id: UserId,
+    pub from: UserStatus,
+    pub to: UserStatus,
+    pub reason: String,
+    pub changed_by: UserId,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct UserStatusChangeRequest {
+    pub reason: String,
+}
@@ -0,0 +1,133 @@
+use crate::middleware::api_key_middleware::API_KEY_HEADER;
+use crate::router::{ApiDoc, AppState};
+use crate::util::deprecation::{Deprecation, find_deprecation};
+use axum::Json;
+use axum::extract::State;
+use axum::response::Html;
+use std::sync::OnceLock;
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::{Deprecated, OpenApi as OpenApiDocument, RefOr};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+pub const OPENAPI_PATH: &str = "/openapi.json";
+pub const DOCS_UI_PATH: &str = "/docs";
+
+/// Swagger UI loaded from a CDN, pointed at `OPENAPI_PATH`. Nothing is bundled into the
+/// binary, so the page needs internet access in the browser that opens it.
+const DOCS_UI_HTML: &str = r##"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>axum-api-starter API</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script>
+      SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+    </script>
+  </body>
+</html>
+"##;
+
+/// Registers the schemes operations refer to in their `security` lists.
+pub struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        // Cargo.toml declares no license, which would otherwise render as an empty one.
+        openapi.info.license = None;
+
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some(
+                        "Access token from a sign-in or the token endpoint, or a personal access token",
+                    ))
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "client_basic",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Basic)
+                    .description(Some("OAuth client id and secret"))
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+    }
+}
+
+/// Adds `request_id` to the `HttpResponse<T>` schemas. The field is filled in from the
+/// request scope when the response is written, so the struct does not carry it.
+pub struct Envelopes;
+
+impl Modify for Envelopes {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+
+        for (name, schema) in components.schemas.iter_mut() {
+            if let (true, RefOr::T(Schema::Object(object))) = (name.starts_with("HttpResponse"), schema) {
+                object.properties.insert(
+                    "request_id".to_string(),
+                    ObjectBuilder::new()
+                        .schema_type(Type::String)
+                        .description(Some("Echoes the X-Request-Id of the request"))
+                        .into(),
+                );
+            }
+        }
+    }
+}
+
+/// The generated document, built once.
+pub fn openapi() -> &'static OpenApiDocument {
+    static DOCUMENT: OnceLock<OpenApiDocument> = OnceLock::new();
+
+    DOCUMENT.get_or_init(ApiDoc::openapi)
+}
+
+/// Copy of `document` with the operations covered by `deprecations` marked deprecated, so
+/// generated clients flag them as well.
+pub fn with_deprecations(document: &OpenApiDocument, deprecations: &[Deprecation]) -> OpenApiDocument {
+    let mut document = document.clone();
+
+    for (path, item) in document.paths.paths.iter_mut() {
+        if find_deprecation(deprecations, path).is_none() {
+            continue;
+        }
+
+        let operations = [
+            &mut item.get,
+            &mut item.put,
+            &mut item.post,
+            &mut item.delete,
+            &mut item.patch,
+        ];
+        for operation in operations.into_iter().flatten() {
+            operation.deprecated = Some(Deprecated::True);
+        }
+    }
+
+    document
+}
+
+pub async fn render_openapi(State(state): State<AppState>) -> Json<OpenApiDocument> {
+    Json(with_deprecations(openapi(), &state.deprecations))
+}
+
+pub async fn render_docs_ui() -> Html<&'static str> {
+    Html(DOCS_UI_HTML)
+}
@@ -1 +1,11 @@
-pub mod user_repository;
\ No newline at end of file
+pub mod user_repository;
+pub mod passkey_repository;
+pub mod webauthn_challenge_repository;
+pub mod oidc_authorization_repository;
+pub mod oidc_identity_repository;
+pub mod oauth_client_repository;
+pub mod personal_access_token_repository;
+pub mod user_status_history_repository;
+
+/// Backend label for repository metrics recorded by the in-memory implementations.
+pub const IN_MEMORY_BACKEND: &str = "memory";
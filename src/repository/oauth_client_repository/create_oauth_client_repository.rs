@@ -0,0 +1,7 @@
+use crate::model::oauth_model::OAuthClient;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateOAuthClientRepository {
+    async fn execute(&self, client: OAuthClient) -> Result<OAuthClient, String>;
+}
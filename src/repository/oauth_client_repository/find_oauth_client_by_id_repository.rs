@@ -0,0 +1,7 @@
+use crate::model::oauth_model::OAuthClient;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindOAuthClientByIdRepository {
+    async fn execute(&self, id: String) -> Result<Option<OAuthClient>, String>;
+}
@@ -0,0 +1,37 @@
+use crate::model::oauth_model::OAuthClient;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::oauth_client_repository::create_oauth_client_repository::CreateOAuthClientRepository;
+use crate::repository::oauth_client_repository::find_oauth_client_by_id_repository::FindOAuthClientByIdRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryOAuthClientRepository {
+    clients: Arc<RwLock<HashMap<String, OAuthClient>>>,
+}
+
+#[async_trait]
+impl CreateOAuthClientRepository for InMemoryOAuthClientRepository {
+    async fn execute(&self, client: OAuthClient) -> Result<OAuthClient, String> {
+        let _timer = repository_timer("create_oauth_client", IN_MEMORY_BACKEND);
+
+        let mut clients = self.clients.write().await;
+        clients.insert(client.id.clone(), client.clone());
+
+        Ok(client)
+    }
+}
+
+#[async_trait]
+impl FindOAuthClientByIdRepository for InMemoryOAuthClientRepository {
+    async fn execute(&self, id: String) -> Result<Option<OAuthClient>, String> {
+        let _timer = repository_timer("find_oauth_client_by_id", IN_MEMORY_BACKEND);
+
+        let clients = self.clients.read().await;
+
+        Ok(clients.get(&id).cloned())
+    }
+}
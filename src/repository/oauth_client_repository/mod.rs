@@ -0,0 +1,3 @@
+pub mod create_oauth_client_repository;
+pub mod find_oauth_client_by_id_repository;
+pub mod in_memory_oauth_client_repository;
@@ -0,0 +1,7 @@
+use crate::model::oidc_model::OidcAuthorization;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateOidcAuthorizationRepository {
+    async fn execute(&self, authorization: OidcAuthorization) -> Result<(), String>;
+}
@@ -0,0 +1,37 @@
+use crate::model::oidc_model::OidcAuthorization;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::oidc_authorization_repository::create_oidc_authorization_repository::CreateOidcAuthorizationRepository;
+use crate::repository::oidc_authorization_repository::take_oidc_authorization_repository::TakeOidcAuthorizationRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Clone, Default)]
+pub struct InMemoryOidcAuthorizationRepository {
+    authorizations: Arc<Mutex<HashMap<String, OidcAuthorization>>>,
+}
+
+#[async_trait]
+impl CreateOidcAuthorizationRepository for InMemoryOidcAuthorizationRepository {
+    async fn execute(&self, authorization: OidcAuthorization) -> Result<(), String> {
+        let _timer = repository_timer("create_oidc_authorization", IN_MEMORY_BACKEND);
+
+        let mut authorizations = self.authorizations.lock().await;
+        authorizations.insert(authorization.state.clone(), authorization);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TakeOidcAuthorizationRepository for InMemoryOidcAuthorizationRepository {
+    async fn execute(&self, state: String) -> Result<Option<OidcAuthorization>, String> {
+        let _timer = repository_timer("take_oidc_authorization", IN_MEMORY_BACKEND);
+
+        let mut authorizations = self.authorizations.lock().await;
+
+        Ok(authorizations.remove(&state))
+    }
+}
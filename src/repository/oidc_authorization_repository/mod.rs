@@ -0,0 +1,3 @@
+pub mod create_oidc_authorization_repository;
+pub mod take_oidc_authorization_repository;
+pub mod in_memory_oidc_authorization_repository;
@@ -0,0 +1,8 @@
+use crate::model::oidc_model::OidcAuthorization;
+use async_trait::async_trait;
+
+/// Removes and returns the pending authorization, so a `state` value can only be redeemed once.
+#[async_trait]
+pub trait TakeOidcAuthorizationRepository {
+    async fn execute(&self, state: String) -> Result<Option<OidcAuthorization>, String>;
+}
@@ -0,0 +1,7 @@
+use crate::model::oidc_model::OidcIdentity;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateOidcIdentityRepository {
+    async fn execute(&self, identity: OidcIdentity) -> Result<(), String>;
+}
@@ -0,0 +1,7 @@
+use crate::model::oidc_model::OidcIdentity;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindOidcIdentityBySubjectRepository {
+    async fn execute(&self, issuer: String, subject: String) -> Result<Option<OidcIdentity>, String>;
+}
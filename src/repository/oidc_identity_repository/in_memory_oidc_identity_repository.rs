@@ -0,0 +1,63 @@
+use crate::model::oidc_model::OidcIdentity;
+use crate::model::user_model::UserId;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::oidc_identity_repository::create_oidc_identity_repository::CreateOidcIdentityRepository;
+use crate::repository::oidc_identity_repository::delete_oidc_identities_by_user_id_repository::DeleteOidcIdentitiesByUserIdRepository;
+use crate::repository::oidc_identity_repository::find_oidc_identity_by_subject_repository::FindOidcIdentityBySubjectRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryOidcIdentityRepository {
+    identities: Arc<RwLock<Vec<OidcIdentity>>>,
+}
+
+#[async_trait]
+impl CreateOidcIdentityRepository for InMemoryOidcIdentityRepository {
+    async fn execute(&self, identity: OidcIdentity) -> Result<(), String> {
+        let _timer = repository_timer("create_oidc_identity", IN_MEMORY_BACKEND);
+
+        let mut identities = self.identities.write().await;
+
+        if identities
+            .iter()
+            .any(|stored| stored.issuer == identity.issuer && stored.subject == identity.subject)
+        {
+            return Err(format!("Identity {} is already linked", identity.subject));
+        }
+
+        identities.push(identity);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FindOidcIdentityBySubjectRepository for InMemoryOidcIdentityRepository {
+    async fn execute(&self, issuer: String, subject: String) -> Result<Option<OidcIdentity>, String> {
+        let _timer = repository_timer("find_oidc_identity_by_subject", IN_MEMORY_BACKEND);
+
+        let identities = self.identities.read().await;
+
+        Ok(identities
+            .iter()
+            .find(|identity| identity.issuer == issuer && identity.subject == subject)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl DeleteOidcIdentitiesByUserIdRepository for InMemoryOidcIdentityRepository {
+    async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+        let _timer = repository_timer("delete_oidc_identities_by_user_id", IN_MEMORY_BACKEND);
+
+        let mut identities = self.identities.write().await;
+
+        let before = identities.len();
+        identities.retain(|identity| identity.user_id != user_id);
+
+        Ok(before - identities.len())
+    }
+}
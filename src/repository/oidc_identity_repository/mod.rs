@@ -0,0 +1,4 @@
+pub mod create_oidc_identity_repository;
+pub mod find_oidc_identity_by_subject_repository;
+pub mod delete_oidc_identities_by_user_id_repository;
+pub mod in_memory_oidc_identity_repository;
@@ -0,0 +1,7 @@
+use crate::model::passkey_model::{Passkey, PasskeyPublic};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreatePasskeyRepository {
+    async fn execute(&self, passkey: Passkey) -> Result<PasskeyPublic, String>;
+}
@@ -0,0 +1,8 @@
+use crate::model::user_model::UserId;
+use async_trait::async_trait;
+
+/// Returns how many rows were removed.
+#[async_trait]
+pub trait DeletePasskeysByUserIdRepository {
+    async fn execute(&self, user_id: UserId) -> Result<usize, String>;
+}
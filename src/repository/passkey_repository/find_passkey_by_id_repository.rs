@@ -0,0 +1,7 @@
+use crate::model::passkey_model::Passkey;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindPasskeyByIdRepository {
+    async fn execute(&self, id: String) -> Result<Option<Passkey>, String>;
+}
@@ -0,0 +1,8 @@
+use crate::model::passkey_model::Passkey;
+use async_trait::async_trait;
+use crate::model::user_model::UserId;
+
+#[async_trait]
+pub trait FindPasskeysByUserIdRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<Passkey>, String>;
+}
@@ -0,0 +1,92 @@
+use crate::model::passkey_model::{Passkey, PasskeyPublic};
+use crate::model::user_model::UserId;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::passkey_repository::create_passkey_repository::CreatePasskeyRepository;
+use crate::repository::passkey_repository::delete_passkeys_by_user_id_repository::DeletePasskeysByUserIdRepository;
+use crate::repository::passkey_repository::find_passkey_by_id_repository::FindPasskeyByIdRepository;
+use crate::repository::passkey_repository::find_passkeys_by_user_id_repository::FindPasskeysByUserIdRepository;
+use crate::repository::passkey_repository::update_passkey_repository::UpdatePasskeyRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryPasskeyRepository {
+    passkeys: Arc<RwLock<HashMap<String, Passkey>>>,
+}
+
+#[async_trait]
+impl CreatePasskeyRepository for InMemoryPasskeyRepository {
+    async fn execute(&self, passkey: Passkey) -> Result<PasskeyPublic, String> {
+        let _timer = repository_timer("create_passkey", IN_MEMORY_BACKEND);
+
+        let mut passkeys = self.passkeys.write().await;
+
+        if passkeys.contains_key(&passkey.id) {
+            return Err(format!("Passkey {} already exists", passkey.id));
+        }
+
+        passkeys.insert(passkey.id.clone(), passkey.clone());
+
+        Ok(PasskeyPublic::from(passkey))
+    }
+}
+
+#[async_trait]
+impl FindPasskeyByIdRepository for InMemoryPasskeyRepository {
+    async fn execute(&self, id: String) -> Result<Option<Passkey>, String> {
+        let _timer = repository_timer("find_passkey_by_id", IN_MEMORY_BACKEND);
+
+        let passkeys = self.passkeys.read().await;
+
+        Ok(passkeys.get(&id).cloned())
+    }
+}
+
+#[async_trait]
+impl FindPasskeysByUserIdRepository for InMemoryPasskeyRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<Passkey>, String> {
+        let _timer = repository_timer("find_passkeys_by_user_id", IN_MEMORY_BACKEND);
+
+        let passkeys = self.passkeys.read().await;
+
+        Ok(passkeys
+            .values()
+            .filter(|passkey| passkey.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait]
+impl UpdatePasskeyRepository for InMemoryPasskeyRepository {
+    async fn execute(&self, passkey: Passkey) -> Result<(), String> {
+        let _timer = repository_timer("update_passkey", IN_MEMORY_BACKEND);
+
+        let mut passkeys = self.passkeys.write().await;
+
+        match passkeys.get_mut(&passkey.id) {
+            Some(stored) => {
+                *stored = passkey;
+                Ok(())
+            }
+            None => Err(format!("Passkey {} not found", passkey.id)),
+        }
+    }
+}
+
+#[async_trait]
+impl DeletePasskeysByUserIdRepository for InMemoryPasskeyRepository {
+    async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+        let _timer = repository_timer("delete_passkeys_by_user_id", IN_MEMORY_BACKEND);
+
+        let mut passkeys = self.passkeys.write().await;
+
+        let before = passkeys.len();
+        passkeys.retain(|_, passkey| passkey.user_id != user_id);
+
+        Ok(before - passkeys.len())
+    }
+}
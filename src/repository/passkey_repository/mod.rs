@@ -0,0 +1,6 @@
+pub mod create_passkey_repository;
+pub mod find_passkey_by_id_repository;
+pub mod find_passkeys_by_user_id_repository;
+pub mod update_passkey_repository;
+pub mod delete_passkeys_by_user_id_repository;
+pub mod in_memory_passkey_repository;
@@ -0,0 +1,7 @@
+use crate::model::passkey_model::Passkey;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait UpdatePasskeyRepository {
+    async fn execute(&self, passkey: Passkey) -> Result<(), String>;
+}
@@ -0,0 +1,8 @@
+use crate::model::user_model::UserId;
+use async_trait::async_trait;
+
+/// Returns how many rows were removed.
+#[async_trait]
+pub trait DeletePersonalAccessTokensByUserIdRepository {
+    async fn execute(&self, user_id: UserId) -> Result<usize, String>;
+}
@@ -0,0 +1,7 @@
+use crate::model::personal_access_token_model::PersonalAccessToken;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindPersonalAccessTokenByIdRepository {
+    async fn execute(&self, id: String) -> Result<Option<PersonalAccessToken>, String>;
+}
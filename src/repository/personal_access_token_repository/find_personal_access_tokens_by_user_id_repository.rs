@@ -0,0 +1,8 @@
+use crate::model::personal_access_token_model::PersonalAccessToken;
+use async_trait::async_trait;
+use crate::model::user_model::UserId;
+
+#[async_trait]
+pub trait FindPersonalAccessTokensByUserIdRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<PersonalAccessToken>, String>;
+}
@@ -0,0 +1,110 @@
+use crate::model::personal_access_token_model::PersonalAccessToken;
+use crate::model::user_model::UserId;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::personal_access_token_repository::create_personal_access_token_repository::CreatePersonalAccessTokenRepository;
+use crate::repository::personal_access_token_repository::delete_personal_access_tokens_by_user_id_repository::DeletePersonalAccessTokensByUserIdRepository;
+use crate::repository::personal_access_token_repository::find_personal_access_token_by_hash_repository::FindPersonalAccessTokenByHashRepository;
+use crate::repository::personal_access_token_repository::find_personal_access_token_by_id_repository::FindPersonalAccessTokenByIdRepository;
+use crate::repository::personal_access_token_repository::find_personal_access_tokens_by_user_id_repository::FindPersonalAccessTokensByUserIdRepository;
+use crate::repository::personal_access_token_repository::update_personal_access_token_repository::UpdatePersonalAccessTokenRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryPersonalAccessTokenRepository {
+    tokens: Arc<RwLock<HashMap<String, PersonalAccessToken>>>,
+}
+
+#[async_trait]
+impl CreatePersonalAccessTokenRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, token: PersonalAccessToken) -> Result<PersonalAccessToken, String> {
+        let _timer = repository_timer("create_personal_access_token", IN_MEMORY_BACKEND);
+
+        let mut tokens = self.tokens.write().await;
+
+        if tokens.contains_key(&token.id) {
+            return Err(format!("Personal access token {} already exists", token.id));
+        }
+
+        tokens.insert(token.id.clone(), token.clone());
+
+        Ok(token)
+    }
+}
+
+#[async_trait]
+impl FindPersonalAccessTokenByIdRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, id: String) -> Result<Option<PersonalAccessToken>, String> {
+        let _timer = repository_timer("find_personal_access_token_by_id", IN_MEMORY_BACKEND);
+
+        let tokens = self.tokens.read().await;
+
+        Ok(tokens.get(&id).cloned())
+    }
+}
+
+#[async_trait]
+impl FindPersonalAccessTokenByHashRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, token_hash: String) -> Result<Option<PersonalAccessToken>, String> {
+        let _timer = repository_timer("find_personal_access_token_by_hash", IN_MEMORY_BACKEND);
+
+        let tokens = self.tokens.read().await;
+
+        Ok(tokens
+            .values()
+            .find(|token| *token.token_hash.expose() == token_hash)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl FindPersonalAccessTokensByUserIdRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<PersonalAccessToken>, String> {
+        let _timer = repository_timer("find_personal_access_tokens_by_user_id", IN_MEMORY_BACKEND);
+
+        let tokens = self.tokens.read().await;
+
+        let mut found: Vec<PersonalAccessToken> = tokens
+            .values()
+            .filter(|token| token.user_id == user_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|token| token.created_at);
+
+        Ok(found)
+    }
+}
+
+#[async_trait]
+impl UpdatePersonalAccessTokenRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, token: PersonalAccessToken) -> Result<(), String> {
+        let _timer = repository_timer("update_personal_access_token", IN_MEMORY_BACKEND);
+
+        let mut tokens = self.tokens.write().await;
+
+        match tokens.get_mut(&token.id) {
+            Some(stored) => {
+                *stored = token;
+                Ok(())
+            }
+            None => Err(format!("Personal access token {} not found", token.id)),
+        }
+    }
+}
+
+#[async_trait]
+impl DeletePersonalAccessTokensByUserIdRepository for InMemoryPersonalAccessTokenRepository {
+    async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+        let _timer = repository_timer("delete_personal_access_tokens_by_user_id", IN_MEMORY_BACKEND);
+
+        let mut tokens = self.tokens.write().await;
+
+        let before = tokens.len();
+        tokens.retain(|_, token| token.user_id != user_id);
+
+        Ok(before - tokens.len())
+    }
+}
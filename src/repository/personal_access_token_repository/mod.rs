@@ -0,0 +1,7 @@
+pub mod create_personal_access_token_repository;
+pub mod find_personal_access_token_by_id_repository;
+pub mod find_personal_access_token_by_hash_repository;
+pub mod find_personal_access_tokens_by_user_id_repository;
+pub mod update_personal_access_token_repository;
+pub mod delete_personal_access_tokens_by_user_id_repository;
+pub mod in_memory_personal_access_token_repository;
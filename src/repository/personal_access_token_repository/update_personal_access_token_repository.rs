@@ -0,0 +1,7 @@
+use crate::model::personal_access_token_model::PersonalAccessToken;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait UpdatePersonalAccessTokenRepository {
+    async fn execute(&self, token: PersonalAccessToken) -> Result<(), String>;
+}
@@ -1,7 +1,14 @@
 use crate::model::user_model::{User, UserPublic};
 use async_trait::async_trait;
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum CreateUserError {
+    /// Another live user already holds the email; checked in the same write as the insert.
+    DuplicateEmail,
+    Storage(String),
+}
+
 #[async_trait]
 pub trait CreateUserRepository {
-    async fn execute(&self, user: User) -> Result<UserPublic, String>;
-}
\ No newline at end of file
+    async fn execute(&self, user: User) -> Result<UserPublic, CreateUserError>;
+}
@@ -0,0 +1,8 @@
+use crate::model::email_model::Email;
+use crate::model::user_model::User;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindDeletedUserByEmailRepository {
+    async fn execute(&self, email: Email) -> Result<Option<User>, String>;
+}
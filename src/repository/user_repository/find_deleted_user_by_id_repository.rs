@@ -0,0 +1,7 @@
+use crate::model::user_model::{User, UserId};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindDeletedUserByIdRepository {
+    async fn execute(&self, id: UserId) -> Result<Option<User>, String>;
+}
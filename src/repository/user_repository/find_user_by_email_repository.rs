@@ -1,7 +1,9 @@
+use crate::model::email_model::Email;
 use crate::model::user_model::User;
 use async_trait::async_trait;
 
+/// Soft-deleted users are not returned.
 #[async_trait]
 pub trait FindUserByEmailRepository {
-    async fn execute(&self, email: String) -> Result<Option<User>, String>;
+    async fn execute(&self, email: Email) -> Result<Option<User>, String>;
 }
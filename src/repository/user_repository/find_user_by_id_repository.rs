@@ -0,0 +1,8 @@
+use crate::model::user_model::{User, UserId};
+use async_trait::async_trait;
+
+/// Soft-deleted users are not returned.
+#[async_trait]
+pub trait FindUserByIdRepository {
+    async fn execute(&self, id: UserId) -> Result<Option<User>, String>;
+}
@@ -0,0 +1,9 @@
+use crate::model::user_model::UserId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Ids of users soft-deleted before `deleted_before`.
+#[async_trait]
+pub trait FindUsersToPurgeRepository {
+    async fn execute(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>, String>;
+}
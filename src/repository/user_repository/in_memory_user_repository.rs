@@ -0,0 +1,215 @@
+use crate::model::email_model::Email;
+use crate::model::user_model::{User, UserId, UserPublic};
+use crate::model::user_query_model::UserListQuery;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::user_repository::create_user_repository::{CreateUserError, CreateUserRepository};
+use crate::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
+use crate::repository::user_repository::find_deleted_user_by_id_repository::FindDeletedUserByIdRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::find_users_to_purge_repository::FindUsersToPurgeRepository;
+use crate::repository::user_repository::list_users_repository::ListUsersRepository;
+use crate::repository::user_repository::purge_deleted_user_repository::PurgeDeletedUserRepository;
+use crate::repository::user_repository::soft_delete_user_repository::SoftDeleteUserRepository;
+use crate::repository::user_repository::update_user_repository::{UpdateUserError, UpdateUserRepository};
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Default)]
+pub struct InMemoryUserRepository {
+    users: Arc<RwLock<HashMap<UserId, User>>>,
+}
+
+#[async_trait]
+impl CreateUserRepository for InMemoryUserRepository {
+    async fn execute(&self, user: User) -> Result<UserPublic, CreateUserError> {
+        let _timer = repository_timer("create_user", IN_MEMORY_BACKEND);
+
+        let mut users = self.users.write().await;
+
+        // Checked under the write lock, so concurrent signups cannot both pass.
+        if users
+            .values()
+            .any(|existing| existing.deleted_at.is_none() && existing.email == user.email)
+        {
+            return Err(CreateUserError::DuplicateEmail);
+        }
+
+        users.insert(user.id.clone(), user.clone());
+
+        Ok(UserPublic::from(user))
+    }
+}
+
+#[async_trait]
+impl FindUserByEmailRepository for InMemoryUserRepository {
+    async fn execute(&self, email: Email) -> Result<Option<User>, String> {
+        let _timer = repository_timer("find_user_by_email", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+
+        Ok(users
+            .values()
+            .find(|user| user.deleted_at.is_none() && user.email == email)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl FindUserByIdRepository for InMemoryUserRepository {
+    async fn execute(&self, id: UserId) -> Result<Option<User>, String> {
+        let _timer = repository_timer("find_user_by_id", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+
+        Ok(users.get(&id).filter(|user| user.deleted_at.is_none()).cloned())
+    }
+}
+
+#[async_trait]
+impl FindDeletedUserByIdRepository for InMemoryUserRepository {
+    async fn execute(&self, id: UserId) -> Result<Option<User>, String> {
+        let _timer = repository_timer("find_deleted_user_by_id", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+
+        Ok(users.get(&id).filter(|user| user.deleted_at.is_some()).cloned())
+    }
+}
+
+#[async_trait]
+impl FindDeletedUserByEmailRepository for InMemoryUserRepository {
+    async fn execute(&self, email: Email) -> Result<Option<User>, String> {
+        let _timer = repository_timer("find_deleted_user_by_email", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+
+        Ok(users
+            .values()
+            .find(|user| user.deleted_at.is_some() && user.email == email)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl ListUsersRepository for InMemoryUserRepository {
+    async fn execute(&self, query: UserListQuery) -> Result<Vec<User>, String> {
+        let _timer = repository_timer("list_users", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+        let sort = query.sort;
+
+        let mut listed: Vec<User> = users
+            .values()
+            .filter(|user| user.deleted_at.is_none() && query.filter.matches(user))
+            .filter(|user| {
+                query.after.as_ref().is_none_or(|after| {
+                    sort.compare((&sort.value_of(user), &user.id), (&after.value, &after.id))
+                        == Ordering::Greater
+                })
+            })
+            .cloned()
+            .collect();
+
+        listed.sort_by(|a, b| sort.compare((&sort.value_of(a), &a.id), (&sort.value_of(b), &b.id)));
+        listed.truncate(query.limit + 1);
+
+        Ok(listed)
+    }
+}
+
+#[async_trait]
+impl UpdateUserRepository for InMemoryUserRepository {
+    async fn execute(
+        &self,
+        user: User,
+        expected_version: u64,
+    ) -> Result<Option<UserPublic>, UpdateUserError> {
+        let _timer = repository_timer("update_user", IN_MEMORY_BACKEND);
+
+        let mut users = self.users.write().await;
+
+        // Same rule as on create, under the same lock: email changes and restores race too.
+        if user.deleted_at.is_none()
+            && users.values().any(|existing| {
+                existing.id != user.id && existing.deleted_at.is_none() && existing.email == user.email
+            })
+        {
+            return Err(UpdateUserError::DuplicateEmail);
+        }
+
+        match users.get_mut(&user.id).filter(|stored| stored.version == expected_version) {
+            Some(stored) => {
+                *stored = User {
+                    version: expected_version + 1,
+                    ..user
+                };
+                Ok(Some(UserPublic::from(stored.clone())))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl SoftDeleteUserRepository for InMemoryUserRepository {
+    async fn execute(
+        &self,
+        id: UserId,
+        expected_version: u64,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<Option<User>, String> {
+        let _timer = repository_timer("soft_delete_user", IN_MEMORY_BACKEND);
+
+        let mut users = self.users.write().await;
+
+        match users
+            .get_mut(&id)
+            .filter(|user| user.deleted_at.is_none() && user.version == expected_version)
+        {
+            Some(user) => {
+                user.deleted_at = Some(deleted_at);
+                user.version += 1;
+                Ok(Some(user.clone()))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl FindUsersToPurgeRepository for InMemoryUserRepository {
+    async fn execute(&self, deleted_before: DateTime<Utc>) -> Result<Vec<UserId>, String> {
+        let _timer = repository_timer("find_users_to_purge", IN_MEMORY_BACKEND);
+
+        let users = self.users.read().await;
+
+        Ok(users
+            .values()
+            .filter(|user| user.deleted_at.is_some_and(|deleted_at| deleted_at < deleted_before))
+            .map(|user| user.id.clone())
+            .collect())
+    }
+}
+
+#[async_trait]
+impl PurgeDeletedUserRepository for InMemoryUserRepository {
+    async fn execute(&self, user_id: UserId) -> Result<bool, String> {
+        let _timer = repository_timer("purge_deleted_user", IN_MEMORY_BACKEND);
+
+        let mut users = self.users.write().await;
+
+        if users.get(&user_id).is_none_or(|user| user.deleted_at.is_none()) {
+            return Ok(false);
+        }
+
+        users.remove(&user_id);
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,8 @@
+use crate::model::user_model::User;
+use crate::model::user_query_model::UserListQuery;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ListUsersRepository {
+    async fn execute(&self, query: UserListQuery) -> Result<Vec<User>, String>;
+}
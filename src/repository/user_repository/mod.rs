@@ -1,2 +1,11 @@
 pub mod create_user_repository;
-pub mod find_user_by_email_repository;
\ No newline at end of file
+pub mod find_user_by_email_repository;
+pub mod find_user_by_id_repository;
+pub mod find_deleted_user_by_id_repository;
+pub mod find_deleted_user_by_email_repository;
+pub mod list_users_repository;
+pub mod update_user_repository;
+pub mod soft_delete_user_repository;
+pub mod find_users_to_purge_repository;
+pub mod purge_deleted_user_repository;
+pub mod in_memory_user_repository;
@@ -0,0 +1,9 @@
+use crate::model::user_model::UserId;
+use async_trait::async_trait;
+
+/// Permanently removes a soft-deleted user. Returns `false` when the user is no longer
+/// soft-deleted, for instance because it was restored after being selected for the purge.
+#[async_trait]
+pub trait PurgeDeletedUserRepository {
+    async fn execute(&self, user_id: UserId) -> Result<bool, String>;
+}
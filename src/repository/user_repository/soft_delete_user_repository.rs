@@ -0,0 +1,16 @@
+use crate::model::user_model::{User, UserId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Marks the user as deleted; the row is only removed by `PurgeDeletedUserRepository`.
+/// Like `UpdateUserRepository`, it returns `None` unless the version still equals
+/// `expected_version`.
+#[async_trait]
+pub trait SoftDeleteUserRepository {
+    async fn execute(
+        &self,
+        id: UserId,
+        expected_version: u64,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<Option<User>, String>;
+}
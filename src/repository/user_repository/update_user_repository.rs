@@ -0,0 +1,21 @@
+use crate::model::user_model::{User, UserPublic};
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateUserError {
+    /// The update would give a live user the email another live user already holds; checked
+    /// in the same write as the update.
+    DuplicateEmail,
+    Storage(String),
+}
+
+/// Replaces the stored user only while its version still equals `expected_version`, bumping
+/// it in the same write. Returns `None` when the user is gone or was modified in between.
+#[async_trait]
+pub trait UpdateUserRepository {
+    async fn execute(
+        &self,
+        user: User,
+        expected_version: u64,
+    ) -> Result<Option<UserPublic>, UpdateUserError>;
+}
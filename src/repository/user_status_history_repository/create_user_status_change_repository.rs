@@ -0,0 +1,7 @@
+use crate::model::user_status_model::UserStatusChange;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateUserStatusChangeRepository {
+    async fn execute(&self, change: UserStatusChange) -> Result<UserStatusChange, String>;
+}
@@ -0,0 +1,8 @@
+use crate::model::user_model::UserId;
+use crate::model::user_status_model::UserStatusChange;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FindUserStatusChangesByUserIdRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<UserStatusChange>, String>;
+}
@@ -0,0 +1,42 @@
+use crate::model::user_model::UserId;
+use crate::model::user_status_model::UserStatusChange;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::user_status_history_repository::create_user_status_change_repository::CreateUserStatusChangeRepository;
+use crate::repository::user_status_history_repository::find_user_status_changes_by_user_id_repository::FindUserStatusChangesByUserIdRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// Append-only: history entries are never updated or removed.
+#[derive(Clone, Default)]
+pub struct InMemoryUserStatusHistoryRepository {
+    changes: Arc<RwLock<Vec<UserStatusChange>>>,
+}
+
+#[async_trait]
+impl CreateUserStatusChangeRepository for InMemoryUserStatusHistoryRepository {
+    async fn execute(&self, change: UserStatusChange) -> Result<UserStatusChange, String> {
+        let _timer = repository_timer("create_user_status_change", IN_MEMORY_BACKEND);
+
+        let mut changes = self.changes.write().await;
+        changes.push(change.clone());
+
+        Ok(change)
+    }
+}
+
+#[async_trait]
+impl FindUserStatusChangesByUserIdRepository for InMemoryUserStatusHistoryRepository {
+    async fn execute(&self, user_id: UserId) -> Result<Vec<UserStatusChange>, String> {
+        let _timer = repository_timer("find_user_status_changes_by_user_id", IN_MEMORY_BACKEND);
+
+        let changes = self.changes.read().await;
+
+        Ok(changes
+            .iter()
+            .filter(|change| change.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_user_status_change_repository;
+pub mod find_user_status_changes_by_user_id_repository;
+pub mod in_memory_user_status_history_repository;
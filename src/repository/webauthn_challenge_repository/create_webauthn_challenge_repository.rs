@@ -0,0 +1,7 @@
+use crate::model::passkey_model::WebauthnChallenge;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateWebauthnChallengeRepository {
+    async fn execute(&self, challenge: WebauthnChallenge) -> Result<(), String>;
+}
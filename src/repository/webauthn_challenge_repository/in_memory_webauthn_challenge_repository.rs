@@ -0,0 +1,37 @@
+use crate::model::passkey_model::WebauthnChallenge;
+use crate::repository::IN_MEMORY_BACKEND;
+use crate::repository::webauthn_challenge_repository::create_webauthn_challenge_repository::CreateWebauthnChallengeRepository;
+use crate::repository::webauthn_challenge_repository::take_webauthn_challenge_repository::TakeWebauthnChallengeRepository;
+use crate::util::metrics::repository_timer;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Clone, Default)]
+pub struct InMemoryWebauthnChallengeRepository {
+    challenges: Arc<Mutex<HashMap<String, WebauthnChallenge>>>,
+}
+
+#[async_trait]
+impl CreateWebauthnChallengeRepository for InMemoryWebauthnChallengeRepository {
+    async fn execute(&self, challenge: WebauthnChallenge) -> Result<(), String> {
+        let _timer = repository_timer("create_webauthn_challenge", IN_MEMORY_BACKEND);
+
+        let mut challenges = self.challenges.lock().await;
+        challenges.insert(challenge.id.clone(), challenge);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TakeWebauthnChallengeRepository for InMemoryWebauthnChallengeRepository {
+    async fn execute(&self, id: String) -> Result<Option<WebauthnChallenge>, String> {
+        let _timer = repository_timer("take_webauthn_challenge", IN_MEMORY_BACKEND);
+
+        let mut challenges = self.challenges.lock().await;
+
+        Ok(challenges.remove(&id))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_webauthn_challenge_repository;
+pub mod take_webauthn_challenge_repository;
+pub mod in_memory_webauthn_challenge_repository;
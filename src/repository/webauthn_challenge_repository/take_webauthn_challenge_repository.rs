@@ -0,0 +1,8 @@
+use crate::model::passkey_model::WebauthnChallenge;
+use async_trait::async_trait;
+
+/// Removes and returns the challenge, so a ceremony can only be finished once.
+#[async_trait]
+pub trait TakeWebauthnChallengeRepository {
+    async fn execute(&self, id: String) -> Result<Option<WebauthnChallenge>, String>;
+}
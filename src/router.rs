@@ -0,0 +1,831 @@
+use crate::handler::auth_handler::authenticate_handler::AuthenticateHandler;
+use crate::handler::health_handler::get_liveness_handler::GetLivenessHandler;
+use crate::handler::health_handler::get_readiness_handler::GetReadinessHandler;
+use crate::handler::oauth_handler::create_oauth_client_handler::CreateOAuthClientHandler;
+use crate::handler::oauth_handler::introspect_token_handler::IntrospectTokenHandler;
+use crate::handler::oauth_handler::issue_token_handler::IssueTokenHandler;
+use crate::handler::oidc_handler::finish_oidc_login_handler::FinishOidcLoginHandler;
+use crate::handler::oidc_handler::start_oidc_login_handler::StartOidcLoginHandler;
+use crate::handler::passkey_handler::finish_passkey_authentication_handler::FinishPasskeyAuthenticationHandler;
+use crate::handler::passkey_handler::finish_passkey_registration_handler::FinishPasskeyRegistrationHandler;
+use crate::handler::passkey_handler::start_passkey_authentication_handler::StartPasskeyAuthenticationHandler;
+use crate::handler::passkey_handler::start_passkey_registration_handler::StartPasskeyRegistrationHandler;
+use crate::handler::personal_access_token_handler::create_personal_access_token_handler::CreatePersonalAccessTokenHandler;
+use crate::handler::personal_access_token_handler::list_personal_access_tokens_handler::ListPersonalAccessTokensHandler;
+use crate::handler::personal_access_token_handler::revoke_personal_access_token_handler::RevokePersonalAccessTokenHandler;
+use crate::handler::token_handler::get_jwks_handler::GetJwksHandler;
+use crate::handler::user_handler::create_user_handler::CreateUserHandler;
+use crate::handler::user_handler::change_user_status_handler::ChangeUserStatusHandler;
+use crate::handler::user_handler::delete_user_handler::DeleteUserHandler;
+use crate::handler::user_handler::get_current_user_handler::GetCurrentUserHandler;
+use crate::handler::user_handler::get_user_by_id_handler::GetUserByIdHandler;
+use crate::handler::user_handler::get_user_status_history_handler::GetUserStatusHistoryHandler;
+use crate::handler::user_handler::list_users_handler::ListUsersHandler;
+use crate::handler::user_handler::purge_deleted_users_handler::PurgeDeletedUsersHandler;
+use crate::handler::user_handler::restore_user_handler::RestoreUserHandler;
+use crate::handler::user_handler::update_user_handler::UpdateUserHandler;
+use crate::middleware::api_key_middleware::require_api_key;
+use crate::middleware::deprecation_middleware::mark_deprecated;
+use crate::middleware::metrics_middleware::track_http_metrics;
+use crate::middleware::request_id_middleware::assign_request_id;
+use crate::middleware::request_span_middleware::trace_request;
+use crate::model::health_model::{Liveness, Readiness};
+use crate::model::oauth_model::{
+    IntrospectionRequest, IntrospectionResponse, OAuthClientCreated, OAuthClientForCreation, OAuthTokenRequest,
+    OAuthTokenResponse,
+};
+use crate::model::oidc_model::{OidcCallback, OidcLoginStart};
+use crate::model::passkey_model::{
+    PasskeyAuthenticationFinish, PasskeyAuthenticationOptions, PasskeyAuthenticationStart, PasskeyPublic,
+    PasskeyRegistrationFinish, PasskeyRegistrationOptions, PasskeyRegistrationStart,
+};
+use crate::model::personal_access_token_model::{
+    PersonalAccessTokenCreated, PersonalAccessTokenForCreation, PersonalAccessTokenPublic,
+};
+use crate::model::principal_model::Principal;
+use crate::model::token_model::{Jwks, UserSession};
+use crate::model::user_model::UserStatus;
+use crate::model::user_model::{UserForCreation, UserForUpdate, UserId, UserPublic, UserPurgeResult};
+use crate::model::user_query_model::{Page, UserListParams};
+use crate::model::user_status_model::{UserStatusChange, UserStatusChangeRequest};
+use crate::openapi::{DOCS_UI_PATH, Envelopes, OPENAPI_PATH, SecuritySchemes, render_docs_ui, render_openapi};
+use crate::service::oidc_service::start_oidc_login_service::AUTHORIZATION_TIMEOUT_SECONDS;
+use crate::util::app_error::AppError;
+use crate::util::deprecation::Deprecation;
+use crate::util::etag::format_etag;
+use crate::util::extract::{ApiJson, ApiQuery, OAuthForm};
+use crate::util::http_status_code::HttpResponse;
+use crate::util::oauth_error::OAuthError;
+use crate::util::oidc::STATE_COOKIE;
+use crate::util::scope;
+use crate::util::secret::Secret;
+use crate::util::shutdown::ShutdownSignal;
+use crate::util::uuid_generator::UuidGenerator;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, COOKIE, ETAG, IF_MATCH, SET_COOKIE};
+use axum::middleware;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post};
+use axum::Router;
+use metrics_exporter_prometheus::PrometheusHandle;
+use std::sync::Arc;
+use utoipa::OpenApi;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub create_user_handler: Arc<dyn CreateUserHandler + Send + Sync + 'static>,
+    pub start_passkey_registration_handler: Arc<dyn StartPasskeyRegistrationHandler + Send + Sync + 'static>,
+    pub finish_passkey_registration_handler: Arc<dyn FinishPasskeyRegistrationHandler + Send + Sync + 'static>,
+    pub start_passkey_authentication_handler: Arc<dyn StartPasskeyAuthenticationHandler + Send + Sync + 'static>,
+    pub finish_passkey_authentication_handler: Arc<dyn FinishPasskeyAuthenticationHandler + Send + Sync + 'static>,
+    pub start_oidc_login_handler: Arc<dyn StartOidcLoginHandler + Send + Sync + 'static>,
+    pub finish_oidc_login_handler: Arc<dyn FinishOidcLoginHandler + Send + Sync + 'static>,
+    pub create_oauth_client_handler: Arc<dyn CreateOAuthClientHandler + Send + Sync + 'static>,
+    pub issue_token_handler: Arc<dyn IssueTokenHandler + Send + Sync + 'static>,
+    pub introspect_token_handler: Arc<dyn IntrospectTokenHandler + Send + Sync + 'static>,
+    pub get_jwks_handler: Arc<dyn GetJwksHandler + Send + Sync + 'static>,
+    pub get_liveness_handler: Arc<dyn GetLivenessHandler + Send + Sync + 'static>,
+    pub get_readiness_handler: Arc<dyn GetReadinessHandler + Send + Sync + 'static>,
+    pub authenticate_handler: Arc<dyn AuthenticateHandler + Send + Sync + 'static>,
+    pub get_current_user_handler: Arc<dyn GetCurrentUserHandler + Send + Sync + 'static>,
+    pub get_user_by_id_handler: Arc<dyn GetUserByIdHandler + Send + Sync + 'static>,
+    pub list_users_handler: Arc<dyn ListUsersHandler + Send + Sync + 'static>,
+    pub update_user_handler: Arc<dyn UpdateUserHandler + Send + Sync + 'static>,
+    pub delete_user_handler: Arc<dyn DeleteUserHandler + Send + Sync + 'static>,
+    pub restore_user_handler: Arc<dyn RestoreUserHandler + Send + Sync + 'static>,
+    pub purge_deleted_users_handler: Arc<dyn PurgeDeletedUsersHandler + Send + Sync + 'static>,
+    pub change_user_status_handler: Arc<dyn ChangeUserStatusHandler + Send + Sync + 'static>,
+    pub get_user_status_history_handler: Arc<dyn GetUserStatusHistoryHandler + Send + Sync + 'static>,
+    pub create_personal_access_token_handler: Arc<dyn CreatePersonalAccessTokenHandler + Send + Sync + 'static>,
+    pub list_personal_access_tokens_handler: Arc<dyn ListPersonalAccessTokensHandler + Send + Sync + 'static>,
+    pub revoke_personal_access_token_handler: Arc<dyn RevokePersonalAccessTokenHandler + Send + Sync + 'static>,
+    pub api_key: Option<Secret<String>>,
+    pub request_id_generator: Arc<dyn UuidGenerator + Send + Sync + 'static>,
+    pub metrics_handle: PrometheusHandle,
+    pub shutdown: ShutdownSignal,
+    pub deprecations: Arc<Vec<Deprecation>>,
+    pub docs_ui_enabled: bool,
+    /// Adds `Secure` to cookies; off only when the app is served over plain http.
+    pub secure_cookies: bool,
+}
+
+/// Every operation served by `create_router`. A route missing here fails the
+/// `openapi_api_test` suite, which compares this list against the router.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "axum-api-starter", description = "User accounts, passkeys, OpenID Connect sign-in and OAuth client credentials."),
+    paths(
+        hello,
+        get_liveness,
+        get_readiness,
+        render_metrics,
+        create_user,
+        list_users,
+        get_user_by_id,
+        update_user,
+        delete_user,
+        restore_user,
+        suspend_user,
+        reactivate_user,
+        deactivate_user,
+        get_user_status_history,
+        get_current_user,
+        create_personal_access_token,
+        list_personal_access_tokens,
+        revoke_personal_access_token,
+        start_passkey_registration,
+        finish_passkey_registration,
+        start_passkey_authentication,
+        finish_passkey_authentication,
+        start_oidc_login,
+        finish_oidc_login,
+        issue_token,
+        introspect_token,
+        get_jwks,
+        create_oauth_client,
+        purge_deleted_users,
+    ),
+    modifiers(&SecuritySchemes, &Envelopes),
+    tags(
+        (name = "users", description = "Accounts and their lifecycle"),
+        (name = "personal access tokens", description = "Long-lived tokens owned by the caller"),
+        (name = "passkeys", description = "WebAuthn registration and sign-in"),
+        (name = "oidc", description = "Sign-in through the configured OpenID Connect provider"),
+        (name = "oauth", description = "Client credentials grant, token introspection and signing keys"),
+        (name = "admin", description = "Operator endpoints guarded by the static API key"),
+        (name = "operations", description = "Health, metrics and other endpoints for the platform"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Prefix of the first API version. A `/v2` gets its own routes function, so DTO changes
+/// there leave `/v1` clients untouched.
+pub const API_V1: &str = "/v1";
+
+pub fn create_router(state: AppState) -> Router {
+    let mut router = Router::new()
+        .route("/", get(hello))
+        .route("/.well-known/jwks.json", get(get_jwks))
+        .route("/health/live", get(get_liveness))
+        .route("/health/ready", get(get_readiness))
+        .nest(API_V1, v1_routes(&state))
+        .route("/metrics", get(render_metrics))
+        .route(OPENAPI_PATH, get(render_openapi));
+
+    if state.docs_ui_enabled {
+        router = router.route(DOCS_UI_PATH, get(render_docs_ui));
+    }
+
+    router
+        .route_layer(middleware::from_fn_with_state(state.clone(), mark_deprecated))
+        .route_layer(middleware::from_fn(track_http_metrics))
+        // The last layer runs first, so the request id exists before the span is opened.
+        .layer(middleware::from_fn(trace_request))
+        .layer(middleware::from_fn_with_state(state.clone(), assign_request_id))
+        .with_state(state)
+}
+
+fn v1_routes(state: &AppState) -> Router<AppState> {
+    let admin_routes = Router::new()
+        .route("/oauth/clients", post(create_oauth_client))
+        .route("/users/purge", post(purge_deleted_users))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_api_key));
+
+    Router::new()
+        .route("/users", post(create_user).get(list_users))
+        .route("/users/{id}", get(get_user_by_id).patch(update_user).delete(delete_user))
+        .route("/users/{id}/restore", post(restore_user))
+        .route("/users/{id}/suspend", post(suspend_user))
+        .route("/users/{id}/reactivate", post(reactivate_user))
+        .route("/users/{id}/deactivate", post(deactivate_user))
+        .route("/users/{id}/status-history", get(get_user_status_history))
+        .route("/passkeys/register/start", post(start_passkey_registration))
+        .route("/passkeys/register/finish", post(finish_passkey_registration))
+        .route("/passkeys/login/start", post(start_passkey_authentication))
+        .route("/passkeys/login/finish", post(finish_passkey_authentication))
+        .route("/auth/oidc/login", get(start_oidc_login))
+        .route("/auth/oidc/callback", get(finish_oidc_login))
+        .route("/oauth/token", post(issue_token))
+        .route("/oauth/introspect", post(introspect_token))
+        .route("/users/me", get(get_current_user))
+        .route("/users/me/tokens", post(create_personal_access_token).get(list_personal_access_tokens))
+        .route("/users/me/tokens/{id}", delete(revoke_personal_access_token))
+        .merge(admin_routes)
+}
+
+// --- Operations ---
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "operations",
+    responses((status = 200, description = "Greeting", body = String, content_type = "text/plain"))
+)]
+async fn hello() -> &'static str {
+    "Hello, World!"
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "operations",
+    responses((status = 200, description = "The process is running", body = HttpResponse<Liveness>))
+)]
+async fn get_liveness(State(state): State<AppState>) -> Response {
+    state.get_liveness_handler.execute().await.into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "operations",
+    responses(
+        (status = 200, description = "Every critical dependency is up", body = HttpResponse<Readiness>),
+        (status = 503, description = "A critical dependency is down or the server is shutting down", body = HttpResponse<Readiness>),
+    )
+)]
+async fn get_readiness(State(state): State<AppState>) -> Response {
+    state.get_readiness_handler.execute().await.into_response()
+}
+
+// --- Users ---
+
+#[utoipa::path(
+    post,
+    path = "/v1/users",
+    tag = "users",
+    request_body = UserForCreation,
+    responses(
+        (status = 201, description = "User created", body = HttpResponse<UserPublic>),
+        (status = 400, description = "Invalid input or email already in use", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn create_user(State(state): State<AppState>, ApiJson(body): ApiJson<UserForCreation>) -> Response {
+    state.create_user_handler.execute(body).await.into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/users",
+    tag = "users",
+    params(UserListParams),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "One page of users", body = HttpResponse<Page<UserPublic>>),
+        (status = 400, description = "Invalid filter, sort or cursor", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:read scope", body = HttpResponse<AppError>),
+    )
+)]
+async fn list_users(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiQuery(query): ApiQuery<UserListParams>,
+) -> Result<HttpResponse<Page<UserPublic>>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_READ)?;
+    state.list_users_handler.execute(principal, query).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/users/{id}",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "The user", body = HttpResponse<UserPublic>, headers(("ETag" = String, description = "Current version, for If-Match"))),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:read scope or not allowed to see this user", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+    )
+)]
+async fn get_user_by_id(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+) -> Result<Response, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_READ)?;
+    state.get_user_by_id_handler.execute(principal, id).await.map(with_etag)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/users/{id}",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User id"),
+        ("If-Match" = String, Header, description = "ETag of the version being updated"),
+    ),
+    request_body = UserForUpdate,
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User updated", body = HttpResponse<UserPublic>, headers(("ETag" = String, description = "New version"))),
+        (status = 400, description = "Invalid input or email already in use", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not allowed to change this user", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+        (status = 412, description = "The user changed since the given ETag", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+        (status = 428, description = "Missing If-Match", body = HttpResponse<AppError>),
+    )
+)]
+async fn update_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+    headers: HeaderMap,
+    ApiJson(body): ApiJson<UserForUpdate>,
+) -> Result<Response, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.update_user_handler.execute(principal, id, if_match_header(&headers), body).await.map(with_etag)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/users/{id}",
+    tag = "users",
+    params(
+        ("id" = String, Path, description = "User id"),
+        ("If-Match" = String, Header, description = "ETag of the version being deleted"),
+    ),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User soft-deleted, restorable until purged", body = HttpResponse<UserPublic>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not allowed to delete this user", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+        (status = 412, description = "The user changed since the given ETag", body = HttpResponse<AppError>),
+        (status = 428, description = "Missing If-Match", body = HttpResponse<AppError>),
+    )
+)]
+async fn delete_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+    headers: HeaderMap,
+) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.delete_user_handler.execute(principal, id, if_match_header(&headers)).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/{id}/restore",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User restored", body = HttpResponse<UserPublic>),
+        (status = 400, description = "Restore window elapsed or email taken", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not an admin", body = HttpResponse<AppError>),
+        (status = 404, description = "No such deleted user", body = HttpResponse<AppError>),
+    )
+)]
+async fn restore_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.restore_user_handler.execute(principal, id).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/{id}/suspend",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    request_body = UserStatusChangeRequest,
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User suspended", body = HttpResponse<UserPublic>),
+        (status = 400, description = "Transition not allowed or missing reason", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not an admin", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn suspend_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+    ApiJson(body): ApiJson<UserStatusChangeRequest>,
+) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.change_user_status_handler.execute(principal, id, UserStatus::Suspended, body).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/{id}/reactivate",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    request_body = UserStatusChangeRequest,
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User reactivated", body = HttpResponse<UserPublic>),
+        (status = 400, description = "Transition not allowed or missing reason", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not an admin", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn reactivate_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+    ApiJson(body): ApiJson<UserStatusChangeRequest>,
+) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.change_user_status_handler.execute(principal, id, UserStatus::Active, body).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/{id}/deactivate",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    request_body = UserStatusChangeRequest,
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "User deactivated", body = HttpResponse<UserPublic>),
+        (status = 400, description = "Transition not allowed or missing reason", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:write scope or not an admin", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn deactivate_user(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+    ApiJson(body): ApiJson<UserStatusChangeRequest>,
+) -> Result<HttpResponse<UserPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_WRITE)?;
+    state.change_user_status_handler.execute(principal, id, UserStatus::Deactivated, body).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/users/{id}/status-history",
+    tag = "users",
+    params(("id" = String, Path, description = "User id")),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "Status changes, oldest first", body = HttpResponse<Vec<UserStatusChange>>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:read scope or not allowed to see this user", body = HttpResponse<AppError>),
+        (status = 404, description = "No such user", body = HttpResponse<AppError>),
+    )
+)]
+async fn get_user_status_history(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<UserId>,
+) -> Result<HttpResponse<Vec<UserStatusChange>>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_READ)?;
+    state.get_user_status_history_handler.execute(principal, id).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/users/me",
+    tag = "users",
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "The caller", body = HttpResponse<UserPublic>, headers(("ETag" = String, description = "Current version, for If-Match"))),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the users:read scope or not a user token", body = HttpResponse<AppError>),
+    )
+)]
+async fn get_current_user(
+    State(state): State<AppState>,
+    principal: Principal,
+) -> Result<Response, HttpResponse<AppError>> {
+    require_scope(&principal, scope::USERS_READ)?;
+    state.get_current_user_handler.execute(principal).await.map(with_etag)
+}
+
+// --- Personal access tokens ---
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/me/tokens",
+    tag = "personal access tokens",
+    request_body = PersonalAccessTokenForCreation,
+    security(("bearer" = [])),
+    responses(
+        (status = 201, description = "Token created; the plaintext is only returned here", body = HttpResponse<PersonalAccessTokenCreated>),
+        (status = 400, description = "Invalid name, scopes or lifetime", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the tokens:write scope", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn create_personal_access_token(
+    State(state): State<AppState>,
+    principal: Principal,
+    ApiJson(body): ApiJson<PersonalAccessTokenForCreation>,
+) -> Result<HttpResponse<PersonalAccessTokenCreated>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::TOKENS_WRITE)?;
+    state.create_personal_access_token_handler.execute(principal, body).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/users/me/tokens",
+    tag = "personal access tokens",
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "The caller's tokens, without their values", body = HttpResponse<Vec<PersonalAccessTokenPublic>>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the tokens:read scope", body = HttpResponse<AppError>),
+    )
+)]
+async fn list_personal_access_tokens(
+    State(state): State<AppState>,
+    principal: Principal,
+) -> Result<HttpResponse<Vec<PersonalAccessTokenPublic>>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::TOKENS_READ)?;
+    state.list_personal_access_tokens_handler.execute(principal).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/users/me/tokens/{id}",
+    tag = "personal access tokens",
+    params(("id" = String, Path, description = "Token id")),
+    security(("bearer" = [])),
+    responses(
+        (status = 200, description = "Token revoked", body = HttpResponse<PersonalAccessTokenPublic>),
+        (status = 401, description = "Missing or invalid token", body = HttpResponse<AppError>),
+        (status = 403, description = "Missing the tokens:write scope", body = HttpResponse<AppError>),
+        (status = 404, description = "No such token for the caller", body = HttpResponse<AppError>),
+    )
+)]
+async fn revoke_personal_access_token(
+    State(state): State<AppState>,
+    principal: Principal,
+    Path(id): Path<String>,
+) -> Result<HttpResponse<PersonalAccessTokenPublic>, HttpResponse<AppError>> {
+    require_scope(&principal, scope::TOKENS_WRITE)?;
+    state.revoke_personal_access_token_handler.execute(principal, id).await
+}
+
+// --- Passkeys ---
+
+#[utoipa::path(
+    post,
+    path = "/v1/passkeys/register/start",
+    tag = "passkeys",
+    request_body = PasskeyRegistrationStart,
+    responses(
+        (status = 200, description = "Options for navigator.credentials.create", body = HttpResponse<PasskeyRegistrationOptions>),
+        (status = 400, description = "Malformed JSON body", body = HttpResponse<AppError>),
+        (status = 401, description = "Wrong email or password", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn start_passkey_registration(State(state): State<AppState>, ApiJson(body): ApiJson<PasskeyRegistrationStart>) -> Response {
+    state.start_passkey_registration_handler.execute(body).await.into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/passkeys/register/finish",
+    tag = "passkeys",
+    request_body = PasskeyRegistrationFinish,
+    responses(
+        (status = 201, description = "Passkey registered", body = HttpResponse<PasskeyPublic>),
+        (status = 400, description = "Invalid or expired ceremony, or attestation rejected", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn finish_passkey_registration(State(state): State<AppState>, ApiJson(body): ApiJson<PasskeyRegistrationFinish>) -> Response {
+    state.finish_passkey_registration_handler.execute(body).await.into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/passkeys/login/start",
+    tag = "passkeys",
+    request_body = PasskeyAuthenticationStart,
+    responses(
+        (status = 200, description = "Options for navigator.credentials.get", body = HttpResponse<PasskeyAuthenticationOptions>),
+        (status = 400, description = "Malformed JSON body", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn start_passkey_authentication(State(state): State<AppState>, ApiJson(body): ApiJson<PasskeyAuthenticationStart>) -> Response {
+    state.start_passkey_authentication_handler.execute(body).await.into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/passkeys/login/finish",
+    tag = "passkeys",
+    request_body = PasskeyAuthenticationFinish,
+    responses(
+        (status = 200, description = "Signed in", body = HttpResponse<UserSession>),
+        (status = 400, description = "Invalid or expired ceremony, or malformed credential", body = HttpResponse<AppError>),
+        (status = 401, description = "Assertion rejected or account not allowed to sign in", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn finish_passkey_authentication(State(state): State<AppState>, ApiJson(body): ApiJson<PasskeyAuthenticationFinish>) -> Response {
+    state.finish_passkey_authentication_handler.execute(body).await.into_response()
+}
+
+// --- OpenID Connect ---
+
+#[utoipa::path(
+    get,
+    path = "/v1/auth/oidc/login",
+    tag = "oidc",
+    responses(
+        (status = 200, description = "URL to send the browser to", body = HttpResponse<OidcLoginStart>, headers(("Set-Cookie" = String, description = "Binds the login to this browser"))),
+        (status = 500, description = "The identity provider could not be reached", body = HttpResponse<AppError>),
+    )
+)]
+async fn start_oidc_login(State(state): State<AppState>) -> Response {
+    match state.start_oidc_login_handler.execute().await {
+        Ok(response) => {
+            let cookie = state_cookie(&response.content.state, AUTHORIZATION_TIMEOUT_SECONDS, state.secure_cookies);
+            ([(SET_COOKIE, cookie)], response).into_response()
+        }
+        Err(error) => error.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/auth/oidc/callback",
+    tag = "oidc",
+    params(OidcCallback),
+    responses(
+        (status = 200, description = "Signed in", body = HttpResponse<UserSession>),
+        (status = 400, description = "Unknown state, login started from another browser or provider error", body = HttpResponse<AppError>),
+        (status = 401, description = "ID token rejected", body = HttpResponse<AppError>),
+    )
+)]
+async fn finish_oidc_login(State(state): State<AppState>, headers: HeaderMap, ApiQuery(query): ApiQuery<OidcCallback>) -> Response {
+    let result = state.finish_oidc_login_handler.execute(query, cookie(&headers, STATE_COOKIE)).await;
+
+    // The state is single use, whatever the outcome.
+    ([(SET_COOKIE, state_cookie("", 0, state.secure_cookies))], result).into_response()
+}
+
+// --- OAuth ---
+
+#[utoipa::path(
+    post,
+    path = "/v1/oauth/token",
+    tag = "oauth",
+    request_body(content = OAuthTokenRequest, content_type = "application/x-www-form-urlencoded"),
+    security((), ("client_basic" = [])),
+    responses(
+        (status = 200, description = "Access token (client credentials grant)", body = OAuthTokenResponse),
+        (status = 400, description = "Invalid request, scope or grant type", body = OAuthError),
+        (status = 401, description = "Client authentication failed", body = OAuthError),
+    )
+)]
+async fn issue_token(State(state): State<AppState>, headers: HeaderMap, OAuthForm(body): OAuthForm<OAuthTokenRequest>) -> Response {
+    state
+        .issue_token_handler
+        .execute(authorization_header(&headers), body)
+        .await
+        .into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/oauth/introspect",
+    tag = "oauth",
+    request_body(content = IntrospectionRequest, content_type = "application/x-www-form-urlencoded"),
+    security((), ("client_basic" = [])),
+    responses(
+        (status = 200, description = "Token state; inactive tokens only carry `active`", body = IntrospectionResponse),
+        (status = 400, description = "Invalid request", body = OAuthError),
+        (status = 401, description = "Client authentication failed", body = OAuthError),
+    )
+)]
+async fn introspect_token(State(state): State<AppState>, headers: HeaderMap, OAuthForm(body): OAuthForm<IntrospectionRequest>) -> Response {
+    state
+        .introspect_token_handler
+        .execute(authorization_header(&headers), body)
+        .await
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "oauth",
+    responses(
+        (status = 200, description = "Public keys that verify access tokens", body = Jwks),
+        (status = 500, description = "Keys unavailable", body = HttpResponse<AppError>),
+    )
+)]
+async fn get_jwks(State(state): State<AppState>) -> Response {
+    state.get_jwks_handler.execute().await.into_response()
+}
+
+// --- Admin ---
+
+#[utoipa::path(
+    post,
+    path = "/v1/oauth/clients",
+    tag = "admin",
+    request_body = OAuthClientForCreation,
+    security(("api_key" = [])),
+    responses(
+        (status = 201, description = "Client created; the secret is only returned here", body = HttpResponse<OAuthClientCreated>),
+        (status = 400, description = "Invalid name or scopes", body = HttpResponse<AppError>),
+        (status = 401, description = "Missing or wrong API key", body = HttpResponse<AppError>),
+        (status = 422, description = "Body does not match the schema", body = HttpResponse<AppError>),
+    )
+)]
+async fn create_oauth_client(State(state): State<AppState>, ApiJson(body): ApiJson<OAuthClientForCreation>) -> Response {
+    state.create_oauth_client_handler.execute(body).await.into_response()
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/users/purge",
+    tag = "admin",
+    security(("api_key" = [])),
+    responses(
+        (status = 200, description = "Users past the restore window were removed", body = HttpResponse<UserPurgeResult>),
+        (status = 401, description = "Missing or wrong API key", body = HttpResponse<AppError>),
+    )
+)]
+async fn purge_deleted_users(State(state): State<AppState>) -> Response {
+    state.purge_deleted_users_handler.execute().await.into_response()
+}
+
+/// Prometheus text exposition format, version 0.0.4.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "operations",
+    responses((status = 200, description = "Prometheus metrics", body = String, content_type = "text/plain"))
+)]
+async fn render_metrics(State(state): State<AppState>) -> Response {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics_handle.render(),
+    )
+        .into_response()
+}
+
+fn authorization_header(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn if_match_header(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(IF_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.to_string())
+}
+
+// Lax still sends the cookie on the top-level redirect back from the identity provider.
+fn state_cookie(value: &str, max_age_seconds: i64, secure: bool) -> String {
+    format!(
+        "{}={}; Path=/v1/auth/oidc; Max-Age={}; HttpOnly; SameSite=Lax{}",
+        STATE_COOKIE,
+        value,
+        max_age_seconds,
+        if secure { "; Secure" } else { "" }
+    )
+}
+
+fn with_etag(response: HttpResponse<UserPublic>) -> Response {
+    let etag = format_etag(response.content.version);
+
+    ([(ETAG, etag)], response).into_response()
+}
+
+fn require_scope(principal: &Principal, scope: &str) -> Result<(), HttpResponse<AppError>> {
+    principal.require_scope(scope).map_err(HttpResponse::from)
+}
@@ -0,0 +1,120 @@
+use crate::model::personal_access_token_model::PERSONAL_ACCESS_TOKEN_PREFIX;
+use crate::model::principal_model::{CredentialKind, Principal};
+use crate::model::user_model::{UserId, UserStatus};
+use crate::repository::personal_access_token_repository::find_personal_access_token_by_hash_repository::FindPersonalAccessTokenByHashRepository;
+use crate::repository::personal_access_token_repository::update_personal_access_token_repository::UpdatePersonalAccessTokenRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::token_generator::hash_token;
+use crate::util::token_issuer::TokenIssuer;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait AuthenticateService {
+    async fn execute(&self, bearer_token: String) -> Result<Principal, AppError>;
+}
+
+pub struct AuthenticateServiceImpl {
+    pub find_personal_access_token_by_hash_repository:
+        Box<dyn FindPersonalAccessTokenByHashRepository + Send + Sync + 'static>,
+    pub update_personal_access_token_repository:
+        Box<dyn UpdatePersonalAccessTokenRepository + Send + Sync + 'static>,
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl AuthenticateService for AuthenticateServiceImpl {
+    async fn execute(&self, bearer_token: String) -> Result<Principal, AppError> {
+        if bearer_token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX) {
+            return self.authenticate_personal_access_token(bearer_token).await;
+        }
+
+        let claims = self
+            .token_issuer
+            .verify(&bearer_token)
+            .map_err(|_| AppError::unauthorized("Invalid or expired access token"))?;
+
+        let scopes: Vec<String> = claims.scopes().into_iter().map(str::to_string).collect();
+
+        match claims.client_id {
+            Some(client_id) => Ok(Principal {
+                user_id: None,
+                client_id: Some(client_id),
+                scopes,
+                credential: CredentialKind::AccessToken,
+            }),
+            None => {
+                let user_id = UserId::from(claims.sub);
+                let user = self
+                    .find_user_by_id_repository
+                    .execute(user_id.clone())
+                    .await
+                    .map_err(AppError::server_error)?
+                    .ok_or_else(|| AppError::unauthorized("Invalid or expired access token"))?;
+
+                ensure_user_is_active(&user.status)?;
+
+                Ok(Principal {
+                    user_id: Some(user_id),
+                    client_id: None,
+                    scopes,
+                    credential: CredentialKind::AccessToken,
+                })
+            }
+        }
+    }
+}
+
+impl AuthenticateServiceImpl {
+    async fn authenticate_personal_access_token(
+        &self,
+        bearer_token: String,
+    ) -> Result<Principal, AppError> {
+        let now = self.clock.now();
+
+        let mut token = self
+            .find_personal_access_token_by_hash_repository
+            .execute(hash_token(&bearer_token))
+            .await
+            .map_err(AppError::server_error)?
+            .filter(|token| token.is_usable(now))
+            .ok_or_else(|| AppError::unauthorized("Invalid, expired or revoked personal access token"))?;
+
+        let user = self
+            .find_user_by_id_repository
+            .execute(token.user_id.clone())
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::unauthorized("Invalid, expired or revoked personal access token"))?;
+
+        ensure_user_is_active(&user.status)?;
+
+        token.last_used_at = Some(now);
+
+        let principal = Principal {
+            user_id: Some(token.user_id.clone()),
+            client_id: None,
+            scopes: token.scopes.clone(),
+            credential: CredentialKind::PersonalAccessToken,
+        };
+
+        self.update_personal_access_token_repository
+            .execute(token)
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(principal)
+    }
+}
+
+/// Tokens issued before a suspension stop working immediately instead of at expiry.
+fn ensure_user_is_active(status: &UserStatus) -> Result<(), AppError> {
+    if !status.allows_sign_in() {
+        return Err(AppError::unauthorized(format!("Account is {:?}", status)));
+    }
+
+    Ok(())
+}
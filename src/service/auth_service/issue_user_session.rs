@@ -0,0 +1,53 @@
+use crate::model::token_model::{AccessTokenClaims, UserSession};
+use crate::model::user_model::{UserPublic, UserStatus};
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::scope::USER_SESSION_SCOPES;
+use crate::util::secret::Secret;
+use crate::util::token_issuer::{TokenConfig, TokenIssuer};
+use crate::util::uuid_generator::UuidGenerator;
+
+/// Shared by the login flows: every successful login ends with a user access token, and
+/// this is where accounts that may not sign in are turned away.
+pub fn issue_user_session(
+    token_issuer: &(dyn TokenIssuer + Send + Sync),
+    uuid_generator: &(dyn UuidGenerator + Send + Sync),
+    clock: &(dyn Clock + Send + Sync),
+    token_config: &TokenConfig,
+    user: UserPublic,
+) -> Result<UserSession, AppError> {
+    ensure_can_sign_in(&user.status)?;
+
+    let token_id = uuid_generator.generate().map_err(AppError::server_error)?;
+
+    let issued_at = clock.now().timestamp();
+    let scope = USER_SESSION_SCOPES.join(" ");
+
+    let access_token = token_issuer
+        .issue(&AccessTokenClaims {
+            iss: token_config.issuer.clone(),
+            sub: user.id.to_string(),
+            client_id: None,
+            scope: scope.clone(),
+            iat: issued_at,
+            exp: issued_at + token_config.access_token_ttl_seconds,
+            jti: token_id,
+        })
+        .map_err(AppError::server_error)?;
+
+    Ok(UserSession {
+        user,
+        access_token: Secret::new(access_token),
+        token_type: "Bearer".to_string(),
+        expires_in: token_config.access_token_ttl_seconds,
+        scope,
+    })
+}
+
+pub fn ensure_can_sign_in(status: &UserStatus) -> Result<(), AppError> {
+    if !status.allows_sign_in() {
+        return Err(AppError::forbidden(format!("Account is {:?}", status)));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod issue_user_session;
+pub mod authenticate_service;
@@ -0,0 +1,80 @@
+use crate::model::health_model::{ComponentHealth, HealthStatus, Readiness};
+use crate::util::health_check::HealthCheck;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[async_trait]
+pub trait CheckReadinessService {
+    async fn execute(&self) -> Readiness;
+}
+
+pub struct CheckReadinessServiceImpl {
+    pub health_checks: Vec<Arc<dyn HealthCheck + Send + Sync + 'static>>,
+    /// Applied to each check; a check that does not answer in time counts as down.
+    pub timeout: Duration,
+}
+
+#[async_trait]
+impl CheckReadinessService for CheckReadinessServiceImpl {
+    async fn execute(&self) -> Readiness {
+        // Every check is started before any is awaited, so one slow dependency costs at most
+        // one timeout.
+        let running: Vec<_> = self
+            .health_checks
+            .iter()
+            .map(|health_check| {
+                let health_check = health_check.clone();
+                let timeout = self.timeout;
+                tokio::spawn(async move { run_check(health_check.as_ref(), timeout).await })
+            })
+            .collect();
+
+        let mut components = Vec::with_capacity(running.len());
+        for (health_check, handle) in self.health_checks.iter().zip(running) {
+            components.push(handle.await.unwrap_or_else(|_| ComponentHealth {
+                name: health_check.name().to_string(),
+                status: HealthStatus::Down,
+                critical: health_check.critical(),
+                duration_ms: 0,
+                error: Some("Check panicked".to_string()),
+            }));
+        }
+
+        let status = if components
+            .iter()
+            .any(|component| component.critical && component.status == HealthStatus::Down)
+        {
+            HealthStatus::Down
+        } else {
+            HealthStatus::Up
+        };
+
+        Readiness { status, components }
+    }
+}
+
+async fn run_check(health_check: &(dyn HealthCheck + Send + Sync), timeout: Duration) -> ComponentHealth {
+    let started_at = Instant::now();
+
+    let result = match tokio::time::timeout(timeout, health_check.check()).await {
+        Ok(result) => result,
+        Err(_) => Err(format!("Timed out after {} ms", timeout.as_millis())),
+    };
+
+    if let Err(err) = &result {
+        tracing::warn!(component = health_check.name(), "Health check failed: {}", err);
+    }
+
+    ComponentHealth {
+        name: health_check.name().to_string(),
+        status: if result.is_ok() {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        },
+        critical: health_check.critical(),
+        duration_ms: started_at.elapsed().as_millis() as u64,
+        error: result.err(),
+    }
+}
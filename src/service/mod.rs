@@ -1 +1,8 @@
-pub mod user_service;
\ No newline at end of file
+pub mod user_service;
+pub mod passkey_service;
+pub mod oidc_service;
+pub mod oauth_service;
+pub mod token_service;
+pub mod auth_service;
+pub mod personal_access_token_service;
+pub mod health_service;
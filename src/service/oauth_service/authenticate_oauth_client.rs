@@ -0,0 +1,27 @@
+use crate::model::oauth_model::{ClientCredentials, OAuthClient};
+use crate::repository::oauth_client_repository::find_oauth_client_by_id_repository::FindOAuthClientByIdRepository;
+use crate::util::app_error::AppError;
+use crate::util::encryptor::Encryptor;
+
+/// Shared by the token and introspection endpoints: both require a registered client.
+pub async fn authenticate_oauth_client(
+    find_oauth_client_by_id_repository: &(dyn FindOAuthClientByIdRepository + Send + Sync),
+    encryptor: &(dyn Encryptor + Send + Sync),
+    credentials: ClientCredentials,
+) -> Result<OAuthClient, AppError> {
+    let client = find_oauth_client_by_id_repository
+        .execute(credentials.client_id)
+        .await
+        .map_err(AppError::server_error)?
+        .ok_or_else(|| AppError::unauthorized("Invalid client credentials"))?;
+
+    let secret_matches = encryptor
+        .verify(credentials.client_secret.expose(), client.secret_hash.expose())
+        .map_err(AppError::server_error)?;
+
+    if !secret_matches {
+        return Err(AppError::unauthorized("Invalid client credentials"));
+    }
+
+    Ok(client)
+}
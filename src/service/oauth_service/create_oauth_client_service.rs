@@ -0,0 +1,80 @@
+use crate::model::oauth_model::{OAuthClient, OAuthClientCreated, OAuthClientForCreation};
+use crate::repository::oauth_client_repository::create_oauth_client_repository::CreateOAuthClientRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::encryptor::Encryptor;
+use crate::util::secret::Secret;
+use crate::util::token_generator::TokenGenerator;
+use crate::util::uuid_generator::UuidGenerator;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CreateOAuthClientService {
+    async fn execute(
+        &self,
+        client_for_creation: OAuthClientForCreation,
+    ) -> Result<OAuthClientCreated, AppError>;
+}
+
+pub struct CreateOAuthClientServiceImpl {
+    pub create_oauth_client_repository: Box<dyn CreateOAuthClientRepository + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl CreateOAuthClientService for CreateOAuthClientServiceImpl {
+    async fn execute(
+        &self,
+        client_for_creation: OAuthClientForCreation,
+    ) -> Result<OAuthClientCreated, AppError> {
+        if client_for_creation.name.trim().is_empty() {
+            return Err(AppError::client_error("Client name is required".to_string()));
+        }
+
+        if client_for_creation
+            .scopes
+            .iter()
+            .any(|scope| scope.is_empty() || scope.contains(char::is_whitespace))
+        {
+            return Err(AppError::client_error("Scopes must be non-empty and contain no spaces".to_string()));
+        }
+
+        let client_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let client_secret = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        let secret_hash = self
+            .encryptor
+            .encrypt(&client_secret)
+            .map_err(AppError::server_error)?;
+
+        let client = self
+            .create_oauth_client_repository
+            .execute(OAuthClient {
+                id: client_id,
+                name: client_for_creation.name,
+                secret_hash: Secret::new(secret_hash),
+                scopes: client_for_creation.scopes,
+                created_at: self.clock.now(),
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(OAuthClientCreated {
+            client_id: client.id,
+            client_secret: Secret::new(client_secret),
+            name: client.name,
+            scopes: client.scopes,
+            created_at: client.created_at,
+        })
+    }
+}
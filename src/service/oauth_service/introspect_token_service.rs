@@ -0,0 +1,57 @@
+use crate::model::oauth_model::{ClientCredentials, IntrospectionResponse};
+use crate::repository::oauth_client_repository::find_oauth_client_by_id_repository::FindOAuthClientByIdRepository;
+use crate::service::oauth_service::authenticate_oauth_client::authenticate_oauth_client;
+use crate::util::encryptor::Encryptor;
+use crate::util::oauth_error::OAuthError;
+use crate::util::secret::Secret;
+use crate::util::token_issuer::TokenIssuer;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IntrospectTokenService {
+    async fn execute(
+        &self,
+        credentials: ClientCredentials,
+        token: Secret<String>,
+    ) -> Result<IntrospectionResponse, OAuthError>;
+}
+
+pub struct IntrospectTokenServiceImpl {
+    pub find_oauth_client_by_id_repository: Box<dyn FindOAuthClientByIdRepository + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl IntrospectTokenService for IntrospectTokenServiceImpl {
+    async fn execute(
+        &self,
+        credentials: ClientCredentials,
+        token: Secret<String>,
+    ) -> Result<IntrospectionResponse, OAuthError> {
+        authenticate_oauth_client(
+            self.find_oauth_client_by_id_repository.as_ref(),
+            self.encryptor.as_ref(),
+            credentials,
+        )
+        .await?;
+
+        // Any token we cannot validate is simply reported as inactive (RFC 7662, 2.2).
+        let claims = match self.token_issuer.verify(token.expose()) {
+            Ok(claims) => claims,
+            Err(_) => return Ok(IntrospectionResponse::default()),
+        };
+
+        Ok(IntrospectionResponse {
+            active: true,
+            scope: Some(claims.scope),
+            client_id: claims.client_id,
+            sub: Some(claims.sub),
+            token_type: Some("Bearer".to_string()),
+            exp: Some(claims.exp),
+            iat: Some(claims.iat),
+            iss: Some(claims.iss),
+            jti: Some(claims.jti),
+        })
+    }
+}
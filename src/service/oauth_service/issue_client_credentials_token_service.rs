@@ -0,0 +1,94 @@
+use crate::model::oauth_model::{ClientCredentials, OAuthTokenResponse};
+use crate::model::token_model::AccessTokenClaims;
+use crate::repository::oauth_client_repository::find_oauth_client_by_id_repository::FindOAuthClientByIdRepository;
+use crate::service::oauth_service::authenticate_oauth_client::authenticate_oauth_client;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::encryptor::Encryptor;
+use crate::util::oauth_error::{OAuthError, OAuthErrorCode};
+use crate::util::secret::Secret;
+use crate::util::token_issuer::{TokenConfig, TokenIssuer};
+use crate::util::uuid_generator::UuidGenerator;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IssueClientCredentialsTokenService {
+    async fn execute(
+        &self,
+        credentials: ClientCredentials,
+        requested_scope: Option<String>,
+    ) -> Result<OAuthTokenResponse, OAuthError>;
+}
+
+pub struct IssueClientCredentialsTokenServiceImpl {
+    pub find_oauth_client_by_id_repository: Box<dyn FindOAuthClientByIdRepository + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+    pub token_config: TokenConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl IssueClientCredentialsTokenService for IssueClientCredentialsTokenServiceImpl {
+    async fn execute(
+        &self,
+        credentials: ClientCredentials,
+        requested_scope: Option<String>,
+    ) -> Result<OAuthTokenResponse, OAuthError> {
+        let client = authenticate_oauth_client(
+            self.find_oauth_client_by_id_repository.as_ref(),
+            self.encryptor.as_ref(),
+            credentials,
+        )
+        .await?;
+
+        // Without a scope parameter the client gets everything it is allowed (RFC 6749, 3.3).
+        let granted_scopes: Vec<String> = match requested_scope {
+            Some(requested_scope) => {
+                let requested: Vec<String> = requested_scope
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect();
+
+                if let Some(scope) = requested.iter().find(|scope| !client.scopes.contains(scope)) {
+                    return Err(OAuthError::new(
+                        OAuthErrorCode::InvalidScope,
+                        format!("Scope {} is not allowed for this client", scope),
+                    ));
+                }
+
+                requested
+            }
+            None => client.scopes.clone(),
+        };
+
+        let token_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let issued_at = self.clock.now().timestamp();
+        let scope = granted_scopes.join(" ");
+
+        let access_token = self
+            .token_issuer
+            .issue(&AccessTokenClaims {
+                iss: self.token_config.issuer.clone(),
+                sub: client.id.clone(),
+                client_id: Some(client.id),
+                scope: scope.clone(),
+                iat: issued_at,
+                exp: issued_at + self.token_config.access_token_ttl_seconds,
+                jti: token_id,
+            })
+            .map_err(AppError::server_error)?;
+
+        Ok(OAuthTokenResponse {
+            access_token: Secret::new(access_token),
+            token_type: "Bearer".to_string(),
+            expires_in: self.token_config.access_token_ttl_seconds,
+            scope,
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod authenticate_oauth_client;
+pub mod create_oauth_client_service;
+pub mod issue_client_credentials_token_service;
+pub mod introspect_token_service;
@@ -0,0 +1,235 @@
+use crate::model::email_model::{Email, EmailConfig};
+use crate::model::oidc_model::{OidcCallback, OidcIdentity};
+use crate::model::token_model::UserSession;
+use crate::model::user_model::{User, UserId, UserPublic, UserRole, UserStatus};
+use crate::repository::oidc_authorization_repository::take_oidc_authorization_repository::TakeOidcAuthorizationRepository;
+use crate::repository::oidc_identity_repository::create_oidc_identity_repository::CreateOidcIdentityRepository;
+use crate::repository::oidc_identity_repository::find_oidc_identity_by_subject_repository::FindOidcIdentityBySubjectRepository;
+use crate::repository::user_repository::create_user_repository::{CreateUserError, CreateUserRepository};
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::service::auth_service::issue_user_session::issue_user_session;
+use crate::service::user_service::email_availability::email_taken;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::encryptor::Encryptor;
+use crate::util::oidc::{OidcClient, OidcConfig, verify_id_token};
+use crate::util::secret::Secret;
+use crate::util::token_generator::TokenGenerator;
+use crate::util::token_issuer::{TokenConfig, TokenIssuer};
+use crate::util::uuid_generator::UuidGenerator;
+use async_trait::async_trait;
+use subtle::ConstantTimeEq;
+
+#[async_trait]
+pub trait FinishOidcLoginService {
+    /// `bound_state` is the state from the browser's `STATE_COOKIE`, if it sent one.
+    async fn execute(&self, callback: OidcCallback, bound_state: Option<String>) -> Result<UserSession, AppError>;
+}
+
+pub struct FinishOidcLoginServiceImpl {
+    pub take_oidc_authorization_repository:
+        Box<dyn TakeOidcAuthorizationRepository + Send + Sync + 'static>,
+    pub find_oidc_identity_by_subject_repository:
+        Box<dyn FindOidcIdentityBySubjectRepository + Send + Sync + 'static>,
+    pub create_oidc_identity_repository:
+        Box<dyn CreateOidcIdentityRepository + Send + Sync + 'static>,
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub create_user_repository: Box<dyn CreateUserRepository + Send + Sync + 'static>,
+    pub oidc_client: Box<dyn OidcClient + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+    pub oidc_config: OidcConfig,
+    pub token_config: TokenConfig,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishOidcLoginService for FinishOidcLoginServiceImpl {
+    async fn execute(&self, callback: OidcCallback, bound_state: Option<String>) -> Result<UserSession, AppError> {
+        let user = self.resolve_user(callback, bound_state).await?;
+
+        issue_user_session(
+            self.token_issuer.as_ref(),
+            self.uuid_generator.as_ref(),
+            self.clock.as_ref(),
+            &self.token_config,
+            user,
+        )
+    }
+}
+
+impl FinishOidcLoginServiceImpl {
+    async fn resolve_user(&self, callback: OidcCallback, bound_state: Option<String>) -> Result<UserPublic, AppError> {
+        // Checked before the authorization is taken, so a forged callback cannot burn the
+        // login the victim's own browser started.
+        let started_here = bound_state
+            .is_some_and(|bound| bool::from(bound.as_bytes().ct_eq(callback.state.as_bytes())));
+
+        if !started_here {
+            return Err(AppError::client_error(
+                "Login was not started from this browser".to_string(),
+            ));
+        }
+
+        let authorization = self
+            .take_oidc_authorization_repository
+            .execute(callback.state)
+            .await
+            .map_err(AppError::server_error)?
+            .filter(|authorization| authorization.expires_at > self.clock.now())
+            .ok_or_else(|| {
+                AppError::client_error("Login request not found or expired".to_string())
+            })?;
+
+        if let Some(error) = callback.error {
+            let description = callback.error_description.unwrap_or_default();
+            return Err(AppError::unauthorized(format!(
+                "Identity provider returned {}: {}",
+                error, description
+            )));
+        }
+
+        let code = callback
+            .code
+            .ok_or_else(|| AppError::client_error("Missing authorization code".to_string()))?;
+
+        let provider = self
+            .oidc_client
+            .discover(&self.oidc_config.issuer_url)
+            .await
+            .map_err(AppError::server_error)?;
+
+        let token_response = self
+            .oidc_client
+            .exchange_code(
+                &provider.token_endpoint,
+                &code,
+                &authorization.code_verifier,
+                &self.oidc_config,
+            )
+            .await
+            .map_err(|err| {
+                tracing::warn!("{}", err);
+                AppError::unauthorized("Authorization code was rejected by the identity provider")
+            })?;
+
+        let jwks = self
+            .oidc_client
+            .fetch_jwks(&provider.jwks_uri)
+            .await
+            .map_err(AppError::server_error)?;
+
+        let claims = verify_id_token(
+            &token_response.id_token,
+            &jwks,
+            &provider.issuer,
+            &self.oidc_config.client_id,
+            &authorization.nonce,
+            self.clock.as_ref(),
+        )
+        .map_err(AppError::unauthorized)?;
+
+        let identity = self
+            .find_oidc_identity_by_subject_repository
+            .execute(claims.iss.clone(), claims.sub.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        if let Some(identity) = identity {
+            let user = self
+                .find_user_by_id_repository
+                .execute(identity.user_id)
+                .await
+                .map_err(AppError::server_error)?
+                .ok_or_else(|| AppError::unauthorized("Linked user no longer exists"))?;
+
+            return Ok(UserPublic::from(user));
+        }
+
+        // First login with this identity: link by verified email, or provision a new user.
+        let email = claims
+            .email
+            .filter(|_| claims.email_verified == Some(true))
+            .ok_or_else(|| {
+                AppError::unauthorized("Identity provider did not return a verified email")
+            })?;
+
+        let email = Email::parse(&email)
+            .map(|email| self.email_config.normalize(email))
+            .map_err(|_| AppError::unauthorized("Identity provider returned an invalid email"))?;
+
+        let existing_user = self
+            .find_user_by_email_repository
+            .execute(email.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        let user = match existing_user {
+            Some(user) => UserPublic::from(user),
+            None => self.provision_user(email).await?,
+        };
+
+        let identity_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        self.create_oidc_identity_repository
+            .execute(OidcIdentity {
+                id: identity_id,
+                user_id: user.id.clone(),
+                issuer: claims.iss,
+                subject: claims.sub,
+                created_at: self.clock.now(),
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(user)
+    }
+
+    async fn provision_user(&self, email: Email) -> Result<UserPublic, AppError> {
+        let user_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        // Federated users never see this password; it only keeps the column populated.
+        let unusable_password = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        let hashed_password = self
+            .encryptor
+            .encrypt(&unusable_password)
+            .map_err(AppError::server_error)?;
+
+        let now = self.clock.now();
+
+        let user = User {
+            id: UserId::from(user_id),
+            email,
+            password: Secret::new(hashed_password),
+            role: UserRole::User,
+            status: UserStatus::Active,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            version: 1,
+        };
+
+        self.create_user_repository
+            .execute(user)
+            .await
+            .map_err(|error| match error {
+                CreateUserError::DuplicateEmail => email_taken(),
+                CreateUserError::Storage(detail) => AppError::server_error(detail),
+            })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod finish_oidc_login_service;
+pub mod start_oidc_login_service;
@@ -0,0 +1,78 @@
+use crate::model::oidc_model::{OidcAuthorization, OidcLoginStart};
+use crate::repository::oidc_authorization_repository::create_oidc_authorization_repository::CreateOidcAuthorizationRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::oidc::{OidcClient, OidcConfig, pkce_challenge};
+use crate::util::token_generator::TokenGenerator;
+use async_trait::async_trait;
+use chrono::Duration;
+
+pub const AUTHORIZATION_TIMEOUT_SECONDS: i64 = 600;
+
+#[async_trait]
+pub trait StartOidcLoginService {
+    async fn execute(&self) -> Result<OidcLoginStart, AppError>;
+}
+
+pub struct StartOidcLoginServiceImpl {
+    pub create_oidc_authorization_repository:
+        Box<dyn CreateOidcAuthorizationRepository + Send + Sync + 'static>,
+    pub oidc_client: Box<dyn OidcClient + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub oidc_config: OidcConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartOidcLoginService for StartOidcLoginServiceImpl {
+    async fn execute(&self) -> Result<OidcLoginStart, AppError> {
+        let provider = self
+            .oidc_client
+            .discover(&self.oidc_config.issuer_url)
+            .await
+            .map_err(AppError::server_error)?;
+
+        let state = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+        let nonce = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+        let code_verifier = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        let authorization_url = reqwest::Url::parse_with_params(
+            &provider.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", self.oidc_config.client_id.as_str()),
+                ("redirect_uri", self.oidc_config.redirect_uri.as_str()),
+                ("scope", self.oidc_config.scopes.join(" ").as_str()),
+                ("state", state.as_str()),
+                ("nonce", nonce.as_str()),
+                ("code_challenge", pkce_challenge(&code_verifier).as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| AppError::server_error(format!("Invalid authorization endpoint: {}", e)))?;
+
+        self.create_oidc_authorization_repository
+            .execute(OidcAuthorization {
+                state: state.clone(),
+                nonce,
+                code_verifier,
+                expires_at: self.clock.now() + Duration::seconds(AUTHORIZATION_TIMEOUT_SECONDS),
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(OidcLoginStart {
+            authorization_url: authorization_url.to_string(),
+            state,
+        })
+    }
+}
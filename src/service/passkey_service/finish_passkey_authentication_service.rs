@@ -0,0 +1,156 @@
+use crate::model::passkey_model::{PasskeyAuthenticationFinish, WebauthnCeremony};
+use crate::model::token_model::UserSession;
+use crate::model::user_model::UserPublic;
+use crate::repository::passkey_repository::find_passkey_by_id_repository::FindPasskeyByIdRepository;
+use crate::repository::passkey_repository::update_passkey_repository::UpdatePasskeyRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::webauthn_challenge_repository::take_webauthn_challenge_repository::TakeWebauthnChallengeRepository;
+use crate::service::auth_service::issue_user_session::issue_user_session;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::token_issuer::{TokenConfig, TokenIssuer};
+use crate::util::uuid_generator::UuidGenerator;
+use crate::util::webauthn::{self, AuthenticatorData, CosePublicKey, WebauthnConfig};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FinishPasskeyAuthenticationService {
+    async fn execute(
+        &self,
+        authentication_finish: PasskeyAuthenticationFinish,
+    ) -> Result<UserSession, AppError>;
+}
+
+pub struct FinishPasskeyAuthenticationServiceImpl {
+    pub take_webauthn_challenge_repository: Box<dyn TakeWebauthnChallengeRepository + Send + Sync + 'static>,
+    pub find_passkey_by_id_repository: Box<dyn FindPasskeyByIdRepository + Send + Sync + 'static>,
+    pub update_passkey_repository: Box<dyn UpdatePasskeyRepository + Send + Sync + 'static>,
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub webauthn_config: WebauthnConfig,
+    pub token_config: TokenConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishPasskeyAuthenticationService for FinishPasskeyAuthenticationServiceImpl {
+    async fn execute(
+        &self,
+        authentication_finish: PasskeyAuthenticationFinish,
+    ) -> Result<UserSession, AppError> {
+        let challenge = self
+            .take_webauthn_challenge_repository
+            .execute(authentication_finish.ceremony_id)
+            .await
+            .map_err(AppError::server_error)?
+            .filter(|challenge| challenge.ceremony == WebauthnCeremony::Authentication)
+            .filter(|challenge| challenge.expires_at > self.clock.now())
+            .ok_or_else(|| {
+                AppError::client_error("Authentication ceremony not found or expired".to_string())
+            })?;
+
+        let credential = authentication_finish.credential;
+        let response = credential.response;
+
+        let raw_id = webauthn::decode(&credential.raw_id, "rawId").map_err(AppError::client_error)?;
+
+        let mut passkey = self
+            .find_passkey_by_id_repository
+            .execute(webauthn::encode(&raw_id))
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::unauthorized("Unknown passkey"))?;
+
+        if challenge
+            .user_id
+            .is_some_and(|user_id| user_id != passkey.user_id)
+        {
+            return Err(AppError::unauthorized("Passkey does not belong to this user"));
+        }
+
+        if let Some(user_handle) = &response.user_handle {
+            let user_handle =
+                webauthn::decode(user_handle, "userHandle").map_err(AppError::client_error)?;
+
+            if user_handle != passkey.user_id.as_str().as_bytes() {
+                return Err(AppError::unauthorized("Passkey does not belong to this user"));
+            }
+        }
+
+        let client_data_json = webauthn::decode(&response.client_data_json, "clientDataJSON")
+            .map_err(AppError::client_error)?;
+
+        webauthn::verify_client_data(
+            &client_data_json,
+            "webauthn.get",
+            &challenge.challenge,
+            &self.webauthn_config.origin,
+        )
+        .map_err(AppError::client_error)?;
+
+        let raw_authenticator_data =
+            webauthn::decode(&response.authenticator_data, "authenticatorData")
+                .map_err(AppError::client_error)?;
+
+        let authenticator_data = AuthenticatorData::parse(&raw_authenticator_data)
+            .map_err(AppError::client_error)?;
+
+        authenticator_data
+            .verify_rp_id(&self.webauthn_config.rp_id)
+            .map_err(AppError::client_error)?;
+
+        if !authenticator_data.user_present() {
+            return Err(AppError::client_error("User presence is required".to_string()));
+        }
+
+        // Requested as `required` when the ceremony started; enforced here since the client
+        // controls what the authenticator is actually asked for.
+        if !authenticator_data.user_verified() {
+            return Err(AppError::client_error("User verification is required".to_string()));
+        }
+
+        let signature =
+            webauthn::decode(&response.signature, "signature").map_err(AppError::client_error)?;
+
+        let mut signed_data = raw_authenticator_data;
+        signed_data.extend_from_slice(&webauthn::sha256(&client_data_json));
+
+        CosePublicKey::parse(&passkey.public_key)
+            .map_err(AppError::server_error)?
+            .verify(&signed_data, &signature)
+            .map_err(AppError::unauthorized)?;
+
+        // A counter that does not move forward means the authenticator may have been cloned.
+        // Authenticators that do not implement counters always report zero.
+        let counter_in_use = authenticator_data.sign_count != 0 || passkey.sign_count != 0;
+        if counter_in_use && authenticator_data.sign_count <= passkey.sign_count {
+            return Err(AppError::unauthorized("Passkey signature counter did not increase"));
+        }
+
+        passkey.sign_count = authenticator_data.sign_count;
+        passkey.last_used_at = Some(self.clock.now());
+
+        let user_id = passkey.user_id.clone();
+
+        self.update_passkey_repository
+            .execute(passkey)
+            .await
+            .map_err(AppError::server_error)?;
+
+        let user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::unauthorized("User not found"))?;
+
+        issue_user_session(
+            self.token_issuer.as_ref(),
+            self.uuid_generator.as_ref(),
+            self.clock.as_ref(),
+            &self.token_config,
+            UserPublic::from(user),
+        )
+    }
+}
@@ -0,0 +1,119 @@
+use crate::model::passkey_model::{
+    Passkey, PasskeyPublic, PasskeyRegistrationFinish, WebauthnCeremony,
+};
+use crate::repository::passkey_repository::create_passkey_repository::CreatePasskeyRepository;
+use crate::repository::passkey_repository::find_passkey_by_id_repository::FindPasskeyByIdRepository;
+use crate::repository::webauthn_challenge_repository::take_webauthn_challenge_repository::TakeWebauthnChallengeRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::webauthn::{self, CosePublicKey, WebauthnConfig};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait FinishPasskeyRegistrationService {
+    async fn execute(
+        &self,
+        registration_finish: PasskeyRegistrationFinish,
+    ) -> Result<PasskeyPublic, AppError>;
+}
+
+pub struct FinishPasskeyRegistrationServiceImpl {
+    pub take_webauthn_challenge_repository: Box<dyn TakeWebauthnChallengeRepository + Send + Sync + 'static>,
+    pub find_passkey_by_id_repository: Box<dyn FindPasskeyByIdRepository + Send + Sync + 'static>,
+    pub create_passkey_repository: Box<dyn CreatePasskeyRepository + Send + Sync + 'static>,
+    pub webauthn_config: WebauthnConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl FinishPasskeyRegistrationService for FinishPasskeyRegistrationServiceImpl {
+    async fn execute(
+        &self,
+        registration_finish: PasskeyRegistrationFinish,
+    ) -> Result<PasskeyPublic, AppError> {
+        let challenge = self
+            .take_webauthn_challenge_repository
+            .execute(registration_finish.ceremony_id)
+            .await
+            .map_err(AppError::server_error)?
+            .filter(|challenge| challenge.ceremony == WebauthnCeremony::Registration)
+            .filter(|challenge| challenge.expires_at > self.clock.now())
+            .ok_or_else(|| {
+                AppError::client_error("Registration ceremony not found or expired".to_string())
+            })?;
+
+        let user_id = challenge
+            .user_id
+            .ok_or_else(|| AppError::server_error("Registration challenge without user"))?;
+
+        let credential = registration_finish.credential;
+        let response = credential.response;
+
+        let client_data_json = webauthn::decode(&response.client_data_json, "clientDataJSON")
+            .map_err(AppError::client_error)?;
+
+        webauthn::verify_client_data(
+            &client_data_json,
+            "webauthn.create",
+            &challenge.challenge,
+            &self.webauthn_config.origin,
+        )
+        .map_err(AppError::client_error)?;
+
+        let attestation_object =
+            webauthn::decode(&response.attestation_object, "attestationObject")
+                .map_err(AppError::client_error)?;
+
+        let authenticator_data = webauthn::parse_attestation_object(&attestation_object)
+            .map_err(AppError::client_error)?;
+
+        authenticator_data
+            .verify_rp_id(&self.webauthn_config.rp_id)
+            .map_err(AppError::client_error)?;
+
+        if !authenticator_data.user_present() {
+            return Err(AppError::client_error("User presence is required".to_string()));
+        }
+
+        let attested_credential = authenticator_data
+            .attested_credential
+            .ok_or_else(|| AppError::client_error("Missing attested credential".to_string()))?;
+
+        let raw_id = webauthn::decode(&credential.raw_id, "rawId").map_err(AppError::client_error)?;
+
+        if raw_id != attested_credential.credential_id {
+            return Err(AppError::client_error("Credential id mismatch".to_string()));
+        }
+
+        CosePublicKey::parse(&attested_credential.public_key).map_err(AppError::client_error)?;
+
+        let passkey_id = webauthn::encode(&attested_credential.credential_id);
+
+        let passkey_exists = self
+            .find_passkey_by_id_repository
+            .execute(passkey_id.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        if passkey_exists.is_some() {
+            return Err(AppError::client_error("Passkey is already registered".to_string()));
+        }
+
+        let passkey = Passkey {
+            id: passkey_id,
+            user_id,
+            public_key: attested_credential.public_key,
+            sign_count: authenticator_data.sign_count,
+            created_at: self.clock.now(),
+            last_used_at: None,
+        };
+
+        let passkey_created = self
+            .create_passkey_repository
+            .execute(passkey)
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(passkey_created)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod start_passkey_registration_service;
+pub mod finish_passkey_registration_service;
+pub mod start_passkey_authentication_service;
+pub mod finish_passkey_authentication_service;
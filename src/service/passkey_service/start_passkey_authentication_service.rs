@@ -0,0 +1,104 @@
+use crate::model::email_model::EmailConfig;
+use crate::model::passkey_model::{
+    PasskeyAuthenticationOptions, PasskeyAuthenticationStart, PublicKeyCredentialDescriptor,
+    PublicKeyCredentialRequestOptions, WebauthnCeremony, WebauthnChallenge,
+};
+use crate::repository::passkey_repository::find_passkeys_by_user_id_repository::FindPasskeysByUserIdRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::webauthn_challenge_repository::create_webauthn_challenge_repository::CreateWebauthnChallengeRepository;
+use crate::service::passkey_service::start_passkey_registration_service::CEREMONY_TIMEOUT_SECONDS;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::token_generator::TokenGenerator;
+use crate::util::uuid_generator::UuidGenerator;
+use crate::util::webauthn::WebauthnConfig;
+use async_trait::async_trait;
+use chrono::Duration;
+
+#[async_trait]
+pub trait StartPasskeyAuthenticationService {
+    async fn execute(
+        &self,
+        authentication_start: PasskeyAuthenticationStart,
+    ) -> Result<PasskeyAuthenticationOptions, AppError>;
+}
+
+pub struct StartPasskeyAuthenticationServiceImpl {
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub find_passkeys_by_user_id_repository: Box<dyn FindPasskeysByUserIdRepository + Send + Sync + 'static>,
+    pub create_webauthn_challenge_repository: Box<dyn CreateWebauthnChallengeRepository + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub webauthn_config: WebauthnConfig,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartPasskeyAuthenticationService for StartPasskeyAuthenticationServiceImpl {
+    async fn execute(
+        &self,
+        authentication_start: PasskeyAuthenticationStart,
+    ) -> Result<PasskeyAuthenticationOptions, AppError> {
+        // Without an email the ceremony relies on discoverable credentials, and so does an
+        // unknown email. A known email with passkeys gets them in `allowCredentials`, which
+        // does tell the caller that the account exists.
+        let user = match authentication_start.email {
+            Some(email) => self
+                .find_user_by_email_repository
+                .execute(self.email_config.normalize(email))
+                .await
+                .map_err(AppError::server_error)?,
+            None => None,
+        };
+
+        let allow_credentials = match &user {
+            Some(user) => self
+                .find_passkeys_by_user_id_repository
+                .execute(user.id.clone())
+                .await
+                .map_err(AppError::server_error)?
+                .into_iter()
+                .map(|passkey| PublicKeyCredentialDescriptor {
+                    credential_type: "public-key".to_string(),
+                    id: passkey.id,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let ceremony_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let challenge = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        self.create_webauthn_challenge_repository
+            .execute(WebauthnChallenge {
+                id: ceremony_id.clone(),
+                challenge: challenge.clone(),
+                ceremony: WebauthnCeremony::Authentication,
+                user_id: user.map(|user| user.id),
+                expires_at: self.clock.now() + Duration::seconds(CEREMONY_TIMEOUT_SECONDS),
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(PasskeyAuthenticationOptions {
+            ceremony_id,
+            public_key: PublicKeyCredentialRequestOptions {
+                challenge,
+                timeout: (CEREMONY_TIMEOUT_SECONDS * 1000) as u64,
+                rp_id: self.webauthn_config.rp_id.clone(),
+                allow_credentials,
+                // The passkey is the only factor, so the authenticator must verify the user
+                // (PIN or biometrics), not just detect a touch.
+                user_verification: "required".to_string(),
+            },
+        })
+    }
+}
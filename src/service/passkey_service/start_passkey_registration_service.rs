@@ -0,0 +1,131 @@
+use crate::model::email_model::EmailConfig;
+use crate::model::passkey_model::{
+    AuthenticatorSelection, PasskeyRegistrationOptions, PasskeyRegistrationStart,
+    PublicKeyCredentialCreationOptions, PublicKeyCredentialDescriptor,
+    PublicKeyCredentialParameters, PublicKeyCredentialUser, RelyingParty, WebauthnCeremony,
+    WebauthnChallenge,
+};
+use crate::repository::passkey_repository::find_passkeys_by_user_id_repository::FindPasskeysByUserIdRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::webauthn_challenge_repository::create_webauthn_challenge_repository::CreateWebauthnChallengeRepository;
+use crate::service::auth_service::issue_user_session::ensure_can_sign_in;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::encryptor::Encryptor;
+use crate::util::token_generator::TokenGenerator;
+use crate::util::uuid_generator::UuidGenerator;
+use crate::util::webauthn::{self, ALG_EDDSA, ALG_ES256, ALG_RS256, WebauthnConfig};
+use async_trait::async_trait;
+use chrono::Duration;
+
+pub const CEREMONY_TIMEOUT_SECONDS: i64 = 300;
+
+#[async_trait]
+pub trait StartPasskeyRegistrationService {
+    async fn execute(
+        &self,
+        registration_start: PasskeyRegistrationStart,
+    ) -> Result<PasskeyRegistrationOptions, AppError>;
+}
+
+pub struct StartPasskeyRegistrationServiceImpl {
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub find_passkeys_by_user_id_repository: Box<dyn FindPasskeysByUserIdRepository + Send + Sync + 'static>,
+    pub create_webauthn_challenge_repository: Box<dyn CreateWebauthnChallengeRepository + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub webauthn_config: WebauthnConfig,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl StartPasskeyRegistrationService for StartPasskeyRegistrationServiceImpl {
+    async fn execute(
+        &self,
+        registration_start: PasskeyRegistrationStart,
+    ) -> Result<PasskeyRegistrationOptions, AppError> {
+        // Until sessions exist, the password proves ownership of the account the passkey is added to.
+        let user = self
+            .find_user_by_email_repository
+            .execute(self.email_config.normalize(registration_start.email.clone()))
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::unauthorized("Invalid email or password"))?;
+
+        let password_matches = self
+            .encryptor
+            .verify(registration_start.password.expose(), user.password.expose())
+            .map_err(AppError::server_error)?;
+
+        if !password_matches {
+            return Err(AppError::unauthorized("Invalid email or password"));
+        }
+
+        ensure_can_sign_in(&user.status)?;
+
+        let existing_passkeys = self
+            .find_passkeys_by_user_id_repository
+            .execute(user.id.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        let ceremony_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let challenge = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        self.create_webauthn_challenge_repository
+            .execute(WebauthnChallenge {
+                id: ceremony_id.clone(),
+                challenge: challenge.clone(),
+                ceremony: WebauthnCeremony::Registration,
+                user_id: Some(user.id.clone()),
+                expires_at: self.clock.now() + Duration::seconds(CEREMONY_TIMEOUT_SECONDS),
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(PasskeyRegistrationOptions {
+            ceremony_id,
+            public_key: PublicKeyCredentialCreationOptions {
+                challenge,
+                rp: RelyingParty {
+                    id: self.webauthn_config.rp_id.clone(),
+                    name: self.webauthn_config.rp_name.clone(),
+                },
+                user: PublicKeyCredentialUser {
+                    id: webauthn::encode(user.id.as_str().as_bytes()),
+                    name: user.email.to_string(),
+                    display_name: user.email.into(),
+                },
+                pub_key_cred_params: [ALG_ES256, ALG_EDDSA, ALG_RS256]
+                    .into_iter()
+                    .map(|alg| PublicKeyCredentialParameters {
+                        credential_type: "public-key".to_string(),
+                        alg,
+                    })
+                    .collect(),
+                timeout: (CEREMONY_TIMEOUT_SECONDS * 1000) as u64,
+                exclude_credentials: existing_passkeys
+                    .into_iter()
+                    .map(|passkey| PublicKeyCredentialDescriptor {
+                        credential_type: "public-key".to_string(),
+                        id: passkey.id,
+                    })
+                    .collect(),
+                authenticator_selection: AuthenticatorSelection {
+                    resident_key: "preferred".to_string(),
+                    user_verification: "preferred".to_string(),
+                },
+                attestation: "none".to_string(),
+            },
+        })
+    }
+}
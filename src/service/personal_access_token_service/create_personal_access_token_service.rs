@@ -0,0 +1,104 @@
+use crate::model::personal_access_token_model::{
+    MAX_PERSONAL_ACCESS_TOKEN_DAYS, PERSONAL_ACCESS_TOKEN_PREFIX, PersonalAccessToken,
+    PersonalAccessTokenCreated, PersonalAccessTokenForCreation, PersonalAccessTokenPublic,
+};
+use crate::model::principal_model::Principal;
+use crate::repository::personal_access_token_repository::create_personal_access_token_repository::CreatePersonalAccessTokenRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::secret::Secret;
+use crate::util::token_generator::{TokenGenerator, hash_token};
+use crate::util::uuid_generator::UuidGenerator;
+use async_trait::async_trait;
+use chrono::Duration;
+
+#[async_trait]
+pub trait CreatePersonalAccessTokenService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_for_creation: PersonalAccessTokenForCreation,
+    ) -> Result<PersonalAccessTokenCreated, AppError>;
+}
+
+pub struct CreatePersonalAccessTokenServiceImpl {
+    pub create_personal_access_token_repository:
+        Box<dyn CreatePersonalAccessTokenRepository + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub token_generator: Box<dyn TokenGenerator + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl CreatePersonalAccessTokenService for CreatePersonalAccessTokenServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_for_creation: PersonalAccessTokenForCreation,
+    ) -> Result<PersonalAccessTokenCreated, AppError> {
+        let user_id = principal.require_user()?.clone();
+
+        let name = token_for_creation.name.trim().to_string();
+        if name.is_empty() {
+            return Err(AppError::client_error("Token name is required".to_string()));
+        }
+
+        if !(1..=MAX_PERSONAL_ACCESS_TOKEN_DAYS).contains(&token_for_creation.expires_in_days) {
+            return Err(AppError::client_error(format!(
+                "expires_in_days must be between 1 and {}",
+                MAX_PERSONAL_ACCESS_TOKEN_DAYS
+            )));
+        }
+
+        if token_for_creation.scopes.is_empty() {
+            return Err(AppError::client_error("At least one scope is required".to_string()));
+        }
+
+        // A token can never grant more than the credential used to create it.
+        if let Some(scope) = token_for_creation
+            .scopes
+            .iter()
+            .find(|scope| !principal.has_scope(scope))
+        {
+            return Err(AppError::forbidden(format!("Scope {} cannot be granted", scope)));
+        }
+
+        let mut scopes = token_for_creation.scopes;
+        scopes.sort();
+        scopes.dedup();
+
+        let id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let secret = self
+            .token_generator
+            .generate(32)
+            .map_err(AppError::server_error)?;
+
+        let token = format!("{}{}", PERSONAL_ACCESS_TOKEN_PREFIX, secret);
+        let now = self.clock.now();
+
+        let personal_access_token = self
+            .create_personal_access_token_repository
+            .execute(PersonalAccessToken {
+                id,
+                user_id,
+                name,
+                token_hash: Secret::new(hash_token(&token)),
+                scopes,
+                expires_at: now + Duration::days(token_for_creation.expires_in_days),
+                created_at: now,
+                last_used_at: None,
+                revoked_at: None,
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(PersonalAccessTokenCreated {
+            token: Secret::new(token),
+            personal_access_token: PersonalAccessTokenPublic::from(personal_access_token),
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use crate::model::personal_access_token_model::PersonalAccessTokenPublic;
+use crate::model::principal_model::Principal;
+use crate::repository::personal_access_token_repository::find_personal_access_tokens_by_user_id_repository::FindPersonalAccessTokensByUserIdRepository;
+use crate::util::app_error::AppError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ListPersonalAccessTokensService {
+    async fn execute(&self, principal: Principal) -> Result<Vec<PersonalAccessTokenPublic>, AppError>;
+}
+
+pub struct ListPersonalAccessTokensServiceImpl {
+    pub find_personal_access_tokens_by_user_id_repository:
+        Box<dyn FindPersonalAccessTokensByUserIdRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ListPersonalAccessTokensService for ListPersonalAccessTokensServiceImpl {
+    async fn execute(&self, principal: Principal) -> Result<Vec<PersonalAccessTokenPublic>, AppError> {
+        let user_id = principal.require_user()?.clone();
+
+        let tokens = self
+            .find_personal_access_tokens_by_user_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(tokens.into_iter().map(PersonalAccessTokenPublic::from).collect())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod create_personal_access_token_service;
+pub mod list_personal_access_tokens_service;
+pub mod revoke_personal_access_token_service;
@@ -0,0 +1,55 @@
+use crate::model::personal_access_token_model::PersonalAccessTokenPublic;
+use crate::model::principal_model::Principal;
+use crate::repository::personal_access_token_repository::find_personal_access_token_by_id_repository::FindPersonalAccessTokenByIdRepository;
+use crate::repository::personal_access_token_repository::update_personal_access_token_repository::UpdatePersonalAccessTokenRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RevokePersonalAccessTokenService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_id: String,
+    ) -> Result<PersonalAccessTokenPublic, AppError>;
+}
+
+pub struct RevokePersonalAccessTokenServiceImpl {
+    pub find_personal_access_token_by_id_repository:
+        Box<dyn FindPersonalAccessTokenByIdRepository + Send + Sync + 'static>,
+    pub update_personal_access_token_repository:
+        Box<dyn UpdatePersonalAccessTokenRepository + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl RevokePersonalAccessTokenService for RevokePersonalAccessTokenServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        token_id: String,
+    ) -> Result<PersonalAccessTokenPublic, AppError> {
+        let user_id = principal.require_user()?;
+
+        // Tokens of other users are reported as missing so their ids cannot be probed.
+        let mut token = self
+            .find_personal_access_token_by_id_repository
+            .execute(token_id)
+            .await
+            .map_err(AppError::server_error)?
+            .filter(|token| token.user_id == *user_id)
+            .ok_or_else(|| AppError::not_found("Personal access token not found"))?;
+
+        if token.revoked_at.is_none() {
+            token.revoked_at = Some(self.clock.now());
+
+            self.update_personal_access_token_repository
+                .execute(token.clone())
+                .await
+                .map_err(AppError::server_error)?;
+        }
+
+        Ok(PersonalAccessTokenPublic::from(token))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::model::token_model::Jwks;
+use crate::util::app_error::AppError;
+use crate::util::token_issuer::TokenIssuer;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetJwksService {
+    async fn execute(&self) -> Result<Jwks, AppError>;
+}
+
+pub struct GetJwksServiceImpl {
+    pub token_issuer: Box<dyn TokenIssuer + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetJwksService for GetJwksServiceImpl {
+    async fn execute(&self) -> Result<Jwks, AppError> {
+        self.token_issuer
+            .public_jwks()
+            .map_err(AppError::server_error)
+    }
+}
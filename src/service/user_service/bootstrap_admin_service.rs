@@ -0,0 +1,56 @@
+use crate::model::email_model::{Email, EmailConfig};
+use crate::model::user_model::{UserForCreation, UserPublic, UserRole};
+use crate::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::service::user_service::create_user_service::CreateUserService;
+use crate::service::user_service::email_availability::is_email_available;
+use crate::util::app_error::AppError;
+use crate::util::retention::RetentionConfig;
+use crate::util::secret::Secret;
+use async_trait::async_trait;
+
+/// Creates the configured operator account as an admin when `serve` starts. Returns `None`
+/// when the email is already taken: an existing account is never promoted or otherwise
+/// touched, so registering the address first does not grant admin, and a deliberate
+/// demotion survives restarts.
+#[async_trait]
+pub trait BootstrapAdminService {
+    async fn execute(&self, email: Email, password: Secret<String>) -> Result<Option<UserPublic>, AppError>;
+}
+
+pub struct BootstrapAdminServiceImpl {
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub find_deleted_user_by_email_repository: Box<dyn FindDeletedUserByEmailRepository + Send + Sync + 'static>,
+    /// The regular signup service, so the admin gets the same checks and metrics as any user.
+    pub create_user_service: Box<dyn CreateUserService + Send + Sync + 'static>,
+    pub retention_config: RetentionConfig,
+    pub email_config: EmailConfig,
+}
+
+#[async_trait]
+impl BootstrapAdminService for BootstrapAdminServiceImpl {
+    async fn execute(&self, email: Email, password: Secret<String>) -> Result<Option<UserPublic>, AppError> {
+        let email = self.email_config.normalize(email);
+
+        let available = is_email_available(
+            self.find_user_by_email_repository.as_ref(),
+            self.find_deleted_user_by_email_repository.as_ref(),
+            &self.retention_config,
+            &email,
+        )
+        .await?;
+
+        if !available {
+            return Ok(None);
+        }
+
+        self.create_user_service
+            .execute(UserForCreation {
+                email,
+                password,
+                role: UserRole::Admin,
+            })
+            .await
+            .map(Some)
+    }
+}
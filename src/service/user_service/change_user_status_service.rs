@@ -0,0 +1,109 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic, UserStatus};
+use crate::model::user_status_model::{UserStatusChange, UserStatusChangeRequest};
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::update_user_repository::UpdateUserRepository;
+use crate::repository::user_status_history_repository::create_user_status_change_repository::CreateUserStatusChangeRepository;
+use crate::service::user_service::email_availability::update_failed;
+use crate::service::user_service::user_access::{UserAccess, resolve_user_access};
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::uuid_generator::UuidGenerator;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ChangeUserStatusService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        status: UserStatus,
+        change_request: UserStatusChangeRequest,
+    ) -> Result<UserPublic, AppError>;
+}
+
+pub struct ChangeUserStatusServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub update_user_repository: Box<dyn UpdateUserRepository + Send + Sync + 'static>,
+    pub create_user_status_change_repository:
+        Box<dyn CreateUserStatusChangeRepository + Send + Sync + 'static>,
+    pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ChangeUserStatusService for ChangeUserStatusServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        status: UserStatus,
+        change_request: UserStatusChangeRequest,
+    ) -> Result<UserPublic, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if access != UserAccess::Admin {
+            return Err(AppError::forbidden("Only admins can change account status"));
+        }
+
+        // Admins cannot lock themselves out; another admin has to do it.
+        let admin_id = principal.require_user()?.clone();
+        if admin_id == user_id {
+            return Err(AppError::forbidden("Admins cannot change their own account status"));
+        }
+
+        let reason = change_request.reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(AppError::client_error("A reason is required".to_string()));
+        }
+
+        let mut user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        if !user.status.can_change_to(&status) {
+            return Err(AppError::client_error(format!(
+                "Cannot change status from {:?} to {:?}",
+                user.status, status
+            )));
+        }
+
+        let change_id = self
+            .uuid_generator
+            .generate()
+            .map_err(AppError::server_error)?;
+
+        let now = self.clock.now();
+        let previous_status = user.status.clone();
+        let expected_version = user.version;
+
+        user.status = status.clone();
+        user.updated_at = now;
+
+        // Written before the history entry so a lost race leaves no phantom change behind.
+        let updated_user = self
+            .update_user_repository
+            .execute(user, expected_version)
+            .await
+            .map_err(update_failed)?
+            .ok_or_else(|| AppError::precondition_failed("The user was modified concurrently, try again"))?;
+
+        self.create_user_status_change_repository
+            .execute(UserStatusChange {
+                id: change_id,
+                user_id: updated_user.id.clone(),
+                from: previous_status,
+                to: status,
+                reason,
+                changed_by: admin_id,
+                changed_at: now,
+            })
+            .await
+            .map_err(AppError::server_error)?;
+
+        Ok(updated_user)
+    }
+}
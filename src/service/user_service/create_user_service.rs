@@ -1,11 +1,18 @@
-use crate::model::user_model::{User, UserForCreation, UserPublic};
-use crate::repository::user_repository::create_user_repository::CreateUserRepository;
+use crate::model::email_model::EmailConfig;
+use crate::model::user_model::{User, UserForCreation, UserId, UserPublic, UserStatus};
+use crate::repository::user_repository::create_user_repository::{CreateUserError, CreateUserRepository};
+use crate::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
 use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
-use crate::util::app_error::AppError;
+use crate::service::user_service::email_availability::{email_taken, is_email_available};
+use crate::util::app_error::{AppError, ErrorType};
+use crate::util::clock::Clock;
 use crate::util::encryptor::Encryptor;
+use crate::util::metrics::{SignupOutcome, record_signup};
+use crate::util::retention::RetentionConfig;
+use crate::util::secret::Secret;
 use crate::util::uuid_generator::UuidGenerator;
 use async_trait::async_trait;
-use chrono::Utc;
+use tracing::Instrument;
 
 #[async_trait]
 pub trait CreateUserService {
@@ -15,47 +22,103 @@ pub trait CreateUserService {
 pub struct CreateUserServiceImpl {
     pub create_user_repository: Box<dyn CreateUserRepository + Send + Sync + 'static>,
     pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub find_deleted_user_by_email_repository: Box<dyn FindDeletedUserByEmailRepository + Send + Sync + 'static>,
     pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
     pub uuid_generator: Box<dyn UuidGenerator + Send + Sync + 'static>,
+    pub retention_config: RetentionConfig,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
 }
 
 #[async_trait]
 impl CreateUserService for CreateUserServiceImpl {
+    #[tracing::instrument(name = "create_user", skip_all)]
     async fn execute(&self, user_for_creation: UserForCreation) -> Result<UserPublic, AppError> {
-        let user_exists = self
-            .find_user_by_email_repository
-            .execute(user_for_creation.email.clone())
-            .await
-            .map_err(|err| AppError::server_error(err))?;
-
-        if user_exists.is_some() {
-            return Err(AppError::client_error("User with this email already exists".to_string()));
+        match self.create(user_for_creation).await {
+            Ok(user) => {
+                record_signup(SignupOutcome::Created);
+                Ok(user)
+            }
+            Err((outcome, error)) => {
+                record_signup(outcome);
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Errors not classified at the point they are raised: anything but a server error is the
+/// request being refused, never a duplicate.
+fn failed(error: AppError) -> (SignupOutcome, AppError) {
+    let outcome = match error.error_type {
+        ErrorType::ServerError => SignupOutcome::Error,
+        _ => SignupOutcome::Rejected,
+    };
+
+    (outcome, error)
+}
+
+impl CreateUserServiceImpl {
+    async fn create(
+        &self,
+        mut user_for_creation: UserForCreation,
+    ) -> Result<UserPublic, (SignupOutcome, AppError)> {
+        user_for_creation.email = self.email_config.normalize(user_for_creation.email);
+
+        let available = is_email_available(
+            self.find_user_by_email_repository.as_ref(),
+            self.find_deleted_user_by_email_repository.as_ref(),
+            &self.retention_config,
+            &user_for_creation.email,
+        )
+        .instrument(tracing::info_span!("find_user_by_email"))
+        .await
+        .map_err(failed)?;
+
+        if !available {
+            return Err((SignupOutcome::Duplicate, email_taken()));
         }
 
         let user_id = self
             .uuid_generator
             .generate()
-        .map_err(|err| AppError::server_error(err))?;
+            .map_err(AppError::server_error)
+            .map_err(failed)?;
 
-        let hashed_password = self
-            .encryptor
-            .encrypt(&user_for_creation.password)
-        .map_err(|err| AppError::server_error(err))?;
+        // Failures are mapped inside each span so the logged detail is attributed to the step.
+        let hashed_password = tracing::info_span!("hash_password")
+            .in_scope(|| {
+                self.encryptor
+                    .encrypt(user_for_creation.password.expose())
+                    .map_err(AppError::server_error)
+            })
+            .map_err(failed)?;
+
+        let now = self.clock.now();
 
         let user = User {
-            id: user_id.clone(),
+            id: UserId::from(user_id),
             email: user_for_creation.email.clone(),
-            password: hashed_password,
+            password: Secret::new(hashed_password),
             role: user_for_creation.role,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
+            status: UserStatus::Active,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            version: 1,
         };
 
-        let user_created = self
-            .create_user_repository
-            .execute(user)
-            .await
-        .map_err(|err| AppError::server_error(err))?;
+        let user_created = async {
+            self.create_user_repository
+                .execute(user)
+                .await
+                .map_err(|error| match error {
+                    CreateUserError::DuplicateEmail => (SignupOutcome::Duplicate, email_taken()),
+                    CreateUserError::Storage(detail) => failed(AppError::server_error(detail)),
+                })
+        }
+        .instrument(tracing::info_span!("insert_user"))
+        .await?;
 
         Ok(user_created)
     }
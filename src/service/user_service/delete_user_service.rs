@@ -0,0 +1,59 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::soft_delete_user_repository::SoftDeleteUserRepository;
+use crate::service::user_service::user_access::resolve_user_access;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::etag::ensure_if_match;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait DeleteUserService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+    ) -> Result<UserPublic, AppError>;
+}
+
+pub struct DeleteUserServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub soft_delete_user_repository: Box<dyn SoftDeleteUserRepository + Send + Sync + 'static>,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl DeleteUserService for DeleteUserServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+    ) -> Result<UserPublic, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if !access.can_write(&user_id) {
+            return Err(AppError::forbidden("You can only delete your own account"));
+        }
+
+        let user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        ensure_if_match(if_match.as_deref(), user.version)?;
+
+        let user = self
+            .soft_delete_user_repository
+            .execute(user.id, user.version, self.clock.now())
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::precondition_failed("The resource was modified since it was fetched"))?;
+
+        Ok(UserPublic::from(user))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::model::email_model::Email;
+use crate::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::update_user_repository::UpdateUserError;
+use crate::util::app_error::AppError;
+use crate::util::retention::{EmailReusePolicy, RetentionConfig};
+
+/// Shared by signup and email changes. Whether a soft-deleted user still holds its email
+/// depends on the retention policy.
+pub async fn ensure_email_available(
+    find_user_by_email_repository: &(dyn FindUserByEmailRepository + Send + Sync),
+    find_deleted_user_by_email_repository: &(dyn FindDeletedUserByEmailRepository + Send + Sync),
+    retention_config: &RetentionConfig,
+    email: &Email,
+) -> Result<(), AppError> {
+    let available = is_email_available(
+        find_user_by_email_repository,
+        find_deleted_user_by_email_repository,
+        retention_config,
+        email,
+    )
+    .await?;
+
+    if available { Ok(()) } else { Err(email_taken()) }
+}
+
+/// Same check as `ensure_email_available` for callers that need to tell a taken email apart
+/// from other failures; the error is always a server error.
+pub async fn is_email_available(
+    find_user_by_email_repository: &(dyn FindUserByEmailRepository + Send + Sync),
+    find_deleted_user_by_email_repository: &(dyn FindDeletedUserByEmailRepository + Send + Sync),
+    retention_config: &RetentionConfig,
+    email: &Email,
+) -> Result<bool, AppError> {
+    let user_exists = find_user_by_email_repository
+        .execute(email.clone())
+        .await
+        .map_err(AppError::server_error)?;
+
+    if user_exists.is_some() {
+        return Ok(false);
+    }
+
+    if retention_config.email_reuse == EmailReusePolicy::AfterPurge {
+        let deleted_user_exists = find_deleted_user_by_email_repository
+            .execute(email.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        if deleted_user_exists.is_some() {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Also returned when the repository reports a duplicate that slipped past the check above.
+pub fn email_taken() -> AppError {
+    AppError::client_error("User with this email already exists".to_string())
+}
+
+/// For writes that go through `UpdateUserRepository`; only an email change or a restore
+/// can actually hit the duplicate.
+pub fn update_failed(error: UpdateUserError) -> AppError {
+    match error {
+        UpdateUserError::DuplicateEmail => email_taken(),
+        UpdateUserError::Storage(detail) => AppError::server_error(detail),
+    }
+}
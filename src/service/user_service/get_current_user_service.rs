@@ -0,0 +1,30 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::UserPublic;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::util::app_error::AppError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetCurrentUserService {
+    async fn execute(&self, principal: Principal) -> Result<UserPublic, AppError>;
+}
+
+pub struct GetCurrentUserServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetCurrentUserService for GetCurrentUserServiceImpl {
+    async fn execute(&self, principal: Principal) -> Result<UserPublic, AppError> {
+        let user_id = principal.require_user()?.clone();
+
+        let user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::unauthorized("User not found"))?;
+
+        Ok(UserPublic::from(user))
+    }
+}
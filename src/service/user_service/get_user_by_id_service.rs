@@ -0,0 +1,35 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::service::user_service::user_access::resolve_user_access;
+use crate::util::app_error::AppError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetUserByIdService {
+    async fn execute(&self, principal: Principal, user_id: UserId) -> Result<UserPublic, AppError>;
+}
+
+pub struct GetUserByIdServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetUserByIdService for GetUserByIdServiceImpl {
+    async fn execute(&self, principal: Principal, user_id: UserId) -> Result<UserPublic, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if !access.can_read(&user_id) {
+            return Err(AppError::forbidden("You can only access your own account"));
+        }
+
+        let user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        Ok(UserPublic::from(user))
+    }
+}
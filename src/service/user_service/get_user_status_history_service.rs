@@ -0,0 +1,49 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::UserId;
+use crate::model::user_status_model::UserStatusChange;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_status_history_repository::find_user_status_changes_by_user_id_repository::FindUserStatusChangesByUserIdRepository;
+use crate::service::user_service::user_access::{UserAccess, resolve_user_access};
+use crate::util::app_error::AppError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait GetUserStatusHistoryService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+    ) -> Result<Vec<UserStatusChange>, AppError>;
+}
+
+pub struct GetUserStatusHistoryServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub find_user_status_changes_by_user_id_repository:
+        Box<dyn FindUserStatusChangesByUserIdRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl GetUserStatusHistoryService for GetUserStatusHistoryServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+    ) -> Result<Vec<UserStatusChange>, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if access != UserAccess::Admin {
+            return Err(AppError::forbidden("Only admins can read account status history"));
+        }
+
+        self.find_user_by_id_repository
+            .execute(user_id.clone())
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        self.find_user_status_changes_by_user_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)
+    }
+}
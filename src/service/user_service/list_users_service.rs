@@ -0,0 +1,110 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::UserPublic;
+use crate::model::user_query_model::{
+    DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, Page, UserCursor, UserFilter, UserListParams, UserListQuery,
+    UserSort,
+};
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::list_users_repository::ListUsersRepository;
+use crate::service::user_service::user_access::{UserAccess, resolve_user_access};
+use crate::util::app_error::AppError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ListUsersService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        params: UserListParams,
+    ) -> Result<Page<UserPublic>, AppError>;
+}
+
+pub struct ListUsersServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub list_users_repository: Box<dyn ListUsersRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl ListUsersService for ListUsersServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        params: UserListParams,
+    ) -> Result<Page<UserPublic>, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if let UserAccess::Owner(_) = access {
+            return Err(AppError::forbidden("Only admins can list users"));
+        }
+
+        let query = build_query(params).map_err(AppError::client_error)?;
+        let limit = query.limit;
+        let sort = query.sort;
+
+        let mut users = self
+            .list_users_repository
+            .execute(query)
+            .await
+            .map_err(AppError::server_error)?;
+
+        // Backends return one extra row so we know whether another page exists.
+        let has_more = users.len() > limit;
+        users.truncate(limit);
+
+        let next_cursor = match users.last().filter(|_| has_more) {
+            Some(last) => Some(
+                UserCursor {
+                    sort,
+                    value: sort.value_of(last),
+                    id: last.id.clone(),
+                }
+                .encode()
+                .map_err(AppError::server_error)?,
+            ),
+            None => None,
+        };
+
+        Ok(Page {
+            items: users.into_iter().map(UserPublic::from).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+}
+
+fn build_query(params: UserListParams) -> Result<UserListQuery, String> {
+    let limit = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(format!("limit must be between 1 and {}", MAX_PAGE_SIZE));
+    }
+
+    let sort = match params.sort.as_deref() {
+        Some(sort) => UserSort::parse(sort)?,
+        None => UserSort::default(),
+    };
+
+    let after = params.cursor.as_deref().map(UserCursor::decode).transpose()?;
+
+    // A cursor is a position in one specific ordering and means nothing in another.
+    if after.as_ref().is_some_and(|cursor| cursor.sort != sort) {
+        return Err("Cursor was issued for a different sort order".to_string());
+    }
+
+    if let (Some(from), Some(to)) = (params.created_from, params.created_to)
+        && from >= to
+    {
+        return Err("created_from must be before created_to".to_string());
+    }
+
+    Ok(UserListQuery {
+        limit,
+        after,
+        filter: UserFilter {
+            role: params.role,
+            email_prefix: params.email_prefix.filter(|prefix| !prefix.is_empty()),
+            created_from: params.created_from,
+            created_to: params.created_to,
+        },
+        sort,
+    })
+}
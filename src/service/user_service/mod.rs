@@ -1 +1,14 @@
-pub mod create_user_service;
\ No newline at end of file
+pub mod create_user_service;
+pub mod get_current_user_service;
+pub mod user_access;
+pub mod get_user_by_id_service;
+pub mod list_users_service;
+pub mod update_user_service;
+pub mod delete_user_service;
+pub mod change_user_status_service;
+pub mod get_user_status_history_service;
+pub mod email_availability;
+pub mod restore_user_service;
+pub mod purge_deleted_users_service;
+pub mod set_user_role_service;
+pub mod bootstrap_admin_service;
@@ -0,0 +1,81 @@
+use crate::model::user_model::UserPurgeResult;
+use crate::repository::oidc_identity_repository::delete_oidc_identities_by_user_id_repository::DeleteOidcIdentitiesByUserIdRepository;
+use crate::repository::passkey_repository::delete_passkeys_by_user_id_repository::DeletePasskeysByUserIdRepository;
+use crate::repository::personal_access_token_repository::delete_personal_access_tokens_by_user_id_repository::DeletePersonalAccessTokensByUserIdRepository;
+use crate::repository::user_repository::find_users_to_purge_repository::FindUsersToPurgeRepository;
+use crate::repository::user_repository::purge_deleted_user_repository::PurgeDeletedUserRepository;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::retention::RetentionConfig;
+use async_trait::async_trait;
+
+/// Permanently erases users whose restore window has passed, together with their
+/// credentials. The status history is kept as an audit trail; it holds no personal data.
+///
+/// Credentials go first and the user row last, so a failure part way leaves a user that is
+/// still soft-deleted and picked up again by the next run, never credentials without a user.
+#[async_trait]
+pub trait PurgeDeletedUsersService {
+    async fn execute(&self) -> Result<UserPurgeResult, AppError>;
+}
+
+pub struct PurgeDeletedUsersServiceImpl {
+    pub find_users_to_purge_repository: Box<dyn FindUsersToPurgeRepository + Send + Sync + 'static>,
+    pub purge_deleted_user_repository: Box<dyn PurgeDeletedUserRepository + Send + Sync + 'static>,
+    pub delete_passkeys_by_user_id_repository:
+        Box<dyn DeletePasskeysByUserIdRepository + Send + Sync + 'static>,
+    pub delete_personal_access_tokens_by_user_id_repository:
+        Box<dyn DeletePersonalAccessTokensByUserIdRepository + Send + Sync + 'static>,
+    pub delete_oidc_identities_by_user_id_repository:
+        Box<dyn DeleteOidcIdentitiesByUserIdRepository + Send + Sync + 'static>,
+    pub retention_config: RetentionConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl PurgeDeletedUsersService for PurgeDeletedUsersServiceImpl {
+    async fn execute(&self) -> Result<UserPurgeResult, AppError> {
+        let deleted_before = self.clock.now() - self.retention_config.restore_window();
+
+        let user_ids = self
+            .find_users_to_purge_repository
+            .execute(deleted_before)
+            .await
+            .map_err(AppError::server_error)?;
+
+        let mut purged_users = 0;
+
+        for user_id in user_ids {
+            self.delete_passkeys_by_user_id_repository
+                .execute(user_id.clone())
+                .await
+                .map_err(AppError::server_error)?;
+
+            self.delete_personal_access_tokens_by_user_id_repository
+                .execute(user_id.clone())
+                .await
+                .map_err(AppError::server_error)?;
+
+            self.delete_oidc_identities_by_user_id_repository
+                .execute(user_id.clone())
+                .await
+                .map_err(AppError::server_error)?;
+
+            let purged = self
+                .purge_deleted_user_repository
+                .execute(user_id)
+                .await
+                .map_err(AppError::server_error)?;
+
+            if purged {
+                purged_users += 1;
+            }
+        }
+
+        if purged_users > 0 {
+            tracing::info!("Purged {} deleted users", purged_users);
+        }
+
+        Ok(UserPurgeResult { purged_users })
+    }
+}
@@ -0,0 +1,77 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserPublic};
+use crate::repository::user_repository::find_deleted_user_by_id_repository::FindDeletedUserByIdRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::update_user_repository::UpdateUserRepository;
+use crate::service::user_service::email_availability::update_failed;
+use crate::service::user_service::user_access::{UserAccess, resolve_user_access};
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::retention::RetentionConfig;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait RestoreUserService {
+    async fn execute(&self, principal: Principal, user_id: UserId) -> Result<UserPublic, AppError>;
+}
+
+pub struct RestoreUserServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub find_deleted_user_by_id_repository:
+        Box<dyn FindDeletedUserByIdRepository + Send + Sync + 'static>,
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub update_user_repository: Box<dyn UpdateUserRepository + Send + Sync + 'static>,
+    pub retention_config: RetentionConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl RestoreUserService for RestoreUserServiceImpl {
+    async fn execute(&self, principal: Principal, user_id: UserId) -> Result<UserPublic, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if access != UserAccess::Admin {
+            return Err(AppError::forbidden("Only admins can restore users"));
+        }
+
+        let mut user = self
+            .find_deleted_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("Deleted user not found"))?;
+
+        let now = self.clock.now();
+
+        if user
+            .deleted_at
+            .is_some_and(|deleted_at| deleted_at + self.retention_config.restore_window() <= now)
+        {
+            return Err(AppError::client_error("The restore window for this user has passed".to_string()));
+        }
+
+        // With immediate email reuse someone may have signed up with the same email meanwhile.
+        let email_owner = self
+            .find_user_by_email_repository
+            .execute(user.email.clone())
+            .await
+            .map_err(AppError::server_error)?;
+
+        if email_owner.is_some() {
+            return Err(AppError::client_error(
+                "Another user has registered this email since the deletion".to_string(),
+            ));
+        }
+
+        let expected_version = user.version;
+        user.deleted_at = None;
+        user.updated_at = now;
+
+        self.update_user_repository
+            .execute(user, expected_version)
+            .await
+            .map_err(update_failed)?
+            .ok_or_else(|| AppError::precondition_failed("The user was modified concurrently, try again"))
+    }
+}
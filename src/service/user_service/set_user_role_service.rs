@@ -0,0 +1,48 @@
+use crate::model::email_model::{Email, EmailConfig};
+use crate::model::user_model::{UserPublic, UserRole};
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::update_user_repository::UpdateUserRepository;
+use crate::service::user_service::email_availability::update_failed;
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use async_trait::async_trait;
+
+/// Operator-only role change used by the CLI. There is no principal: whoever can run the
+/// binary against the store is already trusted.
+#[async_trait]
+pub trait SetUserRoleService {
+    async fn execute(&self, email: Email, role: UserRole) -> Result<UserPublic, AppError>;
+}
+
+pub struct SetUserRoleServiceImpl {
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub update_user_repository: Box<dyn UpdateUserRepository + Send + Sync + 'static>,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl SetUserRoleService for SetUserRoleServiceImpl {
+    async fn execute(&self, email: Email, role: UserRole) -> Result<UserPublic, AppError> {
+        let mut user = self
+            .find_user_by_email_repository
+            .execute(self.email_config.normalize(email))
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        if user.role == role {
+            return Ok(UserPublic::from(user));
+        }
+
+        let expected_version = user.version;
+        user.role = role;
+        user.updated_at = self.clock.now();
+
+        self.update_user_repository
+            .execute(user, expected_version)
+            .await
+            .map_err(update_failed)?
+            .ok_or_else(|| AppError::precondition_failed("The user was modified concurrently, try again"))
+    }
+}
@@ -0,0 +1,137 @@
+use crate::model::email_model::EmailConfig;
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserForUpdate, UserId, UserPublic};
+use crate::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::repository::user_repository::update_user_repository::UpdateUserRepository;
+use crate::service::user_service::email_availability::{ensure_email_available, update_failed};
+use crate::service::user_service::user_access::{UserAccess, resolve_user_access};
+use crate::util::app_error::AppError;
+use crate::util::clock::Clock;
+use crate::util::encryptor::Encryptor;
+use crate::util::etag::ensure_if_match;
+use crate::util::retention::RetentionConfig;
+use crate::util::secret::Secret;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait UpdateUserService {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+        user_for_update: UserForUpdate,
+    ) -> Result<UserPublic, AppError>;
+}
+
+pub struct UpdateUserServiceImpl {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+    pub find_user_by_email_repository: Box<dyn FindUserByEmailRepository + Send + Sync + 'static>,
+    pub find_deleted_user_by_email_repository:
+        Box<dyn FindDeletedUserByEmailRepository + Send + Sync + 'static>,
+    pub update_user_repository: Box<dyn UpdateUserRepository + Send + Sync + 'static>,
+    pub encryptor: Box<dyn Encryptor + Send + Sync + 'static>,
+    pub retention_config: RetentionConfig,
+    pub email_config: EmailConfig,
+    pub clock: Box<dyn Clock + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl UpdateUserService for UpdateUserServiceImpl {
+    async fn execute(
+        &self,
+        principal: Principal,
+        user_id: UserId,
+        if_match: Option<String>,
+        user_for_update: UserForUpdate,
+    ) -> Result<UserPublic, AppError> {
+        let access = resolve_user_access(self.find_user_by_id_repository.as_ref(), &principal).await?;
+
+        if !access.can_write(&user_id) {
+            return Err(AppError::forbidden("You can only edit your own account"));
+        }
+
+        let mut user = self
+            .find_user_by_id_repository
+            .execute(user_id)
+            .await
+            .map_err(AppError::server_error)?
+            .ok_or_else(|| AppError::not_found("User not found"))?;
+
+        ensure_if_match(if_match.as_deref(), user.version)?;
+
+        let expected_version = user.version;
+        let mut changed = false;
+
+        if let Some(role) = user_for_update.role.filter(|role| *role != user.role) {
+            if access != UserAccess::Admin {
+                return Err(AppError::forbidden("Only admins can change roles"));
+            }
+
+            user.role = role;
+            changed = true;
+        }
+
+        let new_email = user_for_update
+            .email
+            .map(|email| self.email_config.normalize(email))
+            .filter(|email| *email != user.email);
+
+        // A bearer token alone, such as a leaked PAT, must not be enough to take over the
+        // account. Admins editing someone else cannot know that password and skip the check.
+        let is_self = principal.user_id.as_ref() == Some(&user.id);
+        if is_self && (new_email.is_some() || user_for_update.password.is_some()) {
+            let current_password = user_for_update.current_password.ok_or_else(|| {
+                AppError::client_error(
+                    "current_password is required to change your email or password".to_string(),
+                )
+            })?;
+
+            let password_matches = self
+                .encryptor
+                .verify(current_password.expose(), user.password.expose())
+                .map_err(AppError::server_error)?;
+
+            if !password_matches {
+                return Err(AppError::forbidden("The current password is incorrect"));
+            }
+        }
+
+        if let Some(email) = new_email {
+            ensure_email_available(
+                self.find_user_by_email_repository.as_ref(),
+                self.find_deleted_user_by_email_repository.as_ref(),
+                &self.retention_config,
+                &email,
+            )
+            .await?;
+
+            user.email = email;
+            changed = true;
+        }
+
+        if let Some(password) = user_for_update.password {
+            user.password = self
+                .encryptor
+                .encrypt(password.expose())
+                .map(Secret::new)
+                .map_err(AppError::server_error)?;
+            changed = true;
+        }
+
+        // A request that changes nothing must not look like a modification.
+        if !changed {
+            return Ok(UserPublic::from(user));
+        }
+
+        user.updated_at = self.clock.now();
+
+        self.update_user_repository
+            .execute(user, expected_version)
+            .await
+            .map_err(update_failed)?
+            .ok_or_else(|| AppError::precondition_failed("The resource was modified since it was fetched"))
+    }
+}
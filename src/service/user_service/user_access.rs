@@ -0,0 +1,53 @@
+use crate::model::principal_model::Principal;
+use crate::model::user_model::{UserId, UserRole};
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::util::app_error::AppError;
+
+/// Ownership rules for user resources: admins act on anyone, users only on themselves and
+/// OAuth clients (service accounts) may only read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserAccess {
+    Admin,
+    Owner(UserId),
+    Client,
+}
+
+impl UserAccess {
+    pub fn can_read(&self, user_id: &UserId) -> bool {
+        match self {
+            UserAccess::Admin | UserAccess::Client => true,
+            UserAccess::Owner(owner_id) => owner_id == user_id,
+        }
+    }
+
+    pub fn can_write(&self, user_id: &UserId) -> bool {
+        match self {
+            UserAccess::Admin => true,
+            UserAccess::Owner(owner_id) => owner_id == user_id,
+            UserAccess::Client => false,
+        }
+    }
+}
+
+/// The caller's role is read from the repository rather than the token, so role changes
+/// apply to tokens that are already issued.
+pub async fn resolve_user_access(
+    find_user_by_id_repository: &(dyn FindUserByIdRepository + Send + Sync),
+    principal: &Principal,
+) -> Result<UserAccess, AppError> {
+    let Some(user_id) = &principal.user_id else {
+        return Ok(UserAccess::Client);
+    };
+
+    let user = find_user_by_id_repository
+        .execute(user_id.clone())
+        .await
+        .map_err(AppError::server_error)?
+        .ok_or_else(|| AppError::unauthorized("User not found"))?;
+
+    if user.role == UserRole::Admin {
+        return Ok(UserAccess::Admin);
+    }
+
+    Ok(UserAccess::Owner(user.id))
+}
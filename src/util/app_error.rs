@@ -1,13 +1,20 @@
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
 use serde::Serialize;
 use tracing::error;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub enum ErrorType {
     ClientError,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    PreconditionFailed,
+    PreconditionRequired,
     ServerError,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct AppError {
     pub error_type: ErrorType,
     pub detail: String,
@@ -18,18 +25,75 @@ impl AppError {
 
         Self {
             error_type: ErrorType::ClientError,
-            detail: detail,
+            detail,
+        }
+    }
+
+    pub fn unauthorized(detail: impl Into<String>) -> Self {
+        Self {
+            error_type: ErrorType::Unauthorized,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn forbidden(detail: impl Into<String>) -> Self {
+        Self {
+            error_type: ErrorType::Forbidden,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn not_found(detail: impl Into<String>) -> Self {
+        Self {
+            error_type: ErrorType::NotFound,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn precondition_failed(detail: impl Into<String>) -> Self {
+        Self {
+            error_type: ErrorType::PreconditionFailed,
+            detail: detail.into(),
+        }
+    }
+
+    pub fn precondition_required(detail: impl Into<String>) -> Self {
+        Self {
+            error_type: ErrorType::PreconditionRequired,
+            detail: detail.into(),
         }
     }
 
     pub fn server_error(details: impl Into<String>) -> Self {
         let details = details.into();
 
-        error!("Internal error: {}", details);
+        error!(detail = %details, "Internal error");
 
         Self {
             error_type: ErrorType::ServerError,
             detail: "Internal error".to_string(),
         }
     }
-}
\ No newline at end of file
+
+    pub fn status(&self) -> HttpStatus {
+        match self.error_type {
+            ErrorType::ClientError => HttpStatus::BadRequest,
+            ErrorType::Unauthorized => HttpStatus::Unauthorized,
+            ErrorType::Forbidden => HttpStatus::Forbidden,
+            ErrorType::NotFound => HttpStatus::NotFound,
+            ErrorType::PreconditionFailed => HttpStatus::PreconditionFailed,
+            ErrorType::PreconditionRequired => HttpStatus::PreconditionRequired,
+            ErrorType::ServerError => HttpStatus::InternalServerError,
+        }
+    }
+}
+
+impl From<AppError> for HttpResponse<AppError> {
+    fn from(err: AppError) -> Self {
+        HttpResponse {
+            status: err.status(),
+            detail: err.detail.clone(),
+            content: err,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::model::oauth_model::ClientCredentials;
+use crate::util::oauth_error::{OAuthError, OAuthErrorCode};
+use crate::util::secret::Secret;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use percent_encoding::percent_decode_str;
+
+/// Reads client credentials from HTTP Basic (`client_secret_basic`) or from the form body
+/// (`client_secret_post`). RFC 6749, section 2.3.1 forbids using both at once.
+pub fn resolve_client_credentials(
+    authorization: Option<&str>,
+    client_id: Option<String>,
+    client_secret: Option<Secret<String>>,
+) -> Result<ClientCredentials, OAuthError> {
+    let basic = authorization.and_then(|value| value.strip_prefix("Basic "));
+
+    match (basic, client_id, client_secret) {
+        (Some(_), Some(_), _) | (Some(_), _, Some(_)) => Err(OAuthError::new(
+            OAuthErrorCode::InvalidRequest,
+            "Use only one client authentication method",
+        )),
+        (Some(encoded), None, None) => parse_basic_credentials(encoded),
+        (None, Some(client_id), Some(client_secret)) => Ok(ClientCredentials {
+            client_id,
+            client_secret,
+        }),
+        _ => Err(OAuthError::new(
+            OAuthErrorCode::InvalidClient,
+            "Client authentication is required",
+        )),
+    }
+}
+
+fn parse_basic_credentials(encoded: &str) -> Result<ClientCredentials, OAuthError> {
+    let invalid = || OAuthError::new(OAuthErrorCode::InvalidClient, "Malformed Basic credentials");
+
+    let decoded = STANDARD.decode(encoded.trim()).map_err(|_| invalid())?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+    let (client_id, client_secret) = decoded.split_once(':').ok_or_else(invalid)?;
+
+    Ok(ClientCredentials {
+        client_id: form_decode(client_id).ok_or_else(invalid)?,
+        client_secret: form_decode(client_secret).map(Secret::new).ok_or_else(invalid)?,
+    })
+}
+
+fn form_decode(value: &str) -> Option<String> {
+    percent_decode_str(&value.replace('+', " "))
+        .decode_utf8()
+        .ok()
+        .map(|decoded| decoded.into_owned())
+}
@@ -0,0 +1,110 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// A deprecated API version or route, configured as
+/// `<route prefix>;deprecated=<date>[;sunset=<date>][;link=<url>]` with dates as `YYYY-MM-DD`
+/// or RFC 3339. The prefix is matched against route templates, so `/v1` covers the whole
+/// version and `/v1/users/{id}/suspend` a single route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deprecation {
+    pub route_prefix: String,
+    pub deprecated_at: DateTime<Utc>,
+    /// When the route is expected to stop answering.
+    pub sunset_at: Option<DateTime<Utc>>,
+    /// Migration notes for clients.
+    pub link: Option<String>,
+}
+
+impl Deprecation {
+    pub fn parse(entry: &str) -> Result<Self, String> {
+        let mut parts = entry.split(';').map(str::trim);
+
+        let route_prefix = parts.next().unwrap_or_default().trim_end_matches('/').to_string();
+        if !route_prefix.starts_with('/') {
+            return Err(format!("deprecation `{}` must start with a route such as /v1", entry));
+        }
+
+        let mut deprecated_at = None;
+        let mut sunset_at = None;
+        let mut link = None;
+
+        for part in parts {
+            match part.split_once('=') {
+                Some(("deprecated", date)) => deprecated_at = Some(parse_date(date)?),
+                Some(("sunset", date)) => sunset_at = Some(parse_date(date)?),
+                Some(("link", url)) => link = Some(url.to_string()),
+                _ => {
+                    return Err(format!(
+                        "unknown parameter `{}` in deprecation of {}, expected deprecated, sunset or link",
+                        part, route_prefix
+                    ));
+                }
+            }
+        }
+
+        let deprecated_at = deprecated_at
+            .ok_or_else(|| format!("deprecation of {} needs a deprecated=<date>", route_prefix))?;
+
+        if sunset_at.is_some_and(|sunset| sunset <= deprecated_at) {
+            return Err(format!("sunset of {} must be after its deprecation date", route_prefix));
+        }
+
+        Ok(Self {
+            route_prefix,
+            deprecated_at,
+            sunset_at,
+            link,
+        })
+    }
+
+    pub fn applies_to(&self, route: &str) -> bool {
+        route
+            .strip_prefix(&self.route_prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// RFC 9745: the deprecation date as a structured-field date.
+    pub fn deprecation_header(&self) -> String {
+        format!("@{}", self.deprecated_at.timestamp())
+    }
+
+    /// RFC 8594: an HTTP-date.
+    pub fn sunset_header(&self) -> Option<String> {
+        self.sunset_at
+            .map(|sunset| sunset.format("%a, %d %b %Y %H:%M:%S GMT").to_string())
+    }
+
+    pub fn link_header(&self) -> Option<String> {
+        self.link
+            .as_ref()
+            .map(|link| format!("<{}>; rel=\"deprecation\"; type=\"text/html\"", link))
+    }
+}
+
+/// Comma-separated list of `Deprecation` entries; empty means nothing is deprecated.
+pub fn parse_deprecations(value: &str) -> Result<Vec<Deprecation>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(Deprecation::parse)
+        .collect()
+}
+
+/// The most specific deprecation covering `route`, so a route can carry an earlier sunset
+/// than the rest of its version.
+pub fn find_deprecation<'a>(deprecations: &'a [Deprecation], route: &str) -> Option<&'a Deprecation> {
+    deprecations
+        .iter()
+        .filter(|deprecation| deprecation.applies_to(route))
+        .max_by_key(|deprecation| deprecation.route_prefix.len())
+}
+
+fn parse_date(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
+    }
+
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.with_timezone(&Utc))
+        .map_err(|_| format!("invalid date `{}`, expected YYYY-MM-DD or RFC 3339", value))
+}
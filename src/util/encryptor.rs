@@ -1,17 +1,26 @@
+use crate::util::metrics::password_hash_timer;
+
 pub trait Encryptor {
     fn encrypt(&self, data: &str) -> Result<String, String>;
     fn verify(&self, data: &str, hash: &str) -> Result<bool, String>;
 }
-pub struct BcryptEncryptor;
+#[derive(Clone, Copy)]
+pub struct BcryptEncryptor {
+    pub cost: u32,
+}
 
 impl Encryptor for BcryptEncryptor {
     fn encrypt(&self, data: &str) -> Result<String, String> {
-        bcrypt::hash(data, bcrypt::DEFAULT_COST)
+        let _timer = password_hash_timer("hash");
+
+        bcrypt::hash(data, self.cost)
             .map_err(|e| format!("Erro ao criptografar senha: {}", e))
     }
 
     fn verify(&self, data: &str, hash: &str) -> Result<bool, String> {
+        let _timer = password_hash_timer("verify");
+
         bcrypt::verify(data, hash)
             .map_err(|e| format!("Erro ao validar senha: {}", e))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,31 @@
+use crate::util::app_error::AppError;
+
+/// Strong entity tag for a versioned resource, e.g. `"3"`.
+pub fn format_etag(version: u64) -> String {
+    format!("\"{}\"", version)
+}
+
+/// RFC 9110, section 13.1.1: `*` matches any current representation and weak tags never
+/// match, since If-Match uses the strong comparison.
+pub fn if_match_satisfied(if_match: &str, version: u64) -> bool {
+    let current = format_etag(version);
+
+    if_match
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag == current)
+}
+
+/// Writes to versioned resources must carry `If-Match`, so a missing header is a 428 and
+/// a stale one a 412.
+pub fn ensure_if_match(if_match: Option<&str>, version: u64) -> Result<(), AppError> {
+    let if_match = if_match.ok_or_else(|| AppError::precondition_required("If-Match header is required"))?;
+
+    if !if_match_satisfied(if_match, version) {
+        return Err(AppError::precondition_failed(
+            "The resource was modified since it was fetched",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::util::app_error::AppError;
+use crate::util::http_status_code::{HttpResponse, HttpStatus};
+use crate::util::oauth_error::{OAuthError, OAuthErrorCode};
+use axum::extract::{FromRequest, FromRequestParts, Query, Request};
+use axum::http::StatusCode;
+use axum::{Form, Json};
+use axum::http::request::Parts;
+use serde::de::DeserializeOwned;
+
+/// `Json<T>` whose rejections use the `HttpResponse` envelope instead of axum's plain text.
+pub struct ApiJson<T>(pub T);
+
+/// `Query<T>` whose rejections use the `HttpResponse` envelope instead of axum's plain text.
+pub struct ApiQuery<T>(pub T);
+
+/// `Form<T>` for the OAuth endpoints: any rejection is the RFC 6749 `invalid_request` error
+/// with status 400, since OAuth clients expect that body rather than the envelope.
+pub struct OAuthForm<T>(pub T);
+
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = HttpResponse<AppError>;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(request, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(rejected(rejection.status(), rejection.body_text())),
+        }
+    }
+}
+
+impl<T, S> FromRequestParts<S> for ApiQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = HttpResponse<AppError>;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Query::<T>::from_request_parts(parts, state).await {
+            Ok(Query(value)) => Ok(ApiQuery(value)),
+            Err(rejection) => Err(rejected(rejection.status(), rejection.body_text())),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for OAuthForm<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = OAuthError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Form::<T>::from_request(request, state).await {
+            Ok(Form(value)) => Ok(OAuthForm(value)),
+            Err(rejection) => Err(OAuthError::new(OAuthErrorCode::InvalidRequest, rejection.body_text())),
+        }
+    }
+}
+
+// Keeps axum's status (400 unparseable, 413 too large, 415 not JSON, 422 wrong shape)
+// so clients can still tell a syntax error from a field that failed validation.
+fn rejected(status: StatusCode, detail: String) -> HttpResponse<AppError> {
+    let status = match status {
+        StatusCode::PAYLOAD_TOO_LARGE => HttpStatus::PayloadTooLarge,
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => HttpStatus::UnsupportedMediaType,
+        StatusCode::UNPROCESSABLE_ENTITY => HttpStatus::UnprocessableEntity,
+        _ => HttpStatus::BadRequest,
+    };
+
+    HttpResponse {
+        status,
+        detail: detail.clone(),
+        content: AppError::client_error(detail),
+    }
+}
@@ -0,0 +1,84 @@
+use crate::model::user_model::UserId;
+use crate::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use crate::util::key_ring::KeyRing;
+use crate::util::shutdown::ShutdownSignal;
+use async_trait::async_trait;
+
+/// A dependency the instance needs in order to serve traffic, probed by `/health/ready`.
+#[async_trait]
+pub trait HealthCheck {
+    fn name(&self) -> &'static str;
+    /// Whether a failure takes the instance out of rotation.
+    fn critical(&self) -> bool;
+    async fn check(&self) -> Result<(), String>;
+}
+
+/// Tokens cannot be issued without a key whose signing window contains the current time, as
+/// read from the key ring's clock.
+pub struct SigningKeyHealthCheck {
+    pub key_ring: KeyRing,
+}
+
+#[async_trait]
+impl HealthCheck for SigningKeyHealthCheck {
+    fn name(&self) -> &'static str {
+        "signing_keys"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.key_ring.check_active_key()
+    }
+}
+
+/// Runs a cheap lookup through the user repository, so a store that errors or hangs (held
+/// lock, exhausted pool) is caught by the check or its timeout.
+pub struct UserStoreHealthCheck {
+    pub find_user_by_id_repository: Box<dyn FindUserByIdRepository + Send + Sync + 'static>,
+}
+
+#[async_trait]
+impl HealthCheck for UserStoreHealthCheck {
+    fn name(&self) -> &'static str {
+        "user_store"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        self.find_user_by_id_repository
+            .execute(UserId::from("health-check"))
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Fails as soon as shutdown begins, so load balancers stop sending new requests while the
+/// ones in flight drain.
+pub struct ShutdownHealthCheck {
+    pub shutdown: ShutdownSignal,
+}
+
+#[async_trait]
+impl HealthCheck for ShutdownHealthCheck {
+    fn name(&self) -> &'static str {
+        "shutdown"
+    }
+
+    fn critical(&self) -> bool {
+        true
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        if self.shutdown.is_draining() {
+            Err("Shutting down".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -1,6 +1,11 @@
+use crate::util::request_id::current_request_id;
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub enum HttpStatus {
     // 2xx Success
     Ok,
@@ -12,10 +17,16 @@ pub enum HttpStatus {
     Unauthorized,
     Forbidden,
     NotFound,
+    PreconditionFailed,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    UnprocessableEntity,
+    PreconditionRequired,
     TooManyRequests,
 
     // 5xx Server Errors
     InternalServerError,
+    ServiceUnavailable,
 }
 
 impl HttpStatus {
@@ -29,9 +40,15 @@ impl HttpStatus {
             HttpStatus::Unauthorized => 401,
             HttpStatus::Forbidden => 403,
             HttpStatus::NotFound => 404,
+            HttpStatus::PreconditionFailed => 412,
+            HttpStatus::PayloadTooLarge => 413,
+            HttpStatus::UnsupportedMediaType => 415,
+            HttpStatus::UnprocessableEntity => 422,
+            HttpStatus::PreconditionRequired => 428,
             HttpStatus::TooManyRequests => 429,
 
             HttpStatus::InternalServerError => 500,
+            HttpStatus::ServiceUnavailable => 503,
         }
     }
 
@@ -45,17 +62,52 @@ impl HttpStatus {
             HttpStatus::Unauthorized => "Unauthorized",
             HttpStatus::Forbidden => "Forbidden",
             HttpStatus::NotFound => "NotFound",
+            HttpStatus::PreconditionFailed => "PreconditionFailed",
+            HttpStatus::PayloadTooLarge => "PayloadTooLarge",
+            HttpStatus::UnsupportedMediaType => "UnsupportedMediaType",
+            HttpStatus::UnprocessableEntity => "UnprocessableEntity",
+            HttpStatus::PreconditionRequired => "PreconditionRequired",
             HttpStatus::TooManyRequests => "TooManyRequests",
 
             HttpStatus::InternalServerError => "InternalServerError",
+            HttpStatus::ServiceUnavailable => "ServiceUnavailable",
         }
     }
 }
 
-#[derive(Debug, Serialize)]
+/// Envelope of every JSON response outside the OAuth endpoints.
+// `request_id` only exists on the wire; `openapi::Envelopes` adds it to the schema.
+#[derive(Debug, Serialize, ToSchema)]
 pub struct HttpResponse<T> {
     pub status: HttpStatus,
     pub detail: String,
     pub content: T,
 }
 
+/// What is written on the wire: the response plus the id of the request it answers, so a
+/// client reporting an error can quote it.
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    status: &'a HttpStatus,
+    detail: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+    content: &'a T,
+}
+
+impl<T: Serialize> IntoResponse for HttpResponse<T> {
+    fn into_response(self) -> Response {
+        let status_code =
+            StatusCode::from_u16(self.status.code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        let envelope = Envelope {
+            status: &self.status,
+            detail: &self.detail,
+            request_id: current_request_id(),
+            content: &self.content,
+        };
+
+        (status_code, Json(envelope)).into_response()
+    }
+}
+
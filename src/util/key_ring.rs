@@ -0,0 +1,243 @@
+use crate::model::token_model::{AccessTokenClaims, Jwk, Jwks};
+use crate::util::clock::Clock;
+use crate::util::token_issuer::{TokenConfig, TokenIssuer};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode};
+use ring::digest::{SHA256, digest};
+use ring::hmac;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use std::sync::{Arc, RwLock};
+use tokio::task::JoinHandle;
+
+const ROTATION_CHECK_SECONDS: u64 = 60;
+
+/// PKCS#8 v1 header of an Ed25519 private key; the 32-byte seed follows it.
+const ED25519_PKCS8_PREFIX: [u8; 16] = [
+    0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20,
+];
+
+struct SigningKey {
+    kid: String,
+    pkcs8: Vec<u8>,
+    public_key: Vec<u8>,
+    /// Start of the window in which the key signs new tokens.
+    not_before: DateTime<Utc>,
+    /// End of the signing window; the key keeps verifying until `expires_at`.
+    retire_at: DateTime<Utc>,
+    /// After this instant no token signed by the key can still be valid, so it is dropped.
+    expires_at: DateTime<Utc>,
+}
+
+/// Ed25519 signing keys with overlapping validity windows. The next key is published in the
+/// JWKS one rotation period before it starts signing, so verifiers can cache the set.
+///
+/// Windows start at multiples of the rotation period since the epoch. With
+/// `TokenConfig::signing_secret` set, each window's key is derived from the secret, so a
+/// restarted process or another replica signs and verifies with the same keys. Without it
+/// keys are random per process: tokens do not survive a restart and replicas reject each
+/// other's tokens.
+#[derive(Clone)]
+pub struct KeyRing {
+    keys: Arc<RwLock<Vec<SigningKey>>>,
+    token_config: TokenConfig,
+    clock: Arc<dyn Clock + Send + Sync + 'static>,
+}
+
+impl KeyRing {
+    pub fn new(
+        token_config: TokenConfig,
+        clock: impl Clock + Send + Sync + 'static,
+    ) -> Result<Self, String> {
+        let key_ring = Self {
+            keys: Arc::new(RwLock::new(Vec::new())),
+            token_config,
+            clock: Arc::new(clock),
+        };
+
+        key_ring.rotate()?;
+
+        Ok(key_ring)
+    }
+
+    /// Drops expired keys and makes sure there is a current and a next key for the clock's
+    /// current time.
+    pub fn rotate(&self) -> Result<(), String> {
+        let now = self.clock.now();
+        let mut keys = self.keys.write().map_err(|_| "Key ring lock poisoned".to_string())?;
+
+        keys.retain(|key| key.expires_at > now);
+
+        let current_retire_at = match keys
+            .iter()
+            .filter(|key| key.not_before <= now && now < key.retire_at)
+            .map(|key| key.retire_at)
+            .max()
+        {
+            Some(retire_at) => retire_at,
+            None => {
+                let key = self.generate_key(self.window_start(now)?)?;
+                let retire_at = key.retire_at;
+                tracing::info!("Generated signing key {}", key.kid);
+                keys.push(key);
+                retire_at
+            }
+        };
+
+        if !keys.iter().any(|key| key.not_before >= current_retire_at) {
+            let key = self.generate_key(current_retire_at)?;
+            tracing::info!("Published next signing key {}", key.kid);
+            keys.push(key);
+        }
+
+        Ok(())
+    }
+
+    /// Fails when no key can sign a token issued now, e.g. after rotation kept failing.
+    pub fn check_active_key(&self) -> Result<(), String> {
+        let now = self.clock.now();
+        let keys = self.keys.read().map_err(|_| "Key ring lock poisoned".to_string())?;
+
+        if keys.iter().any(|key| key.not_before <= now && now < key.retire_at) {
+            Ok(())
+        } else {
+            Err("No active signing key".to_string())
+        }
+    }
+
+    pub fn spawn_rotation(&self) -> JoinHandle<()> {
+        let key_ring = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(ROTATION_CHECK_SECONDS));
+
+            loop {
+                interval.tick().await;
+
+                if let Err(err) = key_ring.rotate() {
+                    tracing::error!("Signing key rotation failed: {}", err);
+                }
+            }
+        })
+    }
+
+    fn window_start(&self, instant: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        let timestamp = instant.timestamp();
+        let offset = timestamp.rem_euclid(self.token_config.signing_key_rotation_seconds);
+
+        Utc.timestamp_opt(timestamp - offset, 0)
+            .single()
+            .ok_or_else(|| "Invalid signing window".to_string())
+    }
+
+    fn generate_key(&self, not_before: DateTime<Utc>) -> Result<SigningKey, String> {
+        let pkcs8 = match &self.token_config.signing_secret {
+            Some(secret) => {
+                let key = hmac::Key::new(hmac::HMAC_SHA256, secret.expose().as_bytes());
+                let seed = hmac::sign(&key, format!("signing-key:{}", not_before.timestamp()).as_bytes());
+                [ED25519_PKCS8_PREFIX.as_slice(), seed.as_ref()].concat()
+            }
+            None => Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+                .map_err(|_| "Failed to generate signing key".to_string())?
+                .as_ref()
+                .to_vec(),
+        };
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&pkcs8)
+            .map_err(|_| "Failed to load signing key".to_string())?;
+        let public_key = key_pair.public_key().as_ref().to_vec();
+
+        let retire_at = not_before + Duration::seconds(self.token_config.signing_key_rotation_seconds);
+
+        Ok(SigningKey {
+            // A thumbprint of the public key, so instances deriving the same key agree on it.
+            kid: URL_SAFE_NO_PAD.encode(&digest(&SHA256, &public_key).as_ref()[..12]),
+            pkcs8,
+            public_key,
+            not_before,
+            retire_at,
+            expires_at: retire_at + Duration::seconds(self.token_config.access_token_ttl_seconds),
+        })
+    }
+}
+
+impl TokenIssuer for KeyRing {
+    /// Signs with the key whose signing window contains the token's `iat`.
+    fn issue(&self, claims: &AccessTokenClaims) -> Result<String, String> {
+        let issued_at = Utc
+            .timestamp_opt(claims.iat, 0)
+            .single()
+            .ok_or_else(|| "Invalid iat claim".to_string())?;
+
+        let keys = self.keys.read().map_err(|_| "Key ring lock poisoned".to_string())?;
+
+        let key = keys
+            .iter()
+            .filter(|key| key.not_before <= issued_at && issued_at < key.retire_at)
+            .max_by_key(|key| key.not_before)
+            .ok_or_else(|| "No active signing key".to_string())?;
+
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(key.kid.clone());
+
+        encode(&header, claims, &EncodingKey::from_ed_der(&key.pkcs8))
+            .map_err(|e| format!("Failed to sign access token: {}", e))
+    }
+
+    fn verify(&self, token: &str) -> Result<AccessTokenClaims, String> {
+        let header = decode_header(token).map_err(|e| format!("Invalid access token: {}", e))?;
+
+        if header.alg != Algorithm::EdDSA {
+            return Err(format!("Unexpected token algorithm {:?}", header.alg));
+        }
+
+        let kid = header.kid.ok_or_else(|| "Access token has no kid".to_string())?;
+
+        let public_key = {
+            let keys = self.keys.read().map_err(|_| "Key ring lock poisoned".to_string())?;
+
+            keys.iter()
+                .find(|key| key.kid == kid)
+                .map(|key| key.public_key.clone())
+                .ok_or_else(|| format!("Unknown signing key {}", kid))?
+        };
+
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_issuer(&[&self.token_config.issuer]);
+        validation.set_required_spec_claims(&["exp", "iss", "sub"]);
+        validation.validate_aud = false;
+        // Expiry is checked against the injected clock below, not the system time.
+        validation.validate_exp = false;
+
+        let claims =
+            decode::<AccessTokenClaims>(token, &DecodingKey::from_ed_der(&public_key), &validation)
+                .map(|data| data.claims)
+                .map_err(|e| format!("Invalid access token: {}", e))?;
+
+        if claims.exp <= self.clock.now().timestamp() {
+            return Err("Invalid access token: ExpiredSignature".to_string());
+        }
+
+        Ok(claims)
+    }
+
+    fn public_jwks(&self) -> Result<Jwks, String> {
+        let keys = self.keys.read().map_err(|_| "Key ring lock poisoned".to_string())?;
+
+        Ok(Jwks {
+            keys: keys
+                .iter()
+                .map(|key| Jwk {
+                    kty: "OKP".to_string(),
+                    crv: "Ed25519".to_string(),
+                    alg: "EdDSA".to_string(),
+                    key_use: "sig".to_string(),
+                    kid: key.kid.clone(),
+                    x: URL_SAFE_NO_PAD.encode(&key.public_key),
+                })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::config::app_config::{LogConfig, LogFormat};
+use opentelemetry_sdk::trace::SdkTracer;
+use tracing::Subscriber;
+use tracing_subscriber::Layer;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
+
+/// Builds the subscriber described by `config`, writing to `writer`. JSON lines carry the
+/// current span and its parents, so every event can be joined back to the request it came
+/// from. With a `tracer`, the same spans are also exported over OpenTelemetry.
+pub fn subscriber<W>(
+    config: &LogConfig,
+    writer: W,
+    tracer: Option<SdkTracer>,
+) -> Box<dyn Subscriber + Send + Sync + 'static>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let fmt_layer = match config.format {
+        LogFormat::Text => tracing_subscriber::fmt::layer().with_writer(writer).boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed(),
+    };
+
+    let otel_layer = tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer));
+
+    Box::new(
+        tracing_subscriber::registry()
+            .with(fmt_layer)
+            .with(otel_layer)
+            .with(LevelFilter::from_level(config.level)),
+    )
+}
+
+pub fn init(config: &LogConfig, tracer: Option<SdkTracer>) {
+    tracing::subscriber::set_global_default(subscriber(config, std::io::stdout, tracer))
+        .expect("failed to set subscriber");
+}
@@ -0,0 +1,131 @@
+use metrics::Histogram;
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+pub const HTTP_REQUESTS_IN_FLIGHT: &str = "http_requests_in_flight";
+pub const USER_SIGNUPS_TOTAL: &str = "user_signups_total";
+pub const PASSWORD_HASH_DURATION_SECONDS: &str = "password_hash_duration_seconds";
+pub const REPOSITORY_CALL_DURATION_SECONDS: &str = "repository_call_duration_seconds";
+pub const API_DEPRECATED_REQUESTS_TOTAL: &str = "api_deprecated_requests_total";
+
+/// Histogram buckets in seconds, wide enough for an in-memory lookup and a slow bcrypt hash.
+const DURATION_BUCKETS: &[f64] = &[
+    0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Installs the Prometheus recorder on first use and returns the handle that renders it.
+/// The recorder is process-wide, so every router built in the process reports into it.
+pub fn prometheus_handle() -> PrometheusHandle {
+    PROMETHEUS_HANDLE
+        .get_or_init(|| {
+            let handle = PrometheusBuilder::new()
+                .set_buckets(DURATION_BUCKETS)
+                .expect("duration buckets must not be empty")
+                .install_recorder()
+                .expect("failed to install the metrics recorder");
+            describe();
+            handle
+        })
+        .clone()
+}
+
+fn describe() {
+    metrics::describe_counter!(HTTP_REQUESTS_TOTAL, "HTTP requests by route and status");
+    metrics::describe_histogram!(
+        HTTP_REQUEST_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "HTTP request latency by route and status"
+    );
+    metrics::describe_gauge!(HTTP_REQUESTS_IN_FLIGHT, "HTTP requests being handled");
+    metrics::describe_counter!(USER_SIGNUPS_TOTAL, "Signups by outcome");
+    metrics::describe_histogram!(
+        PASSWORD_HASH_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "Time spent hashing or verifying passwords"
+    );
+    metrics::describe_histogram!(
+        REPOSITORY_CALL_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "Repository call latency by operation and backend"
+    );
+    metrics::describe_counter!(
+        API_DEPRECATED_REQUESTS_TOTAL,
+        "Requests to deprecated API versions or routes"
+    );
+}
+
+/// How a signup ended, as reported in `user_signups_total`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SignupOutcome {
+    Created,
+    Duplicate,
+    /// Refused for any reason other than the email being taken.
+    Rejected,
+    Error,
+}
+
+impl SignupOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignupOutcome::Created => "created",
+            SignupOutcome::Duplicate => "duplicate",
+            SignupOutcome::Rejected => "rejected",
+            SignupOutcome::Error => "error",
+        }
+    }
+}
+
+pub fn record_signup(outcome: SignupOutcome) {
+    metrics::counter!(USER_SIGNUPS_TOTAL, "outcome" => outcome.as_str()).increment(1);
+}
+
+pub fn record_deprecated_request(method: &str, route: &str) {
+    metrics::counter!(
+        API_DEPRECATED_REQUESTS_TOTAL,
+        "method" => method.to_string(),
+        "route" => route.to_string()
+    )
+    .increment(1);
+}
+
+/// Records the time from `start` until it is dropped, so early returns are measured too.
+pub struct HistogramTimer {
+    histogram: Histogram,
+    started_at: Instant,
+}
+
+impl HistogramTimer {
+    pub fn start(histogram: Histogram) -> Self {
+        Self {
+            histogram,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+impl Drop for HistogramTimer {
+    fn drop(&mut self) {
+        self.histogram.record(self.started_at.elapsed());
+    }
+}
+
+/// `operation` is `hash` or `verify`.
+pub fn password_hash_timer(operation: &'static str) -> HistogramTimer {
+    HistogramTimer::start(metrics::histogram!(
+        PASSWORD_HASH_DURATION_SECONDS,
+        "operation" => operation
+    ))
+}
+
+pub fn repository_timer(operation: &'static str, backend: &'static str) -> HistogramTimer {
+    HistogramTimer::start(metrics::histogram!(
+        REPOSITORY_CALL_DURATION_SECONDS,
+        "operation" => operation,
+        "backend" => backend
+    ))
+}
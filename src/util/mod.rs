@@ -1,4 +1,24 @@
 pub mod encryptor;
 pub mod uuid_generator;
 pub mod http_status_code;
-pub mod app_error;
\ No newline at end of file
+pub mod app_error;
+pub mod extract;
+pub mod token_generator;
+pub mod webauthn;
+pub mod oidc;
+pub mod token_issuer;
+pub mod oauth_error;
+pub mod client_credentials;
+pub mod key_ring;
+pub mod scope;
+pub mod retention;
+pub mod etag;
+pub mod clock;
+pub mod secret;
+pub mod logging;
+pub mod request_id;
+pub mod metrics;
+pub mod telemetry;
+pub mod health_check;
+pub mod shutdown;
+pub mod deprecation;
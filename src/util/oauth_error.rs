@@ -0,0 +1,71 @@
+use crate::util::app_error::{AppError, ErrorType};
+use crate::util::request_id::current_request_id;
+use axum::Json;
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Error codes from RFC 6749, section 5.2.
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthErrorCode {
+    InvalidRequest,
+    InvalidClient,
+    InvalidScope,
+    UnsupportedGrantType,
+    ServerError,
+}
+
+/// OAuth endpoints answer with the RFC error body instead of the `HttpResponse` envelope,
+/// since standard OAuth clients parse it. The RFC lets clients ignore extra members, so the
+/// request id is carried alongside.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OAuthError {
+    pub error: OAuthErrorCode,
+    pub error_description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl OAuthError {
+    pub fn new(error: OAuthErrorCode, error_description: impl Into<String>) -> Self {
+        Self {
+            error,
+            error_description: error_description.into(),
+            request_id: current_request_id(),
+        }
+    }
+}
+
+impl From<AppError> for OAuthError {
+    fn from(err: AppError) -> Self {
+        let error = match err.error_type {
+            ErrorType::ClientError
+            | ErrorType::NotFound
+            | ErrorType::PreconditionFailed
+            | ErrorType::PreconditionRequired => OAuthErrorCode::InvalidRequest,
+            ErrorType::Unauthorized | ErrorType::Forbidden => OAuthErrorCode::InvalidClient,
+            ErrorType::ServerError => OAuthErrorCode::ServerError,
+        };
+
+        Self::new(error, err.detail)
+    }
+}
+
+impl IntoResponse for OAuthError {
+    fn into_response(self) -> Response {
+        match self.error {
+            OAuthErrorCode::InvalidClient => (
+                StatusCode::UNAUTHORIZED,
+                [(header::WWW_AUTHENTICATE, "Basic realm=\"oauth\"")],
+                Json(self),
+            )
+                .into_response(),
+            OAuthErrorCode::ServerError => {
+                (StatusCode::INTERNAL_SERVER_ERROR, Json(self)).into_response()
+            }
+            _ => (StatusCode::BAD_REQUEST, Json(self)).into_response(),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::util::clock::Clock;
+use crate::util::secret::Secret;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use ring::digest::{SHA256, digest};
+use serde::{Deserialize, Serialize};
+
+/// Cookie holding the `state` of the login the browser started. The callback must present
+/// it, so a callback link carrying someone else's `state` cannot sign the browser into the
+/// attacker's account (login CSRF).
+pub const STATE_COOKIE: &str = "oidc_state";
+
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<Secret<String>>,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub id_token: String,
+    pub access_token: Option<String>,
+    pub token_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub exp: i64,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+}
+
+#[async_trait]
+pub trait OidcClient {
+    async fn discover(&self, issuer_url: &str) -> Result<ProviderMetadata, String>;
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        code: &str,
+        code_verifier: &str,
+        config: &OidcConfig,
+    ) -> Result<TokenResponse, String>;
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, String>;
+}
+
+pub struct ReqwestOidcClient {
+    pub http_client: reqwest::Client,
+}
+
+#[async_trait]
+impl OidcClient for ReqwestOidcClient {
+    async fn discover(&self, issuer_url: &str) -> Result<ProviderMetadata, String> {
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer_url.trim_end_matches('/')
+        );
+
+        let metadata: ProviderMetadata = self
+            .http_client
+            .get(&discovery_url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("OIDC discovery failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Invalid OIDC discovery document: {}", e))?;
+
+        // OpenID Connect Discovery 1.0, section 4.3
+        if metadata.issuer.trim_end_matches('/') != issuer_url.trim_end_matches('/') {
+            return Err(format!("Discovery issuer mismatch: {}", metadata.issuer));
+        }
+
+        Ok(metadata)
+    }
+
+    async fn exchange_code(
+        &self,
+        token_endpoint: &str,
+        code: &str,
+        code_verifier: &str,
+        config: &OidcConfig,
+    ) -> Result<TokenResponse, String> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", config.redirect_uri.as_str()),
+            ("client_id", config.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+
+        let mut request = self.http_client.post(token_endpoint).form(&form);
+
+        if let Some(client_secret) = &config.client_secret {
+            request = request.basic_auth(&config.client_id, Some(client_secret.expose()));
+        }
+
+        request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("OIDC code exchange failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Invalid OIDC token response: {}", e))
+    }
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, String> {
+        self.http_client
+            .get(jwks_uri)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("JWKS request failed: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Invalid JWKS document: {}", e))
+    }
+}
+
+/// RFC 7636 `S256` code challenge for the given verifier.
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(digest(&SHA256, code_verifier.as_bytes()))
+}
+
+/// Validates signature, issuer, audience, expiry and nonce of an ID token. Expiry is checked
+/// against `clock` rather than the system time, like every other deadline in the app.
+pub fn verify_id_token(
+    id_token: &str,
+    jwks: &JwkSet,
+    issuer: &str,
+    client_id: &str,
+    expected_nonce: &str,
+    clock: &dyn Clock,
+) -> Result<IdTokenClaims, String> {
+    let header = decode_header(id_token).map_err(|e| format!("Invalid ID token header: {}", e))?;
+
+    if !matches!(
+        header.alg,
+        Algorithm::RS256 | Algorithm::PS256 | Algorithm::ES256 | Algorithm::EdDSA
+    ) {
+        return Err(format!("Unsupported ID token algorithm {:?}", header.alg));
+    }
+
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| "No matching key in issuer JWKS".to_string())?;
+
+    let decoding_key =
+        DecodingKey::from_jwk(jwk).map_err(|e| format!("Unusable issuer key: {}", e))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+    validation.validate_exp = false;
+
+    let claims = decode::<IdTokenClaims>(id_token, &decoding_key, &validation)
+        .map_err(|e| format!("Invalid ID token: {}", e))?
+        .claims;
+
+    // Same leeway jsonwebtoken would have applied, for clock skew with the issuer.
+    if claims.exp + (validation.leeway as i64) < clock.now().timestamp() {
+        return Err("Invalid ID token: ExpiredSignature".to_string());
+    }
+
+    if claims.nonce.as_deref() != Some(expected_nonce) {
+        return Err("ID token nonce mismatch".to_string());
+    }
+
+    Ok(claims)
+}
@@ -0,0 +1,32 @@
+use chrono::Duration;
+
+/// What happens to the email of a soft-deleted user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmailReusePolicy {
+    /// The email can be registered again as soon as the user is deleted.
+    Immediate,
+    /// The email stays reserved until the user is purged, so a restore can never conflict.
+    AfterPurge,
+}
+
+impl EmailReusePolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "immediate" => Some(EmailReusePolicy::Immediate),
+            "after_purge" => Some(EmailReusePolicy::AfterPurge),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetentionConfig {
+    pub restore_window_days: i64,
+    pub email_reuse: EmailReusePolicy,
+}
+
+impl RetentionConfig {
+    pub fn restore_window(&self) -> Duration {
+        Duration::days(self.restore_window_days)
+    }
+}
@@ -0,0 +1,7 @@
+pub const USERS_READ: &str = "users:read";
+pub const USERS_WRITE: &str = "users:write";
+pub const TOKENS_READ: &str = "tokens:read";
+pub const TOKENS_WRITE: &str = "tokens:write";
+
+/// Scopes carried by a token obtained through an interactive login.
+pub const USER_SESSION_SCOPES: [&str; 4] = [USERS_READ, USERS_WRITE, TOKENS_READ, TOKENS_WRITE];
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared flag flipped once when the server starts shutting down. Readiness reports down
+/// from then on; the server keeps accepting connections until its pre-drain delay ends.
+#[derive(Clone, Default)]
+pub struct ShutdownSignal {
+    draining: Arc<AtomicBool>,
+}
+
+impl ShutdownSignal {
+    pub fn begin(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+}
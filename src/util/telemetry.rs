@@ -0,0 +1,54 @@
+use crate::config::app_config::TelemetryConfig;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+
+/// Builds the provider that batches spans and sends them to the configured collector, or
+/// `None` when no endpoint is configured. The caller keeps it to flush on shutdown.
+pub fn tracer_provider(config: &TelemetryConfig) -> Result<Option<SdkTracerProvider>, String> {
+    let Some(endpoint) = &config.otlp_endpoint else {
+        return Ok(None);
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| format!("failed to build the OTLP exporter: {}", e))?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        // Follow the caller's decision when a trace is already in progress, so a trace is
+        // either complete across services or absent.
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+
+    Ok(Some(provider))
+}
+
+struct HeaderExtractor<'a>(&'a axum::http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+/// Reads the W3C `traceparent` and `tracestate` headers. Without them, or when they are
+/// malformed, the returned context is empty and a new trace is started.
+pub fn extract_trace_context(headers: &axum::http::HeaderMap) -> opentelemetry::Context {
+    TraceContextPropagator::new().extract(&HeaderExtractor(headers))
+}
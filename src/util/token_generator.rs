@@ -0,0 +1,31 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::rand::{SecureRandom, SystemRandom};
+
+pub trait TokenGenerator {
+    fn generate(&self, byte_length: usize) -> Result<String, String>;
+}
+
+pub struct SecureTokenGenerator;
+
+impl TokenGenerator for SecureTokenGenerator {
+    fn generate(&self, byte_length: usize) -> Result<String, String> {
+        let mut bytes = vec![0u8; byte_length];
+
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .map_err(|_| "Failed to generate random token".to_string())?;
+
+        Ok(URL_SAFE_NO_PAD.encode(bytes))
+    }
+}
+
+/// Lookup hash for high-entropy tokens. A fast digest is enough here because the token
+/// itself is random, unlike passwords which go through `Encryptor`.
+pub fn hash_token(token: &str) -> String {
+    ring::digest::digest(&ring::digest::SHA256, token.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
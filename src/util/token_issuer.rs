@@ -0,0 +1,17 @@
+use crate::model::token_model::{AccessTokenClaims, Jwks};
+use crate::util::secret::Secret;
+
+#[derive(Debug, Clone)]
+pub struct TokenConfig {
+    pub issuer: String,
+    pub access_token_ttl_seconds: i64,
+    pub signing_key_rotation_seconds: i64,
+    /// Seed the signing keys are derived from; see `KeyRing`.
+    pub signing_secret: Option<Secret<String>>,
+}
+
+pub trait TokenIssuer {
+    fn issue(&self, claims: &AccessTokenClaims) -> Result<String, String>;
+    fn verify(&self, token: &str) -> Result<AccessTokenClaims, String>;
+    fn public_jwks(&self) -> Result<Jwks, String>;
+}
@@ -1,3 +1,6 @@
+use ring::rand::{SecureRandom, SystemRandom};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 pub trait UuidGenerator {
     fn generate(&self) -> Result<String, String>;
 }
@@ -8,4 +11,70 @@ impl UuidGenerator for UuidV4Generator {
     fn generate(&self) -> Result<String, String> {
         Ok(uuid::Uuid::new_v4().to_string())
     }
-}
\ No newline at end of file
+}
+
+/// RFC 9562 UUIDv7: a millisecond timestamp prefix keeps new rows at the right edge of the
+/// primary key index instead of scattered across it.
+pub struct UuidV7Generator;
+
+impl UuidGenerator for UuidV7Generator {
+    fn generate(&self) -> Result<String, String> {
+        Ok(uuid::Uuid::now_v7().to_string())
+    }
+}
+
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// ULID (https://github.com/ulid/spec): 48-bit millisecond timestamp plus 80 random bits,
+/// written as 26 Crockford base32 characters that sort lexicographically by time.
+pub struct UlidGenerator;
+
+impl UuidGenerator for UlidGenerator {
+    fn generate(&self) -> Result<String, String> {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("System clock is before the Unix epoch: {}", e))?
+            .as_millis() as u64;
+
+        let mut bytes = [0u8; 16];
+        bytes[..6].copy_from_slice(&timestamp_ms.to_be_bytes()[2..]);
+        SystemRandom::new()
+            .fill(&mut bytes[6..])
+            .map_err(|_| "Failed to generate ULID randomness".to_string())?;
+
+        let value = u128::from_be_bytes(bytes);
+
+        Ok((0..26)
+            .rev()
+            .map(|index| CROCKFORD_BASE32[((value >> (5 * index)) & 0x1F) as usize] as char)
+            .collect())
+    }
+}
+
+/// Which generator new identifiers come from. Existing identifiers keep whatever format they
+/// were created with, so switching is safe at any time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdStrategy {
+    UuidV4,
+    UuidV7,
+    Ulid,
+}
+
+impl IdStrategy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "uuid_v4" => Some(IdStrategy::UuidV4),
+            "uuid_v7" => Some(IdStrategy::UuidV7),
+            "ulid" => Some(IdStrategy::Ulid),
+            _ => None,
+        }
+    }
+
+    pub fn generator(&self) -> Box<dyn UuidGenerator + Send + Sync + 'static> {
+        match self {
+            IdStrategy::UuidV4 => Box::new(UuidV4Generator),
+            IdStrategy::UuidV7 => Box::new(UuidV7Generator),
+            IdStrategy::Ulid => Box::new(UlidGenerator),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ciborium::Value;
+use ring::digest::{SHA256, digest};
+use ring::signature::{self, RsaPublicKeyComponents, UnparsedPublicKey};
+use serde::Deserialize;
+
+pub const ALG_ES256: i64 = -7;
+pub const ALG_EDDSA: i64 = -8;
+pub const ALG_RS256: i64 = -257;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+
+#[derive(Debug, Clone)]
+pub struct WebauthnConfig {
+    pub rp_id: String,
+    pub rp_name: String,
+    pub origin: String,
+}
+
+pub fn encode(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn decode(value: &str, field: &str) -> Result<Vec<u8>, String> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|e| format!("Invalid base64url in {}: {}", field, e))
+}
+
+#[derive(Debug, Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony_type: String,
+    challenge: String,
+    origin: String,
+}
+
+/// Checks the `clientDataJSON` produced by the browser against the ceremony we started.
+pub fn verify_client_data(
+    client_data_json: &[u8],
+    expected_type: &str,
+    expected_challenge: &str,
+    expected_origin: &str,
+) -> Result<(), String> {
+    let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+        .map_err(|e| format!("Invalid clientDataJSON: {}", e))?;
+
+    if client_data.ceremony_type != expected_type {
+        return Err(format!("Unexpected ceremony type {}", client_data.ceremony_type));
+    }
+
+    if client_data.challenge.trim_end_matches('=') != expected_challenge {
+        return Err("Challenge mismatch".to_string());
+    }
+
+    if client_data.origin != expected_origin {
+        return Err(format!("Unexpected origin {}", client_data.origin));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct AttestedCredential {
+    pub credential_id: Vec<u8>,
+    pub public_key: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: Vec<u8>,
+    pub flags: u8,
+    pub sign_count: u32,
+    pub attested_credential: Option<AttestedCredential>,
+}
+
+impl AuthenticatorData {
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < 37 {
+            return Err("Authenticator data is too short".to_string());
+        }
+
+        let flags = bytes[32];
+        let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+
+        let attested_credential = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+            // aaguid (16 bytes) + credential id length (2 bytes)
+            if bytes.len() < 55 {
+                return Err("Attested credential data is truncated".to_string());
+            }
+
+            let id_length = u16::from_be_bytes([bytes[53], bytes[54]]) as usize;
+            let id_end = 55 + id_length;
+
+            if bytes.len() < id_end {
+                return Err("Credential id is truncated".to_string());
+            }
+
+            let mut remaining = &bytes[id_end..];
+            let available = remaining.len();
+            let _: Value = ciborium::from_reader(&mut remaining)
+                .map_err(|e| format!("Invalid credential public key: {}", e))?;
+            let key_length = available - remaining.len();
+
+            Some(AttestedCredential {
+                credential_id: bytes[55..id_end].to_vec(),
+                public_key: bytes[id_end..id_end + key_length].to_vec(),
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            rp_id_hash: bytes[..32].to_vec(),
+            flags,
+            sign_count,
+            attested_credential,
+        })
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_USER_PRESENT != 0
+    }
+
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_USER_VERIFIED != 0
+    }
+
+    pub fn verify_rp_id(&self, rp_id: &str) -> Result<(), String> {
+        if self.rp_id_hash != sha256(rp_id.as_bytes()) {
+            return Err("Relying party id hash mismatch".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Extracts the authenticator data from an attestation object. The attestation statement
+/// itself is not verified since registration requests `attestation: "none"`.
+pub fn parse_attestation_object(bytes: &[u8]) -> Result<AuthenticatorData, String> {
+    let value: Value = ciborium::from_reader(bytes)
+        .map_err(|e| format!("Invalid attestation object: {}", e))?;
+
+    let entries = value
+        .as_map()
+        .ok_or_else(|| "Attestation object is not a map".to_string())?;
+
+    let auth_data = entries
+        .iter()
+        .find(|(key, _)| key.as_text() == Some("authData"))
+        .and_then(|(_, value)| value.as_bytes())
+        .ok_or_else(|| "Attestation object has no authData".to_string())?;
+
+    AuthenticatorData::parse(auth_data)
+}
+
+#[derive(Debug, Clone)]
+pub enum CosePublicKey {
+    Es256 { x: Vec<u8>, y: Vec<u8> },
+    EdDsa { x: Vec<u8> },
+    Rs256 { n: Vec<u8>, e: Vec<u8> },
+}
+
+impl CosePublicKey {
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let value: Value =
+            ciborium::from_reader(bytes).map_err(|e| format!("Invalid COSE key: {}", e))?;
+
+        let entries = value
+            .as_map()
+            .ok_or_else(|| "COSE key is not a map".to_string())?;
+
+        let alg = cose_integer(entries, 3)?;
+
+        match alg {
+            ALG_ES256 => Ok(CosePublicKey::Es256 {
+                x: cose_bytes(entries, -2)?,
+                y: cose_bytes(entries, -3)?,
+            }),
+            ALG_EDDSA => Ok(CosePublicKey::EdDsa {
+                x: cose_bytes(entries, -2)?,
+            }),
+            ALG_RS256 => Ok(CosePublicKey::Rs256 {
+                n: cose_bytes(entries, -1)?,
+                e: cose_bytes(entries, -2)?,
+            }),
+            other => Err(format!("Unsupported COSE algorithm {}", other)),
+        }
+    }
+
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), String> {
+        let result = match self {
+            CosePublicKey::Es256 { x, y } => {
+                let mut point = Vec::with_capacity(65);
+                point.push(0x04);
+                point.extend_from_slice(x);
+                point.extend_from_slice(y);
+
+                UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_ASN1, point)
+                    .verify(message, signature)
+            }
+            CosePublicKey::EdDsa { x } => {
+                UnparsedPublicKey::new(&signature::ED25519, x).verify(message, signature)
+            }
+            CosePublicKey::Rs256 { n, e } => RsaPublicKeyComponents { n, e }.verify(
+                &signature::RSA_PKCS1_2048_8192_SHA256,
+                message,
+                signature,
+            ),
+        };
+
+        result.map_err(|_| "Invalid signature".to_string())
+    }
+}
+
+fn cose_value(entries: &[(Value, Value)], label: i64) -> Option<&Value> {
+    entries
+        .iter()
+        .find(|(key, _)| key.as_integer().and_then(|k| i64::try_from(k).ok()) == Some(label))
+        .map(|(_, value)| value)
+}
+
+fn cose_integer(entries: &[(Value, Value)], label: i64) -> Result<i64, String> {
+    cose_value(entries, label)
+        .and_then(|value| value.as_integer())
+        .and_then(|value| i64::try_from(value).ok())
+        .ok_or_else(|| format!("COSE key is missing integer label {}", label))
+}
+
+fn cose_bytes(entries: &[(Value, Value)], label: i64) -> Result<Vec<u8>, String> {
+    cose_value(entries, label)
+        .and_then(|value| value.as_bytes())
+        .cloned()
+        .ok_or_else(|| format!("COSE key is missing byte label {}", label))
+}
+
+pub fn sha256(data: &[u8]) -> Vec<u8> {
+    digest(&SHA256, data).as_ref().to_vec()
+}
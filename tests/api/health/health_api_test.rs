@@ -0,0 +1,125 @@
+use crate::api::support::{get_json, test_app, test_app_with_health_checks, token_config};
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use axum_api_starter::bootstrap::build_app_state;
+use axum_api_starter::router::create_router;
+use axum_api_starter::util::health_check::HealthCheck;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::key_ring::KeyRing;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Mocks ---
+
+    struct MockHealthCheck {
+        name: &'static str,
+        critical: bool,
+        result: Result<(), String>,
+    }
+
+    #[async_trait]
+    impl HealthCheck for MockHealthCheck {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn critical(&self) -> bool {
+            self.critical
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            self.result.clone()
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_report_live_without_checking_dependencies() {
+        let app = test_app_with_health_checks(vec![Arc::new(MockHealthCheck {
+            name: "database",
+            critical: true,
+            result: Err("connection refused".to_string()),
+        })]);
+
+        let (status, body) = get_json(&app, "/health/live").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["content"]["status"], "Up");
+    }
+
+    #[tokio::test]
+    async fn should_report_ready_with_each_default_component() {
+        let app = test_app();
+
+        let (status, body) = get_json(&app, "/health/ready").await;
+
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        assert_eq!(body["content"]["status"], "Up");
+        let components = body["content"]["components"].as_array().unwrap();
+        let names: Vec<_> = components.iter().map(|c| c["name"].as_str().unwrap()).collect();
+        assert_eq!(names, vec!["shutdown", "signing_keys", "user_store"]);
+        assert!(components.iter().all(|c| c["status"] == "Up" && c["critical"] == true));
+    }
+
+    #[tokio::test]
+    async fn should_return_503_when_a_critical_component_is_down() {
+        let app = test_app_with_health_checks(vec![
+            Arc::new(MockHealthCheck {
+                name: "database",
+                critical: true,
+                result: Err("connection refused".to_string()),
+            }),
+            Arc::new(MockHealthCheck {
+                name: "mail_sender",
+                critical: false,
+                result: Ok(()),
+            }),
+        ]);
+
+        let (status, body) = get_json(&app, "/health/ready").await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "ServiceUnavailable");
+        assert_eq!(body["content"]["status"], "Down");
+        assert_eq!(body["content"]["components"][0]["status"], "Down");
+        assert_eq!(body["content"]["components"][0]["error"], "connection refused");
+        assert_eq!(body["content"]["components"][1]["status"], "Up");
+    }
+
+    #[tokio::test]
+    async fn should_stay_ready_when_only_a_non_critical_component_is_down() {
+        let app = test_app_with_health_checks(vec![Arc::new(MockHealthCheck {
+            name: "mail_sender",
+            critical: false,
+            result: Err("smtp timeout".to_string()),
+        })]);
+
+        let (status, body) = get_json(&app, "/health/ready").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["content"]["status"], "Up");
+        assert_eq!(body["content"]["components"][0]["status"], "Down");
+    }
+
+    #[tokio::test]
+    async fn should_fail_readiness_but_stay_live_once_shutdown_begins() {
+        let app = test_app();
+        let state = build_app_state(&app.config, KeyRing::new(token_config(), SystemClock).unwrap(), &app.repositories);
+        let shutdown = state.shutdown.clone();
+        let router = create_router(state);
+
+        shutdown.begin();
+
+        let (status, body) = get_json(&router, "/health/ready").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["content"]["components"][0]["name"], "shutdown");
+        assert_eq!(body["content"]["components"][0]["error"], "Shutting down");
+
+        let (status, _) = get_json(&router, "/health/live").await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod request_span_api_test;
+pub mod request_id_api_test;
@@ -0,0 +1,80 @@
+use crate::support::captured_logs::CapturedLogs;
+use crate::api::support::{post_form, send, test_app};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    fn get_with_request_id(uri: &str, request_id: Option<&str>) -> Request<Body> {
+        let mut request = Request::builder().uri(uri);
+        if let Some(request_id) = request_id {
+            request = request.header("x-request-id", request_id);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_generate_request_id_and_echo_it_everywhere() {
+        let (logs, _guard) = CapturedLogs::install();
+        let app = test_app();
+
+        let (status, headers, body) = send(&app, get_with_request_id("/v1/users/me", None)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // Cabeçalho, envelope de erro e span do log carregam o mesmo id
+        let request_id = headers["x-request-id"].to_str().unwrap();
+        assert!(uuid::Uuid::parse_str(request_id).is_ok());
+        assert_eq!(body["request_id"], request_id);
+        assert_eq!(body["status"], "Unauthorized");
+        assert_eq!(logs.find("request completed")["span"]["request_id"], request_id);
+    }
+
+    #[tokio::test]
+    async fn should_keep_a_valid_request_id_from_the_caller() {
+        let app = test_app();
+
+        let (_, headers, body) =
+            send(&app, get_with_request_id("/v1/users/me", Some("edge-7f3a.01:retry_2"))).await;
+
+        assert_eq!(headers["x-request-id"], "edge-7f3a.01:retry_2");
+        assert_eq!(body["request_id"], "edge-7f3a.01:retry_2");
+    }
+
+    #[tokio::test]
+    async fn should_replace_an_invalid_request_id_from_the_caller() {
+        let app = test_app();
+        let too_long = "a".repeat(129);
+
+        for invalid in ["has spaces", "quote\"d", too_long.as_str()] {
+            let (_, headers, _) = send(&app, get_with_request_id("/v1/users/me", Some(invalid))).await;
+
+            let request_id = headers["x-request-id"].to_str().unwrap();
+            assert_ne!(request_id, invalid);
+            assert!(uuid::Uuid::parse_str(request_id).is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn should_include_request_id_in_oauth_errors() {
+        let app = test_app();
+
+        let (status, headers, error) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials",
+            &[("x-request-id", "oauth-trace-1")],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(headers["x-request-id"], "oauth-trace-1");
+        assert_eq!(error["error"], "invalid_client");
+        assert_eq!(error["request_id"], "oauth-trace-1");
+    }
+}
@@ -0,0 +1,52 @@
+use crate::support::captured_logs::CapturedLogs;
+use crate::api::support::{get_json, post_json, test_app};
+use axum::http::StatusCode;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_log_completed_request_with_its_span_fields() {
+        let (logs, _guard) = CapturedLogs::install();
+        let app = test_app();
+
+        let (status, _) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "logged@example.com", "password": "password123", "role": "User" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let line = logs.find("request completed");
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["span"]["name"], "request");
+        assert_eq!(line["span"]["method"], "POST");
+        assert_eq!(line["span"]["path"], "/v1/users");
+        assert_eq!(line["span"]["status"], 201);
+        assert!(line["span"]["latency_ms"].is_u64());
+        assert!(!line["span"]["request_id"].as_str().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_give_each_request_its_own_request_id() {
+        let (logs, _guard) = CapturedLogs::install();
+        let app = test_app();
+
+        get_json(&app, "/.well-known/jwks.json").await;
+        get_json(&app, "/.well-known/jwks.json").await;
+
+        let request_ids: Vec<_> = logs
+            .lines()
+            .into_iter()
+            .filter(|line| line["message"] == "request completed")
+            .map(|line| line["span"]["request_id"].clone())
+            .collect();
+        assert_eq!(request_ids.len(), 2);
+        assert_ne!(request_ids[0], request_ids[1]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::api::support::{create_user, post_json, send, sign_in, test_app};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::json;
+use tower::ServiceExt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    async fn scrape(app: &axum::Router) -> String {
+        let request = Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response.headers()["content-type"]
+                .to_str()
+                .unwrap()
+                .starts_with("text/plain")
+        );
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    // O recorder é global e compartilhado pelos testes em paralelo, então só a presença da série
+    // (com valor positivo) é verificada, nunca um valor exato
+    fn has_series(metrics: &str, name: &str, labels: &[(&str, &str)]) -> bool {
+        metrics.lines().any(|line| {
+            line.starts_with(&format!("{}{{", name))
+                && labels
+                    .iter()
+                    .all(|(label, value)| line.contains(&format!("{}=\"{}\"", label, value)))
+                && line
+                    .rsplit(' ')
+                    .next()
+                    .and_then(|value| value.parse::<f64>().ok())
+                    .is_some_and(|value| value > 0.0)
+        })
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_expose_signup_hashing_and_repository_metrics() {
+        let app = test_app();
+        create_user(&app, "metrics@example.com", "password123").await;
+        let (status, _) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "metrics@example.com", "password": "password123", "role": "User" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let metrics = scrape(&app).await;
+
+        assert!(has_series(&metrics, "user_signups_total", &[("outcome", "created")]));
+        assert!(has_series(&metrics, "user_signups_total", &[("outcome", "duplicate")]));
+        assert!(has_series(
+            &metrics,
+            "password_hash_duration_seconds_count",
+            &[("operation", "hash")]
+        ));
+        assert!(has_series(
+            &metrics,
+            "repository_call_duration_seconds_bucket",
+            &[("operation", "create_user"), ("backend", "memory"), ("le", "+Inf")]
+        ));
+        assert!(metrics.contains("# TYPE http_requests_in_flight gauge"));
+    }
+
+    #[tokio::test]
+    async fn should_label_http_metrics_with_the_route_template() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "routes@example.com", "password123").await;
+        let request = Request::builder()
+            .uri(format!("/v1/users/{}", user["id"].as_str().unwrap()))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _, _) = send(&app, request).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let metrics = scrape(&app).await;
+
+        assert!(has_series(
+            &metrics,
+            "http_requests_total",
+            &[("method", "POST"), ("route", "/v1/users"), ("status", "201")]
+        ));
+        assert!(has_series(
+            &metrics,
+            "http_request_duration_seconds_count",
+            &[("method", "GET"), ("route", "/v1/users/{id}"), ("status", "200")]
+        ));
+        // O id concreto nunca vira rótulo
+        assert!(!metrics.contains(user["id"].as_str().unwrap()));
+    }
+}
@@ -0,0 +1,12 @@
+pub mod support;
+pub mod passkey;
+pub mod oidc;
+pub mod oauth;
+pub mod personal_access_token;
+pub mod user;
+pub mod logging;
+pub mod metrics;
+pub mod telemetry;
+pub mod health;
+pub mod openapi;
+pub mod versioning;
@@ -0,0 +1,226 @@
+use crate::api::support::{
+    API_KEY, get_json, post_form, post_json, post_json_with_headers, test_app, token_config,
+};
+use axum::Router;
+use axum::http::StatusCode;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, decode_header};
+use serde_json::{Value, json};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    async fn create_client(app: &Router, scopes: &[&str]) -> Value {
+        let (status, body) = post_json_with_headers(
+            app,
+            "/v1/oauth/clients",
+            json!({ "name": "billing-service", "scopes": scopes }),
+            &[("x-api-key", API_KEY)],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+        body["content"].clone()
+    }
+
+    fn basic_auth(client: &Value) -> String {
+        let credentials = format!(
+            "{}:{}",
+            client["client_id"].as_str().unwrap(),
+            client["client_secret"].as_str().unwrap()
+        );
+
+        format!("Basic {}", STANDARD.encode(credentials))
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_issue_token_and_introspect_it() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read", "users:write"]).await;
+        let authorization = basic_auth(&client);
+
+        let (status, headers, token) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials&scope=users:read",
+            &[("authorization", &authorization)],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{}", token);
+        assert_eq!(headers["cache-control"], "no-store");
+        assert_eq!(token["token_type"], "Bearer");
+        assert_eq!(token["scope"], "users:read");
+        assert_eq!(token["expires_in"], 900);
+
+        let (status, _, introspection) = post_form(
+            &app,
+            "/v1/oauth/introspect",
+            &format!("token={}", token["access_token"].as_str().unwrap()),
+            &[("authorization", &authorization)],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{}", introspection);
+        assert_eq!(introspection["active"], true);
+        assert_eq!(introspection["client_id"], client["client_id"]);
+        assert_eq!(introspection["scope"], "users:read");
+    }
+
+    #[tokio::test]
+    async fn should_grant_all_allowed_scopes_with_client_secret_post() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read", "users:write"]).await;
+
+        let (status, _, token) = post_form(
+            &app,
+            "/v1/oauth/token",
+            &format!(
+                "grant_type=client_credentials&client_id={}&client_secret={}",
+                client["client_id"].as_str().unwrap(),
+                client["client_secret"].as_str().unwrap()
+            ),
+            &[],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{}", token);
+        assert_eq!(token["scope"], "users:read users:write");
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_client_secret() {
+        let app = test_app();
+        let mut client = create_client(&app, &["users:read"]).await;
+        client["client_secret"] = json!("wrong-secret");
+
+        let (status, headers, error) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials",
+            &[("authorization", &basic_auth(&client))],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(headers.contains_key("www-authenticate"));
+        assert_eq!(error["error"], "invalid_client");
+    }
+
+    #[tokio::test]
+    async fn should_reject_scope_not_allowed_for_client() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read"]).await;
+
+        let (status, _, error) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials&scope=users:write",
+            &[("authorization", &basic_auth(&client))],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["error"], "invalid_scope");
+    }
+
+    #[tokio::test]
+    async fn should_reject_unsupported_grant_type() {
+        let app = test_app();
+
+        let (status, _, error) = post_form(&app, "/v1/oauth/token", "grant_type=password", &[]).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error["error"], "unsupported_grant_type");
+    }
+
+    #[tokio::test]
+    async fn should_reject_empty_form_as_invalid_request() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read"]).await;
+        let authorization = basic_auth(&client);
+
+        // Campos obrigatórios ausentes não podem virar o 422 em texto puro do axum
+        for uri in ["/v1/oauth/token", "/v1/oauth/introspect"] {
+            let (status, _, error) =
+                post_form(&app, uri, "", &[("authorization", &authorization)]).await;
+
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}: {}", uri, error);
+            assert_eq!(error["error"], "invalid_request");
+            assert!(error["error_description"].is_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn should_report_unknown_token_as_inactive() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read"]).await;
+
+        let (status, _, introspection) = post_form(
+            &app,
+            "/v1/oauth/introspect",
+            "token=not-a-token",
+            &[("authorization", &basic_auth(&client))],
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(introspection, json!({ "active": false }));
+    }
+
+    #[tokio::test]
+    async fn should_publish_keys_that_verify_issued_tokens() {
+        let app = test_app();
+        let client = create_client(&app, &["users:read"]).await;
+
+        let (_, _, token) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials",
+            &[("authorization", &basic_auth(&client))],
+        )
+        .await;
+        let access_token = token["access_token"].as_str().unwrap();
+
+        let (status, jwks) = get_json(&app, "/.well-known/jwks.json").await;
+        assert_eq!(status, StatusCode::OK);
+        // Chave atual e a próxima já publicada
+        assert_eq!(jwks["keys"].as_array().unwrap().len(), 2);
+
+        // Um serviço externo valida o token só com o JWKS público
+        let jwks: JwkSet = serde_json::from_value(jwks).unwrap();
+        let header = decode_header(access_token).unwrap();
+        assert_eq!(header.alg, Algorithm::EdDSA);
+
+        let jwk = jwks.find(&header.kid.unwrap()).unwrap();
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_issuer(&[token_config().issuer]);
+        validation.validate_aud = false;
+
+        let claims = decode::<Value>(access_token, &DecodingKey::from_jwk(jwk).unwrap(), &validation)
+            .unwrap()
+            .claims;
+        assert_eq!(claims["client_id"], client["client_id"]);
+    }
+
+    #[tokio::test]
+    async fn should_require_api_key_to_register_clients() {
+        let app = test_app();
+
+        let (status, _) = post_json(
+            &app,
+            "/v1/oauth/clients",
+            json!({ "name": "rogue", "scopes": ["users:write"] }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
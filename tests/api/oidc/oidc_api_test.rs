@@ -0,0 +1,205 @@
+use crate::api::support::mock_oidc_issuer::MockOidcIssuer;
+use crate::api::support::{create_user, oidc_config, send, test_app_with_oidc};
+use axum::Router;
+use axum::body::Body;
+use axum::http::{HeaderMap, Request, StatusCode};
+use serde_json::Value;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    /// Uma tentativa de login: cookie do navegador e callback já autorizado no issuer.
+    struct LoginAttempt {
+        cookie: String,
+        callback: String,
+    }
+
+    async fn get_with_cookie(
+        app: &Router,
+        uri: &str,
+        cookie: Option<&str>,
+    ) -> (StatusCode, HeaderMap, Value) {
+        let mut request = Request::builder().method("GET").uri(uri);
+
+        if let Some(cookie) = cookie {
+            request = request.header("cookie", cookie);
+        }
+
+        send(app, request.body(Body::empty()).unwrap()).await
+    }
+
+    async fn start_login(
+        app: &Router,
+        issuer: &MockOidcIssuer,
+        subject: &str,
+        email: &str,
+        email_verified: bool,
+    ) -> LoginAttempt {
+        let (status, headers, login_start) =
+            get_with_cookie(app, "/v1/auth/oidc/login", None).await;
+        assert_eq!(status, StatusCode::OK, "{}", login_start);
+
+        let authorization_url = login_start["content"]["authorization_url"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let state = query_param(&authorization_url, "state");
+        let code = issuer.authorize(&authorization_url, subject, email, email_verified);
+
+        // Só o par nome=valor volta ao servidor, sem os atributos
+        let set_cookie = headers["set-cookie"].to_str().unwrap();
+        let cookie = set_cookie.split(';').next().unwrap().to_string();
+
+        LoginAttempt {
+            cookie,
+            callback: format!("/v1/auth/oidc/callback?state={}&code={}", state, code),
+        }
+    }
+
+    async fn login(
+        app: &Router,
+        issuer: &MockOidcIssuer,
+        subject: &str,
+        email: &str,
+        email_verified: bool,
+    ) -> (StatusCode, Value) {
+        let attempt = start_login(app, issuer, subject, email, email_verified).await;
+
+        let (status, _, body) =
+            get_with_cookie(app, &attempt.callback, Some(&attempt.cookie)).await;
+        (status, body)
+    }
+
+    fn query_param(url: &str, name: &str) -> String {
+        reqwest::Url::parse(url)
+            .unwrap()
+            .query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+            .unwrap()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_provision_user_on_first_login() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+
+        let (status, body) = login(&app, &issuer, "subject-1", "federated@example.com", true).await;
+
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        assert_eq!(body["content"]["user"]["email"], "federated@example.com");
+        assert_eq!(body["content"]["user"]["role"], "User");
+    }
+
+    #[tokio::test]
+    async fn should_link_existing_user_by_verified_email() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+        let user = create_user(&app, "local@example.com", "password123").await;
+
+        let (status, first_login) =
+            login(&app, &issuer, "subject-2", "local@example.com", true).await;
+        assert_eq!(status, StatusCode::OK, "{}", first_login);
+        assert_eq!(first_login["content"]["user"]["id"], user["id"]);
+
+        // O vínculo é pelo subject: o email no IdP pode mudar depois
+        let (status, second_login) =
+            login(&app, &issuer, "subject-2", "renamed@example.com", true).await;
+        assert_eq!(status, StatusCode::OK, "{}", second_login);
+        assert_eq!(second_login["content"]["user"]["id"], user["id"]);
+    }
+
+    #[tokio::test]
+    async fn should_reject_unverified_email() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+        create_user(&app, "victim@example.com", "password123").await;
+
+        let (status, _) = login(&app, &issuer, "subject-3", "victim@example.com", false).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_reject_id_token_signed_with_unpublished_key() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+        issuer.rotate_key_without_publishing();
+
+        let (status, _) = login(&app, &issuer, "subject-4", "forged@example.com", true).await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_not_redeem_the_same_state_twice() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+        let attempt = start_login(&app, &issuer, "subject-5", "once@example.com", true).await;
+
+        let (first_status, headers, _) =
+            get_with_cookie(&app, &attempt.callback, Some(&attempt.cookie)).await;
+        let (replay_status, _, _) =
+            get_with_cookie(&app, &attempt.callback, Some(&attempt.cookie)).await;
+
+        assert_eq!(first_status, StatusCode::OK);
+        assert!(
+            headers["set-cookie"]
+                .to_str()
+                .unwrap()
+                .contains("Max-Age=0")
+        );
+        assert_eq!(replay_status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn should_bind_the_state_to_a_short_lived_http_only_cookie() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+
+        let (_, headers, login_start) = get_with_cookie(&app, "/v1/auth/oidc/login", None).await;
+
+        let set_cookie = headers["set-cookie"].to_str().unwrap();
+        let authorization_url = login_start["content"]["authorization_url"]
+            .as_str()
+            .unwrap();
+        let state = query_param(authorization_url, "state");
+        assert!(
+            set_cookie.starts_with(&format!("oidc_state={};", state)),
+            "{}",
+            set_cookie
+        );
+        assert!(set_cookie.contains("HttpOnly"));
+        assert!(set_cookie.contains("SameSite=Lax"));
+        assert!(set_cookie.contains("Max-Age=600"));
+        // O state não aparece no corpo, só na URL e no cookie
+        assert!(login_start["content"].get("state").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_reject_callback_from_a_browser_that_did_not_start_the_login() {
+        let issuer = MockOidcIssuer::start().await;
+        let app = test_app_with_oidc(oidc_config(&issuer.issuer_url));
+        // O atacante inicia o login com a própria conta e envia o callback à vítima
+        let attacker = start_login(&app, &issuer, "attacker", "attacker@example.com", true).await;
+        let victim = start_login(&app, &issuer, "victim", "victim@example.com", true).await;
+
+        let (without_cookie, _, _) = get_with_cookie(&app, &attacker.callback, None).await;
+        let (other_cookie, _, error) =
+            get_with_cookie(&app, &attacker.callback, Some(&victim.cookie)).await;
+
+        assert_eq!(without_cookie, StatusCode::BAD_REQUEST);
+        assert_eq!(other_cookie, StatusCode::BAD_REQUEST);
+        assert_eq!(error["detail"], "Login was not started from this browser");
+
+        // As tentativas forjadas não consomem o login legítimo de ninguém
+        let (status, _, body) =
+            get_with_cookie(&app, &attacker.callback, Some(&attacker.cookie)).await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::api::support::{API_KEY, get_json, send, test_app, token_config};
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use axum_api_starter::bootstrap::build_app_state;
+use axum_api_starter::middleware::api_key_middleware::API_KEY_HEADER;
+use axum_api_starter::openapi::{DOCS_UI_PATH, OPENAPI_PATH, openapi};
+use axum_api_starter::router::create_router;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::key_ring::KeyRing;
+use http_body_util::BodyExt;
+use std::collections::BTreeSet;
+use tower::ServiceExt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    const METHODS: [Method; 5] = [
+        Method::GET,
+        Method::POST,
+        Method::PUT,
+        Method::PATCH,
+        Method::DELETE,
+    ];
+
+    /// O axum não lista as rotas registradas, mas o Debug do Router imprime os caminhos.
+    fn registered_paths(app: &Router) -> BTreeSet<String> {
+        let debug = format!("{:?}", app);
+
+        debug
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            // O fallback interno do axum também aparece como caminho
+            .filter(|text| text.starts_with('/') && !text.contains("__private__axum"))
+            .map(str::to_string)
+            .collect()
+    }
+
+    fn documented_methods(path: &str) -> Vec<Method> {
+        let item = openapi().paths.paths.get(path);
+
+        METHODS
+            .into_iter()
+            .filter(|method| {
+                item.is_some_and(|item| match *method {
+                    Method::GET => item.get.is_some(),
+                    Method::POST => item.post.is_some(),
+                    Method::PUT => item.put.is_some(),
+                    Method::PATCH => item.patch.is_some(),
+                    _ => item.delete.is_some(),
+                })
+            })
+            .collect()
+    }
+
+    /// Troca parâmetros como `{id}` por um valor qualquer.
+    fn concrete(path: &str) -> String {
+        path.split('/')
+            .map(|segment| {
+                if segment.starts_with('{') {
+                    "probe"
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    async fn status_of(app: &Router, method: Method, path: &str) -> StatusCode {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .header(API_KEY_HEADER, API_KEY)
+            .body(Body::empty())
+            .unwrap();
+
+        app.clone().oneshot(request).await.unwrap().status()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_serve_schemas_generated_from_the_dtos() {
+        let app = test_app();
+
+        let (status, body) = get_json(&app, OPENAPI_PATH).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["openapi"], "3.1.0");
+        assert_eq!(
+            body["paths"]["/v1/users"]["post"]["requestBody"]["content"]["application/json"]["schema"]
+                ["$ref"],
+            "#/components/schemas/UserForCreation"
+        );
+
+        let schemas = &body["components"]["schemas"];
+        for name in [
+            "UserForCreation",
+            "UserPublic",
+            "AppError",
+            "HttpResponse_UserPublic",
+        ] {
+            assert!(schemas[name].is_object(), "missing schema {}", name);
+        }
+        let envelope = &schemas["HttpResponse_UserPublic"]["properties"];
+        assert!(envelope["request_id"].is_object());
+        assert_eq!(
+            envelope["content"]["properties"]["email"]["$ref"],
+            "#/components/schemas/Email"
+        );
+        assert_eq!(
+            schemas["UserForCreation"]["properties"]["password"]["format"],
+            "password"
+        );
+        assert!(body["components"]["securitySchemes"]["bearer"].is_object());
+    }
+
+    #[tokio::test]
+    async fn should_document_body_rejections_on_json_operations() {
+        let app = test_app();
+
+        let (_, body) = get_json(&app, OPENAPI_PATH).await;
+
+        let mut json_operations = 0;
+        for (path, item) in body["paths"].as_object().unwrap() {
+            for (method, operation) in item.as_object().unwrap() {
+                if operation["requestBody"]["content"]["application/json"].is_null() {
+                    continue;
+                }
+                json_operations += 1;
+                let rejection =
+                    &operation["responses"]["422"]["content"]["application/json"]["schema"]["$ref"];
+                assert_eq!(
+                    rejection, "#/components/schemas/HttpResponse_AppError",
+                    "{} {} does not document 422",
+                    method, path
+                );
+            }
+        }
+        assert!(json_operations > 0);
+    }
+
+    #[tokio::test]
+    async fn should_document_every_registered_route() {
+        let app = test_app();
+        let paths = registered_paths(&app);
+        assert!(
+            paths.contains("/v1/users"),
+            "could not read routes from {:?}",
+            paths
+        );
+
+        for path in &paths {
+            if path == OPENAPI_PATH || path == DOCS_UI_PATH {
+                continue;
+            }
+
+            let documented = documented_methods(path);
+            assert!(
+                !documented.is_empty(),
+                "{} is routed but not documented",
+                path
+            );
+
+            // Métodos fora do documento precisam responder 405, senão há uma rota escondida
+            for method in METHODS {
+                let status = status_of(&app, method.clone(), &concrete(path)).await;
+
+                if documented.contains(&method) {
+                    assert!(
+                        status != StatusCode::METHOD_NOT_ALLOWED && status != StatusCode::NOT_FOUND,
+                        "{} {} is documented but answered {}",
+                        method,
+                        path,
+                        status
+                    );
+                } else {
+                    assert_eq!(
+                        status,
+                        StatusCode::METHOD_NOT_ALLOWED,
+                        "{} {} is routed but not documented",
+                        method,
+                        path
+                    );
+                }
+            }
+        }
+
+        for path in openapi().paths.paths.keys() {
+            assert!(
+                paths.contains(path),
+                "{} is documented but not routed",
+                path
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn should_serve_the_docs_ui_unless_disabled() {
+        let app = test_app();
+
+        let response = app
+            .router
+            .clone()
+            .oneshot(Request::get(DOCS_UI_PATH).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(String::from_utf8_lossy(&page).contains(OPENAPI_PATH));
+
+        let mut state = build_app_state(
+            &app.config,
+            KeyRing::new(token_config(), SystemClock).unwrap(),
+            &app.repositories,
+        );
+        state.docs_ui_enabled = false;
+        let router = create_router(state);
+
+        let (status, _, _) = send(
+            &router,
+            Request::get(DOCS_UI_PATH).body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = get_json(&router, OPENAPI_PATH).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
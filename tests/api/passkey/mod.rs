@@ -0,0 +1 @@
+pub mod passkey_api_test;
@@ -0,0 +1,208 @@
+use crate::api::support::software_authenticator::SoftwareAuthenticator;
+use crate::api::support::{ORIGIN, RP_ID, create_user, post_json, test_app};
+use axum::Router;
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    async fn register_passkey(
+        app: &Router,
+        authenticator: &mut SoftwareAuthenticator,
+        email: &str,
+        password: &str,
+    ) -> (StatusCode, Value) {
+        let (status, options) = post_json(
+            app,
+            "/v1/passkeys/register/start",
+            json!({ "email": email, "password": password }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", options);
+
+        let credential = authenticator.register(&options["content"]["public_key"]);
+
+        post_json(
+            app,
+            "/v1/passkeys/register/finish",
+            json!({
+                "ceremony_id": options["content"]["ceremony_id"],
+                "credential": credential,
+            }),
+        )
+        .await
+    }
+
+    async fn start_login(app: &Router, email: Option<&str>) -> Value {
+        let (status, options) =
+            post_json(app, "/v1/passkeys/login/start", json!({ "email": email })).await;
+        assert_eq!(status, StatusCode::OK, "{}", options);
+
+        options["content"].clone()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_register_and_authenticate_with_passkey() {
+        let app = test_app();
+        let user = create_user(&app, "passkey@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+
+        let (status, registered) =
+            register_passkey(&app, &mut authenticator, "passkey@example.com", "password123").await;
+        assert_eq!(status, StatusCode::CREATED, "{}", registered);
+        assert_eq!(registered["content"]["id"], authenticator.credential_id());
+        assert_eq!(registered["content"]["user_id"], user["id"]);
+
+        let options = start_login(&app, Some("passkey@example.com")).await;
+        assert_eq!(
+            options["public_key"]["allowCredentials"][0]["id"],
+            authenticator.credential_id()
+        );
+        assert_eq!(options["public_key"]["userVerification"], "required");
+
+        let assertion = authenticator.authenticate(&options["public_key"]);
+        let (status, authenticated) = post_json(
+            &app,
+            "/v1/passkeys/login/finish",
+            json!({ "ceremony_id": options["ceremony_id"], "credential": assertion }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{}", authenticated);
+        assert_eq!(authenticated["content"]["user"]["id"], user["id"]);
+        assert_eq!(authenticated["content"]["user"]["email"], "passkey@example.com");
+        assert_eq!(authenticated["content"]["token_type"], "Bearer");
+        assert!(authenticated["content"]["access_token"].is_string());
+    }
+
+    #[tokio::test]
+    async fn should_authenticate_with_discoverable_passkey_without_email() {
+        let app = test_app();
+        let user = create_user(&app, "discoverable@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(&app, &mut authenticator, "discoverable@example.com", "password123").await;
+
+        let options = start_login(&app, None).await;
+        assert_eq!(options["public_key"]["allowCredentials"], json!([]));
+
+        let assertion = authenticator.authenticate(&options["public_key"]);
+        let (status, authenticated) = post_json(
+            &app,
+            "/v1/passkeys/login/finish",
+            json!({ "ceremony_id": options["ceremony_id"], "credential": assertion }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK, "{}", authenticated);
+        assert_eq!(authenticated["content"]["user"]["id"], user["id"]);
+    }
+
+    #[tokio::test]
+    async fn should_reject_registration_with_wrong_password() {
+        let app = test_app();
+        create_user(&app, "wrong@example.com", "password123").await;
+
+        let (status, _) = post_json(
+            &app,
+            "/v1/passkeys/register/start",
+            json!({ "email": "wrong@example.com", "password": "not-the-password" }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_not_finish_the_same_ceremony_twice() {
+        let app = test_app();
+        create_user(&app, "replay@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(&app, &mut authenticator, "replay@example.com", "password123").await;
+
+        let options = start_login(&app, Some("replay@example.com")).await;
+        let assertion = authenticator.authenticate(&options["public_key"]);
+        let request = json!({ "ceremony_id": options["ceremony_id"], "credential": assertion });
+
+        let (first_status, _) = post_json(&app, "/v1/passkeys/login/finish", request.clone()).await;
+        let (replay_status, _) = post_json(&app, "/v1/passkeys/login/finish", request).await;
+
+        assert_eq!(first_status, StatusCode::OK);
+        assert_eq!(replay_status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn should_reject_assertion_without_user_verification() {
+        let app = test_app();
+        create_user(&app, "touch@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(&app, &mut authenticator, "touch@example.com", "password123").await;
+
+        // Só um toque, sem PIN nem biometria: não basta como único fator
+        authenticator.skip_user_verification();
+        let options = start_login(&app, Some("touch@example.com")).await;
+        let assertion = authenticator.authenticate(&options["public_key"]);
+
+        let (status, body) = post_json(
+            &app,
+            "/v1/passkeys/login/finish",
+            json!({ "ceremony_id": options["ceremony_id"], "credential": assertion }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["detail"], "User verification is required");
+    }
+
+    #[tokio::test]
+    async fn should_reject_assertion_from_another_origin() {
+        let app = test_app();
+        create_user(&app, "phishing@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(&app, &mut authenticator, "phishing@example.com", "password123").await;
+
+        // Mesma credencial, mas o navegador reporta outra origem
+        authenticator.set_origin("https://evil.example");
+        let options = start_login(&app, Some("phishing@example.com")).await;
+        let assertion = authenticator.authenticate(&options["public_key"]);
+
+        let (status, _) = post_json(
+            &app,
+            "/v1/passkeys/login/finish",
+            json!({ "ceremony_id": options["ceremony_id"], "credential": assertion }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn should_reject_assertion_signed_with_unregistered_key() {
+        let app = test_app();
+        create_user(&app, "cloned@example.com", "password123").await;
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(&app, &mut authenticator, "cloned@example.com", "password123").await;
+
+        let options = start_login(&app, Some("cloned@example.com")).await;
+        let mut assertion = authenticator.authenticate(&options["public_key"]);
+
+        // Assinatura produzida por outra chave para o mesmo desafio
+        let mut impostor = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        assertion["response"]["signature"] =
+            impostor.authenticate(&options["public_key"])["response"]["signature"].clone();
+
+        let (status, _) = post_json(
+            &app,
+            "/v1/passkeys/login/finish",
+            json!({ "ceremony_id": options["ceremony_id"], "credential": assertion }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::api::support::{
+    API_KEY, post_form, post_json_with_headers, send_authorized, sign_in, test_app,
+};
+use axum::Router;
+use axum::http::StatusCode;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Value, json};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    async fn create_token(
+        app: &Router,
+        session_token: &str,
+        scopes: &[&str],
+    ) -> (StatusCode, Value) {
+        send_authorized(
+            app,
+            "POST",
+            "/v1/users/me/tokens",
+            session_token,
+            Some(json!({ "name": "deploy-script", "scopes": scopes, "expires_in_days": 30 })),
+        )
+        .await
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_create_token_shown_once_and_authenticate_with_it() {
+        let app = test_app();
+        let (user, session_token) = sign_in(&app, "pat@example.com", "password123").await;
+
+        let (status, created) = create_token(&app, &session_token, &["users:read"]).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", created);
+
+        let token = created["content"]["token"].as_str().unwrap();
+        assert!(token.starts_with("pat_"));
+        assert_eq!(
+            created["content"]["personal_access_token"]["scopes"],
+            json!(["users:read"])
+        );
+
+        let (status, me) = send_authorized(&app, "GET", "/v1/users/me", token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", me);
+        assert_eq!(me["content"]["id"], user["id"]);
+
+        // A listagem nunca devolve o token nem o hash
+        let (status, listed) =
+            send_authorized(&app, "GET", "/v1/users/me/tokens", &session_token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", listed);
+        assert_eq!(listed["content"].as_array().unwrap().len(), 1);
+        assert!(listed["content"][0].get("token").is_none());
+        assert!(listed["content"][0].get("token_hash").is_none());
+        assert!(!listed["content"][0]["last_used_at"].is_null());
+    }
+
+    #[tokio::test]
+    async fn should_enforce_token_scopes_per_route() {
+        let app = test_app();
+        let (_, session_token) = sign_in(&app, "scoped@example.com", "password123").await;
+
+        let (_, created) = create_token(&app, &session_token, &["users:read"]).await;
+        let token = created["content"]["token"].as_str().unwrap();
+
+        let (status, body) = send_authorized(&app, "GET", "/v1/users/me/tokens", token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+
+        let (status, body) = create_token(&app, token, &["users:read"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn should_not_grant_scopes_beyond_the_creating_credential() {
+        let app = test_app();
+        let (_, session_token) = sign_in(&app, "escalate@example.com", "password123").await;
+
+        let (_, created) = create_token(&app, &session_token, &["tokens:write"]).await;
+        let token = created["content"]["token"].as_str().unwrap();
+
+        let (status, body) = create_token(&app, token, &["tokens:write", "users:write"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+
+        let (status, body) = create_token(&app, &session_token, &["admin:everything"]).await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn should_reject_revoked_token() {
+        let app = test_app();
+        let (_, session_token) = sign_in(&app, "revoke@example.com", "password123").await;
+
+        let (_, created) = create_token(&app, &session_token, &["users:read"]).await;
+        let token = created["content"]["token"].as_str().unwrap();
+        let token_id = created["content"]["personal_access_token"]["id"]
+            .as_str()
+            .unwrap();
+
+        let (status, revoked) = send_authorized(
+            &app,
+            "DELETE",
+            &format!("/v1/users/me/tokens/{}", token_id),
+            &session_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", revoked);
+        assert!(!revoked["content"]["revoked_at"].is_null());
+
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", token, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_not_revoke_token_of_another_user() {
+        let app = test_app();
+        let (_, owner_token) = sign_in(&app, "owner@example.com", "password123").await;
+        let (_, other_token) = sign_in(&app, "other@example.com", "password123").await;
+
+        let (_, created) = create_token(&app, &owner_token, &["users:read"]).await;
+        let token_id = created["content"]["personal_access_token"]["id"]
+            .as_str()
+            .unwrap();
+
+        let (status, _) = send_authorized(
+            &app,
+            "DELETE",
+            &format!("/v1/users/me/tokens/{}", token_id),
+            &other_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn should_validate_expiry_and_reject_missing_credentials() {
+        let app = test_app();
+        let (_, session_token) = sign_in(&app, "expiry@example.com", "password123").await;
+
+        let (status, _) = send_authorized(
+            &app,
+            "POST",
+            "/v1/users/me/tokens",
+            &session_token,
+            Some(json!({ "name": "forever", "scopes": ["users:read"], "expires_in_days": 3650 })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", "pat_unknown", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", "not-a-jwt", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_reject_client_credentials_token_on_user_routes() {
+        let app = test_app();
+
+        let (status, client) = post_json_with_headers(
+            &app,
+            "/v1/oauth/clients",
+            json!({ "name": "reporting", "scopes": ["users:read"] }),
+            &[("x-api-key", API_KEY)],
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED, "{}", client);
+
+        let credentials = format!(
+            "{}:{}",
+            client["content"]["client_id"].as_str().unwrap(),
+            client["content"]["client_secret"].as_str().unwrap()
+        );
+        let (_, _, token) = post_form(
+            &app,
+            "/v1/oauth/token",
+            "grant_type=client_credentials",
+            &[(
+                "authorization",
+                &format!("Basic {}", STANDARD.encode(credentials)),
+            )],
+        )
+        .await;
+
+        // O token é válido e tem o escopo, mas não representa um usuário
+        let access_token = token["access_token"].as_str().unwrap();
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", access_token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
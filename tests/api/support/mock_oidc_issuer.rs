@@ -0,0 +1,201 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use ring::digest::{SHA256, digest};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const CLIENT_ID: &str = "starter-test-client";
+pub const REDIRECT_URI: &str = "http://localhost:3000/v1/auth/oidc/callback";
+
+struct PendingCode {
+    code_challenge: String,
+    nonce: String,
+    subject: String,
+    email: String,
+    email_verified: bool,
+}
+
+struct IssuerState {
+    issuer_url: String,
+    signing_key: Vec<u8>,
+    public_key: Vec<u8>,
+    codes: HashMap<String, PendingCode>,
+}
+
+/// Local OpenID Provider: discovery, JWKS and an authorization-code token endpoint with PKCE.
+pub struct MockOidcIssuer {
+    pub issuer_url: String,
+    state: Arc<Mutex<IssuerState>>,
+}
+
+#[derive(Deserialize)]
+struct TokenRequest {
+    grant_type: String,
+    code: String,
+    redirect_uri: String,
+    client_id: String,
+    code_verifier: String,
+}
+
+impl MockOidcIssuer {
+    pub async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let (signing_key, public_key) = generate_ed25519_key();
+
+        let state = Arc::new(Mutex::new(IssuerState {
+            issuer_url: issuer_url.clone(),
+            signing_key,
+            public_key,
+            codes: HashMap::new(),
+        }));
+
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(state.clone());
+
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        Self { issuer_url, state }
+    }
+
+    /// Simulates the user signing in at the provider and returns the authorization code
+    /// that the browser would carry back to the callback.
+    pub fn authorize(
+        &self,
+        authorization_url: &str,
+        subject: &str,
+        email: &str,
+        email_verified: bool,
+    ) -> String {
+        let url = reqwest::Url::parse(authorization_url).unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+        assert_eq!(query["response_type"], "code");
+        assert_eq!(query["client_id"], CLIENT_ID);
+        assert_eq!(query["code_challenge_method"], "S256");
+
+        let code = format!("code-{}", subject);
+
+        self.state.lock().unwrap().codes.insert(
+            code.clone(),
+            PendingCode {
+                code_challenge: query["code_challenge"].clone(),
+                nonce: query["nonce"].clone(),
+                subject: subject.to_string(),
+                email: email.to_string(),
+                email_verified,
+            },
+        );
+
+        code
+    }
+
+    /// Replaces the signing key without publishing it, so previously seen tokens stop validating.
+    pub fn rotate_key_without_publishing(&self) {
+        let (signing_key, _) = generate_ed25519_key();
+        self.state.lock().unwrap().signing_key = signing_key;
+    }
+}
+
+fn generate_ed25519_key() -> (Vec<u8>, Vec<u8>) {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+
+    (
+        pkcs8.as_ref().to_vec(),
+        key_pair.public_key().as_ref().to_vec(),
+    )
+}
+
+async fn discovery(State(state): State<Arc<Mutex<IssuerState>>>) -> Json<Value> {
+    let issuer_url = state.lock().unwrap().issuer_url.clone();
+
+    Json(json!({
+        "issuer": issuer_url,
+        "authorization_endpoint": format!("{}/authorize", issuer_url),
+        "token_endpoint": format!("{}/token", issuer_url),
+        "jwks_uri": format!("{}/jwks", issuer_url),
+    }))
+}
+
+async fn jwks(State(state): State<Arc<Mutex<IssuerState>>>) -> Json<Value> {
+    let public_key = state.lock().unwrap().public_key.clone();
+
+    Json(json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "use": "sig",
+            "alg": "EdDSA",
+            "kid": "mock-key",
+            "x": URL_SAFE_NO_PAD.encode(public_key),
+        }]
+    }))
+}
+
+async fn token(
+    State(state): State<Arc<Mutex<IssuerState>>>,
+    Form(request): Form<TokenRequest>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    let mut state = state.lock().unwrap();
+    let invalid_grant = || {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "invalid_grant" })),
+        )
+    };
+
+    let pending = state
+        .codes
+        .remove(&request.code)
+        .ok_or_else(invalid_grant)?;
+
+    let challenge = URL_SAFE_NO_PAD.encode(digest(&SHA256, request.code_verifier.as_bytes()));
+
+    if request.grant_type != "authorization_code"
+        || request.client_id != CLIENT_ID
+        || request.redirect_uri != REDIRECT_URI
+        || challenge != pending.code_challenge
+    {
+        return Err(invalid_grant());
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some("mock-key".to_string());
+
+    let id_token = encode(
+        &header,
+        &json!({
+            "iss": state.issuer_url,
+            "sub": pending.subject,
+            "aud": CLIENT_ID,
+            "iat": now,
+            "exp": now + 300,
+            "nonce": pending.nonce,
+            "email": pending.email,
+            "email_verified": pending.email_verified,
+        }),
+        &EncodingKey::from_ed_der(&state.signing_key),
+    )
+    .unwrap();
+
+    Ok(Json(json!({
+        "access_token": "mock-access-token",
+        "token_type": "Bearer",
+        "id_token": id_token,
+    })))
+}
@@ -0,0 +1,357 @@
+pub mod mock_oidc_issuer;
+pub mod software_authenticator;
+pub mod otlp_receiver;
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum_api_starter::bootstrap::{Repositories, build_app_state, build_create_user_service};
+use axum_api_starter::cli::user_command::create_admin;
+use axum_api_starter::config::app_config::{ApiConfig, AppConfig, Profile};
+use axum_api_starter::handler::health_handler::get_readiness_handler::GetReadinessHandlerImpl;
+use axum_api_starter::model::email_model::Email;
+use axum_api_starter::router::create_router;
+use axum_api_starter::service::health_service::check_readiness_service::CheckReadinessServiceImpl;
+use axum_api_starter::util::deprecation::Deprecation;
+use axum_api_starter::util::health_check::HealthCheck;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::key_ring::KeyRing;
+use axum_api_starter::util::oidc::OidcConfig;
+use axum_api_starter::util::retention::{EmailReusePolicy, RetentionConfig};
+use axum_api_starter::util::secret::Secret;
+use axum_api_starter::util::token_issuer::TokenConfig;
+use axum_api_starter::util::webauthn::WebauthnConfig;
+use http_body_util::BodyExt;
+use serde_json::{Value, json};
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+pub const RP_ID: &str = "localhost";
+pub const ORIGIN: &str = "http://localhost:3000";
+pub const API_KEY: &str = "test-api-key";
+
+pub fn webauthn_config() -> WebauthnConfig {
+    WebauthnConfig {
+        rp_id: RP_ID.to_string(),
+        rp_name: "axum-api-starter".to_string(),
+        origin: ORIGIN.to_string(),
+    }
+}
+
+pub fn oidc_config(issuer_url: &str) -> OidcConfig {
+    OidcConfig {
+        issuer_url: issuer_url.to_string(),
+        client_id: mock_oidc_issuer::CLIENT_ID.to_string(),
+        client_secret: None,
+        redirect_uri: mock_oidc_issuer::REDIRECT_URI.to_string(),
+        scopes: vec!["openid".to_string(), "email".to_string()],
+    }
+}
+
+pub fn token_config() -> TokenConfig {
+    TokenConfig {
+        issuer: "axum-api-starter-test".to_string(),
+        access_token_ttl_seconds: 900,
+        signing_key_rotation_seconds: 3600,
+        signing_secret: None,
+    }
+}
+
+pub fn retention_config() -> RetentionConfig {
+    RetentionConfig {
+        restore_window_days: 30,
+        email_reuse: EmailReusePolicy::Immediate,
+    }
+}
+
+/// Router de teste junto com a configuração e os repositórios por trás dele, para que
+/// admins sejam criados pelo mesmo caminho do comando `user create-admin`.
+#[derive(Clone)]
+pub struct TestApp {
+    pub router: Router,
+    pub config: AppConfig,
+    pub repositories: Repositories,
+}
+
+impl Deref for TestApp {
+    type Target = Router;
+
+    fn deref(&self) -> &Router {
+        &self.router
+    }
+}
+
+pub fn test_app() -> TestApp {
+    // Nenhum issuer escuta na porta 9; testes de OIDC usam test_app_with_oidc
+    test_app_with_oidc(oidc_config("http://127.0.0.1:9"))
+}
+
+pub fn test_app_with_oidc(oidc_config: OidcConfig) -> TestApp {
+    build_test_app(oidc_config, retention_config())
+}
+
+pub fn test_app_with_retention(retention_config: RetentionConfig) -> TestApp {
+    build_test_app(oidc_config("http://127.0.0.1:9"), retention_config)
+}
+
+/// Troca as verificações de prontidão, para simular dependências fora do ar.
+pub fn test_app_with_health_checks(
+    health_checks: Vec<Arc<dyn HealthCheck + Send + Sync + 'static>>,
+) -> Router {
+    let app = test_app();
+    let mut state = build_app_state(&app.config, KeyRing::new(token_config(), SystemClock).unwrap(), &app.repositories);
+    state.get_readiness_handler = Arc::new(GetReadinessHandlerImpl {
+        check_readiness_service: Box::new(CheckReadinessServiceImpl {
+            health_checks,
+            timeout: Duration::from_millis(app.config.health.check_timeout_ms),
+        }),
+    });
+
+    create_router(state)
+}
+
+/// Marca versões ou rotas como obsoletas, como faria `API_DEPRECATIONS`.
+pub fn test_app_with_deprecations(deprecations: Vec<Deprecation>) -> Router {
+    let app = test_app();
+    let config = AppConfig {
+        api: ApiConfig { deprecations },
+        ..app.config.clone()
+    };
+
+    create_router(build_app_state(&config, KeyRing::new(token_config(), SystemClock).unwrap(), &app.repositories))
+}
+
+fn build_test_app(oidc_config: OidcConfig, retention_config: RetentionConfig) -> TestApp {
+    let config = AppConfig {
+        token: token_config(),
+        webauthn: webauthn_config(),
+        oidc: oidc_config,
+        retention: retention_config,
+        api_key: Some(Secret::new(API_KEY.to_string())),
+        ..AppConfig::defaults(Profile::Test)
+    };
+
+    let repositories = Repositories::default();
+    let router = create_router(build_app_state(
+        &config,
+        KeyRing::new(token_config(), SystemClock).unwrap(),
+        &repositories,
+    ));
+
+    TestApp {
+        router,
+        config,
+        repositories,
+    }
+}
+
+pub async fn get_json(app: &Router, uri: &str) -> (StatusCode, Value) {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+    let (status, _, body) = send(app, request).await;
+    (status, body)
+}
+
+pub async fn post_json(app: &Router, uri: &str, body: Value) -> (StatusCode, Value) {
+    post_json_with_headers(app, uri, body, &[]).await
+}
+
+pub async fn post_json_with_headers(
+    app: &Router,
+    uri: &str,
+    body: Value,
+    headers: &[(&str, &str)],
+) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/json");
+
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+
+    let (status, _, body) = send(app, request.body(Body::from(body.to_string())).unwrap()).await;
+    (status, body)
+}
+
+pub async fn post_form(
+    app: &Router,
+    uri: &str,
+    body: &str,
+    headers: &[(&str, &str)],
+) -> (StatusCode, HeaderMap, Value) {
+    let mut request = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("content-type", "application/x-www-form-urlencoded");
+
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+
+    send(app, request.body(Body::from(body.to_string())).unwrap()).await
+}
+
+pub async fn send(app: &Router, request: Request<Body>) -> (StatusCode, HeaderMap, Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+
+    (
+        status,
+        headers,
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+    )
+}
+
+pub async fn create_user(app: &Router, email: &str, password: &str) -> Value {
+    let (status, body) = post_json(
+        app,
+        "/v1/users",
+        json!({ "email": email, "password": password, "role": "User" }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    body["content"].clone()
+}
+
+pub async fn create_user_with_role(app: &TestApp, email: &str, password: &str, role: &str) -> Value {
+    if role != "Admin" {
+        return create_user(app, email, password).await;
+    }
+
+    // O cadastro público recusa admins; usa o mesmo caminho da CLI
+    let admin = create_admin(
+        &build_create_user_service(&app.config, &app.repositories),
+        Email::parse(email).unwrap(),
+        Secret::new(password.to_string()),
+    )
+    .await
+    .unwrap();
+    serde_json::to_value(admin).unwrap()
+}
+
+/// Cria o usuário, registra uma passkey e faz login, devolvendo o access token da sessão.
+pub async fn sign_in(app: &TestApp, email: &str, password: &str) -> (Value, String) {
+    sign_in_with_role(app, email, password, "User").await
+}
+
+pub async fn sign_in_with_role(
+    app: &TestApp,
+    email: &str,
+    password: &str,
+    role: &str,
+) -> (Value, String) {
+    let user = create_user_with_role(app, email, password, role).await;
+    let mut authenticator = software_authenticator::SoftwareAuthenticator::new(RP_ID, ORIGIN);
+    register_passkey(app, &mut authenticator, email, password).await;
+
+    let (status, session) = login_with_passkey(app, &mut authenticator, email).await;
+    assert_eq!(status, StatusCode::OK, "{}", session);
+
+    let access_token = session["content"]["access_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    (user, access_token)
+}
+
+pub async fn register_passkey(
+    app: &Router,
+    authenticator: &mut software_authenticator::SoftwareAuthenticator,
+    email: &str,
+    password: &str,
+) {
+    let (status, options) = post_json(
+        app,
+        "/v1/passkeys/register/start",
+        json!({ "email": email, "password": password }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{}", options);
+
+    let credential = authenticator.register(&options["content"]["public_key"]);
+    let (status, body) = post_json(
+        app,
+        "/v1/passkeys/register/finish",
+        json!({ "ceremony_id": options["content"]["ceremony_id"], "credential": credential }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+}
+
+pub async fn login_with_passkey(
+    app: &Router,
+    authenticator: &mut software_authenticator::SoftwareAuthenticator,
+    email: &str,
+) -> (StatusCode, Value) {
+    let (status, options) =
+        post_json(app, "/v1/passkeys/login/start", json!({ "email": email })).await;
+    assert_eq!(status, StatusCode::OK, "{}", options);
+
+    let assertion = authenticator.authenticate(&options["content"]["public_key"]);
+    post_json(
+        app,
+        "/v1/passkeys/login/finish",
+        json!({ "ceremony_id": options["content"]["ceremony_id"], "credential": assertion }),
+    )
+    .await
+}
+
+pub async fn send_authorized(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let (status, _, body) = send_authorized_with_headers(app, method, uri, token, &[], body).await;
+    (status, body)
+}
+
+pub async fn send_authorized_with_headers(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    headers: &[(&str, &str)],
+    body: Option<Value>,
+) -> (StatusCode, HeaderMap, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("authorization", format!("Bearer {}", token))
+        .header("content-type", "application/json");
+
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+
+    let body = body
+        .map(|body| Body::from(body.to_string()))
+        .unwrap_or_else(Body::empty);
+
+    send(app, request.body(body).unwrap()).await
+}
+
+/// Lê o recurso para obter o ETag atual e repete a escrita com `If-Match`, como um cliente faria.
+pub async fn send_with_if_match(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    token: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let (status, headers, found) = send_authorized_with_headers(app, "GET", uri, token, &[], None).await;
+    assert_eq!(status, StatusCode::OK, "{}", found);
+    let etag = headers["etag"].to_str().unwrap().to_string();
+
+    let (status, _, body) =
+        send_authorized_with_headers(app, method, uri, token, &[("if-match", &etag)], body).await;
+    (status, body)
+}
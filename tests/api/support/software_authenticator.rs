@@ -0,0 +1,153 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ciborium::Value as CborValue;
+use ring::digest::{SHA256, digest};
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+use serde_json::{Value, json};
+
+/// Minimal CTAP-less authenticator: holds one P-256 credential and answers the
+/// registration and authentication ceremonies the way a browser would serialize them.
+pub struct SoftwareAuthenticator {
+    rp_id: String,
+    origin: String,
+    credential_id: Vec<u8>,
+    key_pair: EcdsaKeyPair,
+    sign_count: u32,
+    user_handle: Option<String>,
+    user_verified: bool,
+}
+
+impl SoftwareAuthenticator {
+    pub fn new(rp_id: &str, origin: &str) -> Self {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng)
+                .unwrap();
+
+        let mut credential_id = vec![0u8; 16];
+        rng.fill(&mut credential_id).unwrap();
+
+        Self {
+            rp_id: rp_id.to_string(),
+            origin: origin.to_string(),
+            credential_id,
+            key_pair,
+            sign_count: 0,
+            user_handle: None,
+            user_verified: true,
+        }
+    }
+
+    /// Simulates the browser reporting a different origin, as on a phishing page.
+    pub fn set_origin(&mut self, origin: &str) {
+        self.origin = origin.to_string();
+    }
+
+    /// Simulates an authenticator that only checks for a touch, without PIN or biometrics.
+    pub fn skip_user_verification(&mut self) {
+        self.user_verified = false;
+    }
+
+    pub fn credential_id(&self) -> String {
+        URL_SAFE_NO_PAD.encode(&self.credential_id)
+    }
+
+    /// Answers `navigator.credentials.create()` for the given `publicKey` options.
+    pub fn register(&mut self, public_key: &Value) -> Value {
+        self.user_handle = public_key["user"]["id"].as_str().map(str::to_string);
+
+        let client_data = self.client_data("webauthn.create", public_key["challenge"].as_str().unwrap());
+
+        let mut authenticator_data = self.authenticator_data_header(0x41);
+        authenticator_data.extend_from_slice(&[0u8; 16]);
+        authenticator_data.extend_from_slice(&(self.credential_id.len() as u16).to_be_bytes());
+        authenticator_data.extend_from_slice(&self.credential_id);
+        authenticator_data.extend_from_slice(&self.cose_public_key());
+
+        let attestation_object = CborValue::Map(vec![
+            (CborValue::Text("fmt".into()), CborValue::Text("none".into())),
+            (CborValue::Text("attStmt".into()), CborValue::Map(vec![])),
+            (CborValue::Text("authData".into()), CborValue::Bytes(authenticator_data)),
+        ]);
+
+        let mut encoded_attestation = Vec::new();
+        ciborium::into_writer(&attestation_object, &mut encoded_attestation).unwrap();
+
+        json!({
+            "id": self.credential_id(),
+            "rawId": self.credential_id(),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": URL_SAFE_NO_PAD.encode(client_data),
+                "attestationObject": URL_SAFE_NO_PAD.encode(encoded_attestation),
+            }
+        })
+    }
+
+    /// Answers `navigator.credentials.get()` for the given `publicKey` options.
+    pub fn authenticate(&mut self, public_key: &Value) -> Value {
+        self.sign_count += 1;
+
+        let client_data = self.client_data("webauthn.get", public_key["challenge"].as_str().unwrap());
+        // UP, plus UV when the user was verified
+        let flags = if self.user_verified { 0x05 } else { 0x01 };
+        let authenticator_data = self.authenticator_data_header(flags);
+
+        let mut signed_data = authenticator_data.clone();
+        signed_data.extend_from_slice(digest(&SHA256, &client_data).as_ref());
+
+        let signature = self
+            .key_pair
+            .sign(&SystemRandom::new(), &signed_data)
+            .unwrap();
+
+        json!({
+            "id": self.credential_id(),
+            "rawId": self.credential_id(),
+            "type": "public-key",
+            "response": {
+                "clientDataJSON": URL_SAFE_NO_PAD.encode(client_data),
+                "authenticatorData": URL_SAFE_NO_PAD.encode(authenticator_data),
+                "signature": URL_SAFE_NO_PAD.encode(signature.as_ref()),
+                "userHandle": self.user_handle,
+            }
+        })
+    }
+
+    fn client_data(&self, ceremony_type: &str, challenge: &str) -> Vec<u8> {
+        json!({
+            "type": ceremony_type,
+            "challenge": challenge,
+            "origin": self.origin,
+            "crossOrigin": false,
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn authenticator_data_header(&self, flags: u8) -> Vec<u8> {
+        let mut data = digest(&SHA256, self.rp_id.as_bytes()).as_ref().to_vec();
+        data.push(flags);
+        data.extend_from_slice(&self.sign_count.to_be_bytes());
+        data
+    }
+
+    fn cose_public_key(&self) -> Vec<u8> {
+        // Uncompressed SEC1 point: 0x04 || x || y
+        let point = self.key_pair.public_key().as_ref();
+
+        let key = CborValue::Map(vec![
+            (CborValue::Integer(1.into()), CborValue::Integer(2.into())),
+            (CborValue::Integer(3.into()), CborValue::Integer((-7).into())),
+            (CborValue::Integer((-1).into()), CborValue::Integer(1.into())),
+            (CborValue::Integer((-2).into()), CborValue::Bytes(point[1..33].to_vec())),
+            (CborValue::Integer((-3).into()), CborValue::Bytes(point[33..65].to_vec())),
+        ]);
+
+        let mut encoded = Vec::new();
+        ciborium::into_writer(&key, &mut encoded).unwrap();
+        encoded
+    }
+}
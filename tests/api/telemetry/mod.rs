@@ -0,0 +1 @@
+pub mod otlp_export_api_test;
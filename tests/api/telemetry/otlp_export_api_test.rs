@@ -0,0 +1,113 @@
+use crate::api::support::otlp_receiver::OtlpReceiver;
+use crate::api::support::{post_json_with_headers, test_app};
+use axum::http::StatusCode;
+use axum_api_starter::config::app_config::{LogConfig, LogFormat, TelemetryConfig};
+use axum_api_starter::util::{logging, telemetry};
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use serde_json::json;
+use tracing::Level;
+use tracing::subscriber::DefaultGuard;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const CALLER_SPAN_ID: &str = "00f067aa0ba902b7";
+
+    fn traceparent(sampled: bool) -> String {
+        format!(
+            "00-{}-{}-{}",
+            TRACE_ID,
+            CALLER_SPAN_ID,
+            if sampled { "01" } else { "00" }
+        )
+    }
+
+    // Exporta para o coletor em processo; o guard vale só para a thread do teste
+    fn install_exporter(
+        receiver: &OtlpReceiver,
+        sample_ratio: f64,
+    ) -> (SdkTracerProvider, DefaultGuard) {
+        let provider = telemetry::tracer_provider(&TelemetryConfig {
+            otlp_endpoint: Some(receiver.endpoint.clone()),
+            sample_ratio,
+            service_name: "starter-under-test".to_string(),
+        })
+        .unwrap()
+        .unwrap();
+
+        let log_config = LogConfig {
+            level: Level::INFO,
+            format: LogFormat::Text,
+        };
+        let subscriber = logging::subscriber(
+            &log_config,
+            std::io::sink,
+            Some(provider.tracer("starter-under-test")),
+        );
+
+        (provider, tracing::subscriber::set_default(subscriber))
+    }
+
+    async fn sign_up(email: &str, headers: &[(&str, &str)]) {
+        let app = test_app();
+        let (status, _) = post_json_with_headers(
+            &app,
+            "/v1/users",
+            json!({ "email": email, "password": "password123", "role": "User" }),
+            headers,
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    // --- Casos de Teste ---
+
+    // multi_thread: o force_flush bloqueia a thread do teste enquanto o coletor roda nas outras
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_export_spans_as_children_of_the_incoming_traceparent() {
+        let receiver = OtlpReceiver::start().await;
+        let (provider, _guard) = install_exporter(&receiver, 1.0);
+
+        sign_up("traced@example.com", &[("traceparent", &traceparent(true))]).await;
+        provider.force_flush().unwrap();
+
+        let request = receiver.span("request");
+        assert_eq!(request.service_name, "starter-under-test");
+        assert_eq!(request.trace_id, TRACE_ID);
+        assert_eq!(request.parent_span_id, CALLER_SPAN_ID);
+
+        // A cadeia request > create_user > insert_user fica no mesmo trace
+        let create_user = receiver.span("create_user");
+        let insert_user = receiver.span("insert_user");
+        assert_eq!(create_user.trace_id, TRACE_ID);
+        assert_eq!(create_user.parent_span_id, request.span_id);
+        assert_eq!(insert_user.parent_span_id, create_user.span_id);
+        assert_eq!(receiver.span("hash_password").parent_span_id, create_user.span_id);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_sample_new_traces_by_ratio_and_follow_the_caller_otherwise() {
+        let receiver = OtlpReceiver::start().await;
+        let (provider, _guard) = install_exporter(&receiver, 0.0);
+
+        // Sem traceparent a razão 0.0 descarta o trace novo
+        sign_up("unsampled@example.com", &[]).await;
+        provider.force_flush().unwrap();
+        assert!(receiver.spans().is_empty());
+
+        // O chamador decidiu não amostrar: a decisão é respeitada
+        sign_up("declined@example.com", &[("traceparent", &traceparent(false))]).await;
+        provider.force_flush().unwrap();
+        assert!(receiver.spans().is_empty());
+
+        // O chamador amostrou: o trace é exportado mesmo com razão 0.0
+        sign_up("sampled@example.com", &[("traceparent", &traceparent(true))]).await;
+        provider.force_flush().unwrap();
+        assert_eq!(receiver.span("request").trace_id, TRACE_ID);
+    }
+}
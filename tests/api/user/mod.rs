@@ -0,0 +1,4 @@
+pub mod user_api_test;
+pub mod user_status_api_test;
+pub mod user_deletion_api_test;
+pub mod user_concurrency_api_test;
@@ -0,0 +1,482 @@
+use crate::api::support::{
+    create_user, post_json, send, send_authorized, send_with_if_match, sign_in, sign_in_with_role,
+    test_app,
+};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use serde_json::{Value, json};
+use tokio::task::JoinSet;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_let_user_read_and_edit_own_account() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "self@example.com", "password123").await;
+        let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+
+        let (status, found) = send_authorized(&app, "GET", &uri, &token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", found);
+        assert_eq!(found["content"]["email"], "self@example.com");
+
+        let (status, updated) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            Some(json!({ "email": "renamed@example.com", "current_password": "password123" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", updated);
+        assert_eq!(updated["content"]["email"], "renamed@example.com");
+        assert_eq!(updated["content"]["created_at"], user["created_at"]);
+        assert_ne!(updated["content"]["updated_at"], user["updated_at"]);
+        assert!(updated["content"].get("password").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_require_current_password_to_change_own_credentials() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "owner@example.com", "password123").await;
+        let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+
+        // Um token vazado não basta para trocar senha ou email
+        let (status, body) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            Some(json!({ "password": "hijacked-password" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+
+        let (status, _) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            Some(json!({ "email": "attacker@example.com", "current_password": "guess" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, body) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            Some(json!({ "password": "password456", "current_password": "password123" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+
+        // Admins alterando outra conta não conhecem a senha dela
+        let (_, admin_token) =
+            sign_in_with_role(&app, "boss@example.com", "password123", "Admin").await;
+        let (status, body) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &admin_token,
+            Some(json!({ "email": "owner@example.org" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn should_not_let_user_touch_other_accounts_or_own_role() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "alice@example.com", "password123").await;
+        let (other, _) = sign_in(&app, "bob@example.com", "password123").await;
+        let other_uri = format!("/v1/users/{}", other["id"].as_str().unwrap());
+
+        let (status, _) = send_authorized(&app, "GET", &other_uri, &token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_authorized(
+            &app,
+            "PATCH",
+            &other_uri,
+            &token,
+            Some(json!({ "email": "stolen@example.com" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_authorized(&app, "DELETE", &other_uri, &token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = send_authorized(&app, "GET", "/v1/users", &token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        // Promover a si mesmo a admin não é permitido
+        let (status, _) = send_with_if_match(
+            &app,
+            "PATCH",
+            &format!("/v1/users/{}", user["id"].as_str().unwrap()),
+            &token,
+            Some(json!({ "role": "Admin" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn should_let_admin_manage_any_account() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let (user, user_token) = sign_in(&app, "managed@example.com", "password123").await;
+        let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+
+        let (status, listed) = send_authorized(&app, "GET", "/v1/users", &admin_token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", listed);
+        assert_eq!(listed["content"]["items"].as_array().unwrap().len(), 2);
+        assert_eq!(listed["content"]["has_more"], false);
+
+        let (status, updated) = send_with_if_match(
+            &app,
+            "PATCH",
+            &uri,
+            &admin_token,
+            Some(json!({ "role": "Admin" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", updated);
+        assert_eq!(updated["content"]["role"], "Admin");
+
+        let (status, deleted) = send_with_if_match(&app, "DELETE", &uri, &admin_token, None).await;
+        assert_eq!(status, StatusCode::OK, "{}", deleted);
+        assert_eq!(deleted["content"]["id"], user["id"]);
+
+        let (status, _) = send_authorized(&app, "GET", &uri, &admin_token, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // Tokens de um usuário removido deixam de valer
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", &user_token, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_admin_role_on_public_signup() {
+        let app = test_app();
+
+        let (status, body) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "intruder@example.com", "password": "password123", "role": "Admin" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+
+        // Nada foi criado: o mesmo email ainda pode se cadastrar como usuário comum
+        create_user(&app, "intruder@example.com", "password123").await;
+    }
+
+    #[tokio::test]
+    async fn should_reject_email_already_in_use() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "first@example.com", "password123").await;
+        sign_in(&app, "taken@example.com", "password123").await;
+
+        let (status, body) = send_with_if_match(
+            &app,
+            "PATCH",
+            &format!("/v1/users/{}", user["id"].as_str().unwrap()),
+            &token,
+            Some(json!({ "email": "taken@example.com", "current_password": "password123" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn should_require_write_scope_to_update() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "readonly@example.com", "password123").await;
+
+        let (_, created) = send_authorized(
+            &app,
+            "POST",
+            "/v1/users/me/tokens",
+            &token,
+            Some(json!({ "name": "reader", "scopes": ["users:read"], "expires_in_days": 1 })),
+        )
+        .await;
+        let read_only_token = created["content"]["token"].as_str().unwrap();
+        let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+
+        let (status, _) = send_authorized(&app, "GET", &uri, read_only_token, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_authorized(&app, "DELETE", &uri, read_only_token, None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn should_page_through_users_with_cursor() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        for index in 0..4 {
+            create_user(&app, &format!("page{}@example.com", index), "password123").await;
+        }
+
+        let mut seen: Vec<Value> = Vec::new();
+        let mut uri = "/v1/users?limit=2".to_string();
+        loop {
+            let (status, page) = send_authorized(&app, "GET", &uri, &admin_token, None).await;
+            assert_eq!(status, StatusCode::OK, "{}", page);
+
+            let items = page["content"]["items"].as_array().unwrap();
+            assert!(items.len() <= 2);
+            seen.extend(items.iter().map(|user| user["email"].clone()));
+
+            if !page["content"]["has_more"].as_bool().unwrap() {
+                assert!(page["content"]["next_cursor"].is_null());
+                break;
+            }
+            let cursor = page["content"]["next_cursor"].as_str().unwrap();
+            uri = format!("/v1/users?limit=2&cursor={}", cursor);
+        }
+
+        // Ordenado por created_at, sem repetições nem lacunas
+        assert_eq!(seen.len(), 5);
+        assert_eq!(seen[0], "admin@example.com");
+        assert_eq!(seen[4], "page3@example.com");
+    }
+
+    #[tokio::test]
+    async fn should_filter_and_sort_users() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        create_user(&app, "carol@example.com", "password123").await;
+        create_user(&app, "CAROLINE@example.com", "password123").await;
+        create_user(&app, "dave@example.com", "password123").await;
+
+        let (status, page) = send_authorized(
+            &app,
+            "GET",
+            "/v1/users?email_prefix=carol&sort=-email",
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", page);
+        assert_eq!(page["content"]["items"].as_array().unwrap().len(), 2);
+        assert_eq!(page["content"]["items"][0]["email"], "caroline@example.com");
+        assert_eq!(page["content"]["items"][1]["email"], "carol@example.com");
+
+        let (_, page) =
+            send_authorized(&app, "GET", "/v1/users?role=Admin", &admin_token, None).await;
+        assert_eq!(page["content"]["items"].as_array().unwrap().len(), 1);
+        assert_eq!(page["content"]["items"][0]["email"], "admin@example.com");
+
+        let (_, page) = send_authorized(
+            &app,
+            "GET",
+            "/v1/users?created_from=2999-01-01T00:00:00Z",
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(page["content"]["items"], json!([]));
+        assert_eq!(page["content"]["has_more"], false);
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_listing_parameters() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        create_user(&app, "other@example.com", "password123").await;
+
+        for uri in [
+            "/v1/users?sort=password",
+            "/v1/users?limit=0",
+            "/v1/users?limit=1000",
+            "/v1/users?cursor=not-a-cursor",
+        ] {
+            let (status, _) = send_authorized(&app, "GET", uri, &admin_token, None).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", uri);
+        }
+
+        // Um cursor emitido para uma ordenação não vale para outra
+        let (_, page) = send_authorized(&app, "GET", "/v1/users?limit=1", &admin_token, None).await;
+        let cursor = page["content"]["next_cursor"].as_str().unwrap();
+        let (status, _) = send_authorized(
+            &app,
+            "GET",
+            &format!("/v1/users?limit=1&sort=email&cursor={}", cursor),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_create_only_one_user_for_concurrent_signups() {
+        let app = test_app();
+
+        // Todos passam pela verificação prévia ao mesmo tempo; só o repositório pode desempatar
+        let mut signups = JoinSet::new();
+        for _ in 0..8 {
+            let app = app.clone();
+            signups.spawn(async move {
+                post_json(
+                    &app,
+                    "/v1/users",
+                    json!({ "email": "race@example.com", "password": "password123", "role": "User" }),
+                )
+                .await
+            });
+        }
+
+        let mut created = 0;
+        while let Some(result) = signups.join_next().await {
+            let (status, body) = result.unwrap();
+            match status {
+                StatusCode::CREATED => created += 1,
+                StatusCode::BAD_REQUEST => {
+                    assert_eq!(body["detail"], "User with this email already exists")
+                }
+                other => panic!("Unexpected status {}: {}", other, body),
+            }
+        }
+
+        assert_eq!(created, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_give_an_email_to_only_one_of_concurrent_updates() {
+        let app = test_app();
+
+        let mut accounts = Vec::new();
+        for index in 0..8 {
+            accounts
+                .push(sign_in(&app, &format!("racer{}@example.com", index), "password123").await);
+        }
+
+        // Cada usuário tenta assumir o mesmo email; o hash da senha nova alarga a janela entre a
+        // verificação prévia e a escrita, que todos atravessam juntos
+        let mut updates = JoinSet::new();
+        for (user, token) in accounts {
+            let app = app.clone();
+            updates.spawn(async move {
+                let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+                send_with_if_match(
+                    &app,
+                    "PATCH",
+                    &uri,
+                    &token,
+                    Some(json!({
+                        "email": "taken@example.com",
+                        "password": "password456",
+                        "current_password": "password123",
+                    })),
+                )
+                .await
+            });
+        }
+
+        let mut updated = 0;
+        while let Some(result) = updates.join_next().await {
+            let (status, body) = result.unwrap();
+            match status {
+                StatusCode::OK => updated += 1,
+                StatusCode::BAD_REQUEST => {
+                    assert_eq!(body["detail"], "User with this email already exists")
+                }
+                other => panic!("Unexpected status {}: {}", other, body),
+            }
+        }
+
+        assert_eq!(updated, 1);
+    }
+
+    #[tokio::test]
+    async fn should_normalize_email_and_reject_case_variants() {
+        let app = test_app();
+
+        let user = create_user(&app, "  Mixed.Case@Example.COM ", "password123").await;
+        assert_eq!(user["email"], "mixed.case@example.com");
+
+        let (status, body) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "MIXED.case@example.com", "password": "password123", "role": "User" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+        assert_eq!(body["detail"], "User with this email already exists");
+
+        // Emails inválidos são barrados na desserialização, antes de chegar ao serviço
+        let (status, _) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "not-an-email", "password": "password123", "role": "User" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn should_wrap_extractor_rejections_in_the_envelope() {
+        let app = test_app();
+        let (_, token) = sign_in(&app, "user@example.com", "password123").await;
+
+        let signup = |content_type: &str, body: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/v1/users")
+                .header("content-type", content_type)
+                .header("x-request-id", "rejection-1")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        for (request, expected_status, expected_name) in [
+            (
+                signup("application/json", "{\"email\":"),
+                StatusCode::BAD_REQUEST,
+                "BadRequest",
+            ),
+            (
+                signup(
+                    "application/json",
+                    r#"{"email":"not-an-email","password":"password123","role":"User"}"#,
+                ),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "UnprocessableEntity",
+            ),
+            (
+                signup("text/plain", "{}"),
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "UnsupportedMediaType",
+            ),
+        ] {
+            let (status, _, body) = send(&app, request).await;
+
+            assert_eq!(status, expected_status, "{}", body);
+            assert_eq!(body["status"], expected_name);
+            assert_eq!(body["request_id"], "rejection-1");
+            assert_eq!(body["content"]["error_type"], "ClientError");
+            assert!(!body["detail"].as_str().unwrap().is_empty());
+        }
+
+        // Query strings malformadas também voltam no envelope
+        let (status, body) =
+            send_authorized(&app, "GET", "/v1/users?limit=many", &token, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["status"], "BadRequest");
+        assert!(body["request_id"].is_string());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::api::support::{
+    send_authorized, send_authorized_with_headers, sign_in_with_role, test_app,
+};
+use axum::http::StatusCode;
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_expose_etag_and_require_if_match() {
+        let app = test_app();
+        let (admin, token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let uri = format!("/v1/users/{}", admin["id"].as_str().unwrap());
+
+        let (status, headers, _) =
+            send_authorized_with_headers(&app, "GET", &uri, &token, &[], None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers["etag"], "\"1\"");
+
+        let (status, _) = send_authorized(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            Some(json!({ "password": "new-password", "current_password": "password123" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PRECONDITION_REQUIRED);
+
+        let (status, headers, updated) = send_authorized_with_headers(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            &[("if-match", "\"1\"")],
+            Some(json!({ "password": "new-password", "current_password": "password123" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", updated);
+        assert_eq!(headers["etag"], "\"2\"");
+        assert_eq!(updated["content"]["version"], 2);
+
+        // ETag antigo não vale mais, nem para atualizar nem para remover
+        let (status, _, _) = send_authorized_with_headers(
+            &app,
+            "PATCH",
+            &uri,
+            &token,
+            &[("if-match", "\"1\"")],
+            Some(json!({ "password": "other-password" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+        let (status, _, _) = send_authorized_with_headers(
+            &app,
+            "DELETE",
+            &uri,
+            &token,
+            &[("if-match", "\"1\"")],
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+        let (status, _, _) = send_authorized_with_headers(
+            &app,
+            "DELETE",
+            &uri,
+            &token,
+            &[("if-match", "W/\"2\"")],
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+        let (status, _, _) = send_authorized_with_headers(
+            &app,
+            "DELETE",
+            &uri,
+            &token,
+            &[("if-match", "\"2\"")],
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_let_only_one_concurrent_edit_win() {
+        let app = test_app();
+        let (_, first_admin) =
+            sign_in_with_role(&app, "first@example.com", "password123", "Admin").await;
+        let (_, second_admin) =
+            sign_in_with_role(&app, "second@example.com", "password123", "Admin").await;
+        let (user, _) = sign_in_with_role(&app, "member@example.com", "password123", "User").await;
+        let uri = format!("/v1/users/{}", user["id"].as_str().unwrap());
+
+        // Os dois admins leram a mesma versão e gravam ao mesmo tempo
+        let headers = [("if-match", "\"1\"")];
+        let (first, second) = tokio::join!(
+            send_authorized_with_headers(
+                &app,
+                "PATCH",
+                &uri,
+                &first_admin,
+                &headers,
+                Some(json!({ "role": "Admin" })),
+            ),
+            send_authorized_with_headers(
+                &app,
+                "PATCH",
+                &uri,
+                &second_admin,
+                &headers,
+                Some(json!({ "password": "reset-by-second" })),
+            ),
+        );
+
+        let mut statuses = vec![first.0, second.0];
+        statuses.sort();
+        assert_eq!(
+            statuses,
+            vec![StatusCode::OK, StatusCode::PRECONDITION_FAILED]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::api::support::{
+    API_KEY, create_user, post_json, post_json_with_headers, send_authorized, send_with_if_match,
+    sign_in, sign_in_with_role, test_app, test_app_with_retention,
+};
+use axum::http::StatusCode;
+use axum_api_starter::util::retention::{EmailReusePolicy, RetentionConfig};
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_hide_deleted_user_and_restore_it() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let user = create_user(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        let (status, _) = send_with_if_match(
+            &app,
+            "DELETE",
+            &format!("/v1/users/{}", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        // O usuário removido some da leitura e da listagem
+        let (status, _) = send_authorized(
+            &app,
+            "GET",
+            &format!("/v1/users/{}", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (_, list) = send_authorized(&app, "GET", "/v1/users", &admin_token, None).await;
+        let items = list["content"]["items"].as_array().unwrap();
+        assert!(items.iter().all(|item| item["id"] != user_id));
+
+        let (status, restored) = send_authorized(
+            &app,
+            "POST",
+            &format!("/v1/users/{}/restore", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", restored);
+        assert_eq!(restored["content"]["id"], user_id);
+        assert!(restored["content"]["deleted_at"].is_null());
+
+        let (status, _) = send_authorized(
+            &app,
+            "GET",
+            &format!("/v1/users/{}", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_forbid_restore_for_non_admin() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        let (status, _) = send_authorized(
+            &app,
+            "POST",
+            &format!("/v1/users/{}/restore", user_id),
+            &token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn should_release_email_immediately_by_default() {
+        let app = test_app();
+        let (user, token) = sign_in(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        let (status, _) =
+            send_with_if_match(&app, "DELETE", &format!("/v1/users/{}", user_id), &token, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let recreated = create_user(&app, "member@example.com", "password123").await;
+        assert_ne!(recreated["id"], user_id);
+    }
+
+    #[tokio::test]
+    async fn should_reject_restore_after_window_and_purge() {
+        let app = test_app_with_retention(RetentionConfig {
+            restore_window_days: 0,
+            email_reuse: EmailReusePolicy::AfterPurge,
+        });
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let user = create_user(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        let (status, _) = send_with_if_match(
+            &app,
+            "DELETE",
+            &format!("/v1/users/{}", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Com AfterPurge o email continua reservado até o expurgo
+        let (status, _) = post_json(
+            &app,
+            "/v1/users",
+            json!({ "email": "member@example.com", "password": "password123", "role": "User" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = send_authorized(
+            &app,
+            "POST",
+            &format!("/v1/users/{}/restore", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = post_json(&app, "/v1/users/purge", json!({})).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, purged) =
+            post_json_with_headers(&app, "/v1/users/purge", json!({}), &[("x-api-key", API_KEY)])
+                .await;
+        assert_eq!(status, StatusCode::OK, "{}", purged);
+        assert_eq!(purged["content"]["purged_users"], 1);
+
+        let (status, _) = send_authorized(
+            &app,
+            "POST",
+            &format!("/v1/users/{}/restore", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        create_user(&app, "member@example.com", "password123").await;
+    }
+}
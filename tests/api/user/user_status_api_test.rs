@@ -0,0 +1,200 @@
+use crate::api::support::software_authenticator::SoftwareAuthenticator;
+use crate::api::support::{
+    ORIGIN, RP_ID, create_user, login_with_passkey, register_passkey, send_authorized, sign_in,
+    sign_in_with_role, test_app,
+};
+use axum::Router;
+use axum::http::StatusCode;
+use serde_json::{Value, json};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    async fn change_status(
+        app: &Router,
+        token: &str,
+        user_id: &str,
+        action: &str,
+        reason: &str,
+    ) -> (StatusCode, Value) {
+        send_authorized(
+            app,
+            "POST",
+            &format!("/v1/users/{}/{}", user_id, action),
+            token,
+            Some(json!({ "reason": reason })),
+        )
+        .await
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_suspend_and_reactivate_account() {
+        let app = test_app();
+        let (admin, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+
+        let user = create_user(&app, "member@example.com", "password123").await;
+        assert_eq!(user["status"], "Active");
+        let user_id = user["id"].as_str().unwrap();
+
+        let mut authenticator = SoftwareAuthenticator::new(RP_ID, ORIGIN);
+        register_passkey(
+            &app,
+            &mut authenticator,
+            "member@example.com",
+            "password123",
+        )
+        .await;
+        let (_, session) = login_with_passkey(&app, &mut authenticator, "member@example.com").await;
+        let user_token = session["content"]["access_token"].as_str().unwrap();
+
+        let (status, suspended) =
+            change_status(&app, &admin_token, user_id, "suspend", "Chargeback fraud").await;
+        assert_eq!(status, StatusCode::OK, "{}", suspended);
+        assert_eq!(suspended["content"]["status"], "Suspended");
+        assert_eq!(suspended["content"]["role"], "User");
+
+        // Tokens já emitidos deixam de valer e novos logins são recusados
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", user_token, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) =
+            login_with_passkey(&app, &mut authenticator, "member@example.com").await;
+        assert_eq!(status, StatusCode::FORBIDDEN, "{}", body);
+
+        let (status, reactivated) = change_status(
+            &app,
+            &admin_token,
+            user_id,
+            "reactivate",
+            "Dispute resolved",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", reactivated);
+        assert_eq!(reactivated["content"]["status"], "Active");
+
+        let (status, body) =
+            login_with_passkey(&app, &mut authenticator, "member@example.com").await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+
+        let (status, history) = send_authorized(
+            &app,
+            "GET",
+            &format!("/v1/users/{}/status-history", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", history);
+        let history = history["content"].as_array().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0]["from"], "Active");
+        assert_eq!(history[0]["to"], "Suspended");
+        assert_eq!(history[0]["reason"], "Chargeback fraud");
+        assert_eq!(history[0]["changed_by"], admin["id"]);
+        assert_eq!(history[1]["to"], "Active");
+    }
+
+    #[tokio::test]
+    async fn should_deactivate_and_reactivate_account() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let (user, user_token) = sign_in(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        let (status, deactivated) = change_status(
+            &app,
+            &admin_token,
+            user_id,
+            "deactivate",
+            "Closed at the customer's request",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", deactivated);
+        assert_eq!(deactivated["content"]["status"], "Deactivated");
+
+        // Conta desativada não autentica e não pode ser desativada de novo
+        let (status, _) = send_authorized(&app, "GET", "/v1/users/me", &user_token, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = change_status(&app, &admin_token, user_id, "deactivate", "Again").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = change_status(&app, &admin_token, user_id, "suspend", "Fraud").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, reactivated) =
+            change_status(&app, &admin_token, user_id, "reactivate", "Came back").await;
+        assert_eq!(status, StatusCode::OK, "{}", reactivated);
+        assert_eq!(reactivated["content"]["status"], "Active");
+
+        let (_, history) = send_authorized(
+            &app,
+            "GET",
+            &format!("/v1/users/{}/status-history", user_id),
+            &admin_token,
+            None,
+        )
+        .await;
+        let history = history["content"].as_array().unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0]["to"], "Deactivated");
+        assert_eq!(history[1]["from"], "Deactivated");
+    }
+
+    #[tokio::test]
+    async fn should_represent_suspended_admin() {
+        let app = test_app();
+        let (_, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let (other_admin, other_admin_token) =
+            sign_in_with_role(&app, "other-admin@example.com", "password123", "Admin").await;
+
+        let (status, suspended) = change_status(
+            &app,
+            &admin_token,
+            other_admin["id"].as_str().unwrap(),
+            "suspend",
+            "Left the company",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", suspended);
+        assert_eq!(suspended["content"]["role"], "Admin");
+        assert_eq!(suspended["content"]["status"], "Suspended");
+
+        let (status, _) = send_authorized(&app, "GET", "/v1/users", &other_admin_token, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_status_changes() {
+        let app = test_app();
+        let (admin, admin_token) =
+            sign_in_with_role(&app, "admin@example.com", "password123", "Admin").await;
+        let (user, user_token) = sign_in(&app, "member@example.com", "password123").await;
+        let user_id = user["id"].as_str().unwrap();
+
+        // Somente admins, nunca sobre a própria conta, e sempre com motivo
+        let (status, _) = change_status(&app, &user_token, user_id, "suspend", "Because").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let admin_id = admin["id"].as_str().unwrap();
+        let (status, _) = change_status(&app, &admin_token, admin_id, "suspend", "Oops").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = change_status(&app, &admin_token, user_id, "suspend", "  ").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = change_status(&app, &admin_token, user_id, "reactivate", "Noop").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = change_status(&app, &admin_token, "missing", "suspend", "Gone").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
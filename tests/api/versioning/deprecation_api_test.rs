@@ -0,0 +1,90 @@
+use crate::api::support::{get_json, send, test_app, test_app_with_deprecations};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use axum_api_starter::openapi::OPENAPI_PATH;
+use axum_api_starter::util::deprecation::parse_deprecations;
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    fn get(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_serve_the_api_only_under_its_version_prefix() {
+        let app = test_app();
+
+        let (status, _, _) = send(&app, get("/v1/users/me")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _, _) = send(&app, get("/users/me")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // Endpoints de operação e padrões como o JWKS ficam fora da versão
+        let (status, _, _) = send(&app, get("/.well-known/jwks.json")).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, headers, _) = send(&app, get("/health/live")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(headers.get("deprecation").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_send_deprecation_headers_on_deprecated_versions_and_routes() {
+        let app = test_app_with_deprecations(
+            parse_deprecations(
+                "/v1;deprecated=2026-10-01;sunset=2027-04-01;link=https://docs.example.com/v2, \
+                 /v1/users/{id}/status-history;deprecated=2026-06-01;sunset=2026-12-01",
+            )
+            .unwrap(),
+        );
+
+        // Também nas respostas de erro, que é o que um cliente quebrado vê
+        let (status, headers, _) = send(&app, get("/v1/users/me")).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(headers["deprecation"], "@1790812800");
+        assert_eq!(headers["sunset"], "Thu, 01 Apr 2027 00:00:00 GMT");
+        assert_eq!(
+            headers["link"],
+            "<https://docs.example.com/v2>; rel=\"deprecation\"; type=\"text/html\""
+        );
+
+        // A rota com sunset próprio usa a entrada mais específica
+        let (_, headers, _) = send(&app, get("/v1/users/abc/status-history")).await;
+        assert_eq!(headers["sunset"], "Tue, 01 Dec 2026 00:00:00 GMT");
+        assert!(headers.get("link").is_none());
+
+        let (_, headers, _) = send(&app, get("/health/ready")).await;
+        assert!(headers.get("deprecation").is_none());
+    }
+
+    #[tokio::test]
+    async fn should_count_deprecated_requests_and_flag_them_in_the_document() {
+        let app = test_app_with_deprecations(
+            parse_deprecations("/v1/users/me/tokens;deprecated=2026-10-01").unwrap(),
+        );
+        send(&app, get("/v1/users/me/tokens")).await;
+
+        let response = app.clone().oneshot(get("/metrics")).await.unwrap();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let metrics = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(metrics.lines().any(|line| {
+            line.starts_with("api_deprecated_requests_total{")
+                && line.contains("method=\"GET\"")
+                && line.contains("route=\"/v1/users/me/tokens\"")
+        }));
+
+        let (_, document) = get_json(&app, OPENAPI_PATH).await;
+        let tokens = &document["paths"]["/v1/users/me/tokens"];
+        assert_eq!(tokens["get"]["deprecated"], true);
+        assert_eq!(tokens["post"]["deprecated"], true);
+        assert!(document["paths"]["/v1/users/me"]["get"]["deprecated"].is_null());
+    }
+}
@@ -0,0 +1 @@
+pub mod deprecation_api_test;
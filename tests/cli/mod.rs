@@ -0,0 +1,2 @@
+pub mod user_command_test;
+pub mod serve_test;
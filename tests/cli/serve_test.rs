@@ -0,0 +1,173 @@
+use axum::Router;
+use axum::routing::get;
+use axum_api_starter::bootstrap::{Repositories, build_app_state};
+use axum_api_starter::cli::serve::{ShutdownTimings, serve};
+use axum_api_starter::config::app_config::{AppConfig, Profile};
+use axum_api_starter::router::create_router;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::key_ring::KeyRing;
+use axum_api_starter::util::shutdown::ShutdownSignal;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    struct RunningServer {
+        base_url: String,
+        shutdown: ShutdownSignal,
+        trigger: oneshot::Sender<()>,
+        server: JoinHandle<Result<(), String>>,
+    }
+
+    /// Sobe a aplicação real com uma rota lenta extra, trocando o sinal do sistema por um
+    /// canal que o teste dispara.
+    async fn start_server(slow_for: Duration, timings: ShutdownTimings) -> RunningServer {
+        let config = AppConfig::defaults(Profile::Test);
+        let repositories = Repositories::default();
+        let state = build_app_state(
+            &config,
+            KeyRing::new(config.token.clone(), SystemClock).unwrap(),
+            &repositories,
+        );
+        let shutdown = state.shutdown.clone();
+
+        let app: Router = create_router(state).route(
+            "/slow",
+            get(move || async move {
+                tokio::time::sleep(slow_for).await;
+                "done"
+            }),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (trigger, signal) = oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            listener,
+            app,
+            shutdown.clone(),
+            timings,
+            async move {
+                let _ = signal.await;
+            },
+        ));
+
+        RunningServer {
+            base_url,
+            shutdown,
+            trigger,
+            server,
+        }
+    }
+
+    fn timings(pre_drain_delay: Duration, drain_timeout: Duration) -> ShutdownTimings {
+        ShutdownTimings {
+            pre_drain_delay,
+            drain_timeout,
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_finish_in_flight_requests_and_refuse_new_ones_after_the_signal() {
+        let running = start_server(
+            Duration::from_millis(300),
+            timings(Duration::ZERO, Duration::from_secs(5)),
+        )
+        .await;
+        let client = reqwest::Client::new();
+
+        let ready = client
+            .get(format!("{}/health/ready", running.base_url))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(ready.status(), 200);
+
+        let in_flight = tokio::spawn(client.get(format!("{}/slow", running.base_url)).send());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        running.trigger.send(()).unwrap();
+
+        // A requisição iniciada antes do sinal termina normalmente
+        let response = in_flight.await.unwrap().unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), "done");
+
+        running.server.await.unwrap().unwrap();
+        assert!(running.shutdown.is_draining());
+
+        // O listener foi fechado, novas conexões são recusadas
+        let refused = reqwest::Client::new()
+            .get(format!("{}/health/live", running.base_url))
+            .send()
+            .await;
+        assert!(refused.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_stop_waiting_for_requests_after_the_drain_deadline() {
+        let running = start_server(
+            Duration::from_secs(30),
+            timings(Duration::ZERO, Duration::from_millis(100)),
+        )
+        .await;
+
+        let in_flight = tokio::spawn(
+            reqwest::Client::new()
+                .get(format!("{}/slow", running.base_url))
+                .send(),
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let started = Instant::now();
+        running.trigger.send(()).unwrap();
+        running.server.await.unwrap().unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        in_flight.abort();
+    }
+
+    #[tokio::test]
+    async fn should_fail_readiness_but_keep_serving_during_the_pre_drain_delay() {
+        let running = start_server(
+            Duration::ZERO,
+            timings(Duration::from_millis(500), Duration::from_secs(5)),
+        )
+        .await;
+        let client = reqwest::Client::new();
+
+        let started = Instant::now();
+        running.trigger.send(()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // O balanceador já vê "não pronto", mas conexões novas ainda são atendidas
+        let ready = client
+            .get(format!("{}/health/ready", running.base_url))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(ready.status(), 503);
+        let live = reqwest::Client::new()
+            .get(format!("{}/health/live", running.base_url))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(live.status(), 200);
+
+        running.server.await.unwrap().unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(500));
+
+        let refused = reqwest::Client::new()
+            .get(format!("{}/health/live", running.base_url))
+            .send()
+            .await;
+        assert!(refused.is_err());
+    }
+}
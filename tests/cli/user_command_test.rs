@@ -0,0 +1,164 @@
+use axum_api_starter::bootstrap::{
+    Repositories, build_create_user_service, build_set_user_role_service,
+};
+use axum_api_starter::cli::password::check_new_password;
+use axum_api_starter::cli::user_command::create_admin;
+use axum_api_starter::cli::{Cli, Command, MigrateAction, UserCommand, migrate};
+use axum_api_starter::config::app_config::{AppConfig, Profile};
+use axum_api_starter::model::email_model::Email;
+use axum_api_starter::model::user_model::{UserForCreation, UserRole};
+use axum_api_starter::service::user_service::create_user_service::CreateUserService;
+use axum_api_starter::service::user_service::set_user_role_service::SetUserRoleService;
+use axum_api_starter::util::app_error::ErrorType;
+use axum_api_starter::util::secret::Secret;
+use clap::Parser;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    fn email(raw: &str) -> Email {
+        Email::parse(raw).unwrap()
+    }
+
+    fn secret(value: &str) -> Secret<String> {
+        Secret::new(value.to_string())
+    }
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_parse_subcommands_and_default_to_serve() {
+        let cli = Cli::try_parse_from(["app"]).unwrap();
+        assert!(cli.command.is_none());
+
+        let cli = Cli::try_parse_from([
+            "app",
+            "user",
+            "set-role",
+            "--email",
+            "Ops@Example.com",
+            "--role",
+            "admin",
+        ])
+        .unwrap();
+        match cli.command {
+            Some(Command::User {
+                command: UserCommand::SetRole { email, role },
+            }) => {
+                assert_eq!(email.as_str(), "Ops@example.com");
+                assert_eq!(role, UserRole::Admin);
+            }
+            other => panic!("Expected user set-role, got {:?}", other),
+        }
+
+        assert!(
+            Cli::try_parse_from([
+                "app",
+                "user",
+                "set-role",
+                "--email",
+                "ops@example.com",
+                "--role",
+                "root"
+            ])
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(["app", "user", "create-admin", "--email", "not-an-email"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from(["app", "migrate", "sideways"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn should_create_admin_through_the_signup_service() {
+        let config = AppConfig::defaults(Profile::Test);
+        let repositories = Repositories::default();
+        let create_user_service = build_create_user_service(&config, &repositories);
+
+        let admin = create_admin(
+            &create_user_service,
+            email("Root@Example.com"),
+            secret("password123"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(admin.role, UserRole::Admin);
+        assert_eq!(admin.email.as_str(), "root@example.com");
+
+        // As mesmas regras de email do cadastro valem para admins
+        let error = create_admin(
+            &create_user_service,
+            email("root@example.com"),
+            secret("password123"),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error.error_type, ErrorType::ClientError));
+    }
+
+    #[tokio::test]
+    async fn should_set_role_of_existing_user() {
+        let config = AppConfig::defaults(Profile::Test);
+        let repositories = Repositories::default();
+        build_create_user_service(&config, &repositories)
+            .execute(UserForCreation {
+                email: email("member@example.com"),
+                password: secret("password123"),
+                role: UserRole::User,
+            })
+            .await
+            .unwrap();
+        let set_user_role_service = build_set_user_role_service(&config, &repositories);
+
+        let promoted = set_user_role_service
+            .execute(email("member@example.com"), UserRole::Admin)
+            .await
+            .unwrap();
+        assert_eq!(promoted.role, UserRole::Admin);
+        assert_eq!(promoted.version, 2);
+
+        // Repetir o mesmo papel não conta como alteração
+        let unchanged = set_user_role_service
+            .execute(email("member@example.com"), UserRole::Admin)
+            .await
+            .unwrap();
+        assert_eq!(unchanged.version, 2);
+
+        let error = set_user_role_service
+            .execute(email("nobody@example.com"), UserRole::Admin)
+            .await
+            .unwrap_err();
+        assert!(matches!(error.error_type, ErrorType::NotFound));
+    }
+
+    #[test]
+    fn should_reject_empty_or_mismatched_passwords() {
+        assert!(check_new_password(&secret("password123"), &secret("password123")).is_ok());
+        assert_eq!(
+            check_new_password(&secret(""), &secret("")).unwrap_err(),
+            "Password must not be empty"
+        );
+        assert_eq!(
+            check_new_password(&secret("password123"), &secret("password124")).unwrap_err(),
+            "Passwords do not match"
+        );
+    }
+
+    #[test]
+    fn should_report_nothing_to_migrate_for_in_memory_store() {
+        let config = AppConfig::defaults(Profile::Test);
+
+        assert_eq!(
+            migrate::run(&MigrateAction::Status, &config),
+            "in-memory store: 0 applied, 0 pending"
+        );
+        assert_eq!(
+            migrate::run(&MigrateAction::Up, &config),
+            "in-memory store: nothing to apply"
+        );
+    }
+}
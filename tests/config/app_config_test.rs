@@ -0,0 +1,336 @@
+use axum_api_starter::config::app_config::{AppConfig, LogFormat, Profile};
+use axum_api_starter::config::source::ConfigSources;
+use axum_api_starter::util::retention::EmailReusePolicy;
+use std::collections::HashMap;
+use tracing::Level;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    fn sources(file: Option<&str>, dotenv: &[(&str, &str)], env: &[(&str, &str)]) -> ConfigSources {
+        ConfigSources {
+            file: file.map(str::to_string),
+            dotenv: dotenv
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            env: env
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<HashMap<_, _>>(),
+        }
+    }
+
+    // URLs https e segredo de assinatura exigidos pelo perfil prod
+    const PROD_SETTINGS: &[(&str, &str)] = &[
+        ("SIGNING_KEY_SECRET", "0123456789abcdef0123456789abcdef"),
+        ("WEBAUTHN_ORIGIN", "https://app.example.com"),
+        ("OIDC_ISSUER_URL", "https://id.example.com"),
+        (
+            "OIDC_REDIRECT_URI",
+            "https://app.example.com/v1/auth/oidc/callback",
+        ),
+    ];
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_use_dev_profile_defaults_when_nothing_is_set() {
+        let config = AppConfig::from_sources(&ConfigSources::default()).unwrap();
+
+        assert_eq!(config.profile, Profile::Dev);
+        assert_eq!(config.server.address().to_string(), "0.0.0.0:3000");
+        assert_eq!(config.server.shutdown_timeout_seconds, 30);
+        assert_eq!(config.server.shutdown_delay_seconds, 0);
+        assert!(config.docs.ui_enabled);
+        assert_eq!(config.log.level, Level::DEBUG);
+        assert_eq!(config.log.format, LogFormat::Text);
+        assert_eq!(config.hashing.bcrypt_cost, 10);
+        assert!(config.database.url.is_none());
+    }
+
+    #[test]
+    fn should_apply_layers_in_increasing_precedence() {
+        let file = r#"
+            [server]
+            port = 4000
+
+            [log]
+            level = "warn"
+
+            [token]
+            issuer = "from-file"
+        "#;
+
+        let config = AppConfig::from_sources(&sources(
+            Some(file),
+            &[("SERVER_PORT", "5000"), ("LOG_LEVEL", "error")],
+            &[("SERVER_PORT", "6000")],
+        ))
+        .unwrap();
+
+        // env > .env > arquivo > padrão do perfil
+        assert_eq!(config.server.port, 6000);
+        assert_eq!(config.log.level, Level::ERROR);
+        assert_eq!(config.token.issuer, "from-file");
+        assert_eq!(config.token.access_token_ttl_seconds, 900);
+    }
+
+    #[test]
+    fn should_pick_profile_defaults_from_any_layer() {
+        let config =
+            AppConfig::from_sources(&sources(Some("profile = \"test\""), &[], &[])).unwrap();
+
+        assert_eq!(config.profile, Profile::Test);
+        assert_eq!(config.hashing.bcrypt_cost, 4);
+        assert_eq!(config.log.level, Level::WARN);
+    }
+
+    #[test]
+    fn should_report_every_problem_in_one_error() {
+        let file = r#"
+            [server]
+            prot = 4000
+        "#;
+
+        let error = AppConfig::from_sources(&sources(
+            Some(file),
+            &[("DELETED_EMAIL_REUSE", "never")],
+            &[
+                ("SERVER_PORT", "abc"),
+                ("BCRYPT_COST", "99"),
+                ("LOG_FORMAT", "xml"),
+            ],
+        ))
+        .unwrap_err();
+
+        assert_eq!(error.errors.len(), 5);
+        let message = error.to_string();
+        assert!(message.contains("unknown setting `server.prot`"));
+        assert!(message.contains("SERVER_PORT=\"abc\" from environment"));
+        assert!(message.contains("DELETED_EMAIL_REUSE=\"never\" from .env"));
+        assert!(message.contains("BCRYPT_COST must be between 4 and 31"));
+        assert!(message.contains("LOG_FORMAT=\"xml\" from environment: expected text or json"));
+    }
+
+    #[test]
+    fn should_enforce_prod_rules() {
+        // Os padrões apontam para localhost via http, o que o perfil prod recusa
+        let error =
+            AppConfig::from_sources(&sources(None, &[], &[("APP_PROFILE", "prod")])).unwrap_err();
+        assert_eq!(error.errors.len(), 4);
+        assert!(
+            error
+                .to_string()
+                .contains("WEBAUTHN_ORIGIN must use https in the prod profile")
+        );
+        assert!(
+            error
+                .to_string()
+                .contains("SIGNING_KEY_SECRET must be set in the prod profile")
+        );
+
+        let mut env = PROD_SETTINGS.to_vec();
+        env.push(("APP_PROFILE", "prod"));
+        env.push(("BCRYPT_COST", "8"));
+        let error = AppConfig::from_sources(&sources(None, &[], &env)).unwrap_err();
+        assert_eq!(
+            error.errors,
+            vec!["BCRYPT_COST must be at least 10 in the prod profile".to_string()]
+        );
+
+        env.pop();
+        let config = AppConfig::from_sources(&sources(None, &[], &env)).unwrap();
+        assert_eq!(config.profile, Profile::Prod);
+        assert_eq!(config.hashing.bcrypt_cost, 12);
+        assert_eq!(config.log.level, Level::INFO);
+        assert_eq!(config.log.format, LogFormat::Json);
+        assert!(!config.docs.ui_enabled);
+        assert_eq!(config.server.shutdown_delay_seconds, 5);
+        assert!(config.token.signing_secret.is_some());
+    }
+
+    #[test]
+    fn should_require_the_bootstrap_admin_email_and_password_together() {
+        let config = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[
+                ("BOOTSTRAP_ADMIN_EMAIL", "Ops@Example.com"),
+                ("BOOTSTRAP_ADMIN_PASSWORD", "password123"),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            config.bootstrap.admin_email.unwrap().as_str(),
+            "Ops@example.com"
+        );
+
+        let error = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[("BOOTSTRAP_ADMIN_EMAIL", "ops@example.com")],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error.errors,
+            vec![
+                "BOOTSTRAP_ADMIN_PASSWORD must be set together with BOOTSTRAP_ADMIN_EMAIL"
+                    .to_string()
+            ]
+        );
+
+        let error = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[("BOOTSTRAP_ADMIN_PASSWORD", "password123")],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error.errors,
+            vec![
+                "BOOTSTRAP_ADMIN_EMAIL must be set together with BOOTSTRAP_ADMIN_PASSWORD"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn should_reject_a_short_signing_secret() {
+        let error =
+            AppConfig::from_sources(&sources(None, &[], &[("SIGNING_KEY_SECRET", "too-short")]))
+                .unwrap_err();
+
+        assert_eq!(
+            error.errors,
+            vec!["SIGNING_KEY_SECRET must be at least 32 characters".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_parse_typed_values_and_clear_optional_ones() {
+        let file = r#"
+            api_key = "from-file"
+            id_strategy = "ulid"
+
+            [retention]
+            deleted_email_reuse = "after_purge"
+
+            [database]
+            url = "postgres://localhost/app"
+        "#;
+
+        let config = AppConfig::from_sources(&sources(
+            Some(file),
+            &[("API_KEY", "")],
+            &[("SERVER_HOST", "127.0.0.1")],
+        ))
+        .unwrap();
+
+        assert!(config.api_key.is_none());
+        assert_eq!(config.retention.email_reuse, EmailReusePolicy::AfterPurge);
+        assert_eq!(
+            config
+                .database
+                .url
+                .as_ref()
+                .map(|url| url.expose().as_str()),
+            Some("postgres://localhost/app")
+        );
+        assert_eq!(config.server.address().to_string(), "127.0.0.1:3000");
+    }
+
+    #[test]
+    fn should_lowercase_email_local_parts_unless_disabled() {
+        let config = AppConfig::from_sources(&ConfigSources::default()).unwrap();
+        assert!(config.email.lowercase_local_part);
+
+        let file = r#"
+            [email]
+            lowercase_local_part = false
+        "#;
+        let config = AppConfig::from_sources(&sources(Some(file), &[], &[])).unwrap();
+        assert!(!config.email.lowercase_local_part);
+
+        let error = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[("EMAIL_LOWERCASE_LOCAL_PART", "sometimes")],
+        ))
+        .unwrap_err();
+        assert!(error.to_string().contains("EMAIL_LOWERCASE_LOCAL_PART"));
+    }
+
+    #[test]
+    fn should_read_standard_opentelemetry_variables() {
+        let config = AppConfig::from_sources(&ConfigSources::default()).unwrap();
+        assert!(config.telemetry.otlp_endpoint.is_none());
+        assert_eq!(config.telemetry.sample_ratio, 1.0);
+
+        let config = AppConfig::from_sources(&sources(
+            Some("[telemetry]\nsample_ratio = 0.25"),
+            &[],
+            &[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", "http://collector:4318"),
+                ("OTEL_SERVICE_NAME", "users-api"),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            config.telemetry.otlp_endpoint.as_deref(),
+            Some("http://collector:4318")
+        );
+        assert_eq!(config.telemetry.sample_ratio, 0.25);
+        assert_eq!(config.telemetry.service_name, "users-api");
+
+        let error = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[
+                ("OTEL_EXPORTER_OTLP_ENDPOINT", "collector:4318"),
+                ("OTEL_TRACES_SAMPLER_ARG", "1.5"),
+            ],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error.errors,
+            vec![
+                "OTEL_TRACES_SAMPLER_ARG must be between 0.0 and 1.0".to_string(),
+                "OTEL_EXPORTER_OTLP_ENDPOINT must be an http or https URL".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_parse_api_deprecations() {
+        let config = AppConfig::from_sources(&ConfigSources::default()).unwrap();
+        assert!(config.api.deprecations.is_empty());
+
+        let file = r#"
+            [api]
+            deprecations = "/v1;deprecated=2026-10-01;sunset=2027-04-01, /v1/users/me/tokens;deprecated=2026-06-01"
+        "#;
+        let config = AppConfig::from_sources(&sources(Some(file), &[], &[])).unwrap();
+        let routes: Vec<&str> = config
+            .api
+            .deprecations
+            .iter()
+            .map(|deprecation| deprecation.route_prefix.as_str())
+            .collect();
+        assert_eq!(routes, vec!["/v1", "/v1/users/me/tokens"]);
+
+        let error = AppConfig::from_sources(&sources(
+            None,
+            &[],
+            &[("API_DEPRECATIONS", "/v1;sunset=2027-04-01")],
+        ))
+        .unwrap_err();
+        assert_eq!(error.errors.len(), 1);
+        assert!(
+            error.errors[0].contains("API_DEPRECATIONS=\"/v1;sunset=2027-04-01\" from environment")
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod app_config_test;
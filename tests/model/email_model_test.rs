@@ -0,0 +1,81 @@
+use axum_api_starter::model::email_model::{Email, EmailConfig};
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_trim_and_lowercase_only_the_domain() {
+        let email = Email::parse("  Foo.Bar@Example.COM ").unwrap();
+
+        assert_eq!(email.as_str(), "Foo.Bar@example.com");
+        assert_eq!(email, Email::parse("Foo.Bar@example.com").unwrap());
+    }
+
+    #[test]
+    fn should_lowercase_the_local_part_by_default() {
+        let config = EmailConfig::default();
+
+        let email = config.normalize(Email::parse("Foo.Bar@Example.COM").unwrap());
+
+        assert_eq!(email.as_str(), "foo.bar@example.com");
+        assert_eq!(
+            email,
+            config.normalize(Email::parse("foo.bar@example.com").unwrap())
+        );
+    }
+
+    #[test]
+    fn should_keep_the_local_part_when_lowercasing_is_disabled() {
+        let config = EmailConfig {
+            lowercase_local_part: false,
+        };
+
+        let email = config.normalize(Email::parse("Foo.Bar@Example.COM").unwrap());
+
+        assert_eq!(email.as_str(), "Foo.Bar@example.com");
+        assert_ne!(
+            email,
+            config.normalize(Email::parse("foo.bar@example.com").unwrap())
+        );
+    }
+
+    #[test]
+    fn should_encode_international_domain_as_punycode() {
+        let email = Email::parse("josé@Bücher.example").unwrap();
+
+        assert_eq!(email.as_str(), "josé@xn--bcher-kva.example");
+        assert_eq!(email, Email::parse("josé@xn--bcher-kva.example").unwrap());
+    }
+
+    #[test]
+    fn should_reject_malformed_addresses() {
+        for raw in [
+            "",
+            "no-at-sign.example.com",
+            "@example.com",
+            "user@",
+            "user@localhost",
+            "two@@example.com",
+            "with space@example.com",
+            "user@bad_domain.com",
+            "user@-example.com",
+        ] {
+            assert!(Email::parse(raw).is_err(), "{} should be rejected", raw);
+        }
+
+        let long_local_part = format!("{}@example.com", "a".repeat(65));
+        assert!(Email::parse(&long_local_part).is_err());
+    }
+
+    #[test]
+    fn should_validate_when_deserializing() {
+        let email: Email = serde_json::from_value(json!("Ana@Example.com")).unwrap();
+        assert_eq!(json!(email), json!("Ana@example.com"));
+
+        assert!(serde_json::from_value::<Email>(json!("not-an-email")).is_err());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod email_model_test;
+pub mod user_model_test;
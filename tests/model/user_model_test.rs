@@ -0,0 +1,48 @@
+use axum_api_starter::model::user_model::UserStatus;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    const STATUSES: [UserStatus; 4] = [
+        UserStatus::Active,
+        UserStatus::Suspended,
+        UserStatus::Deactivated,
+        UserStatus::PendingVerification,
+    ];
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_allow_only_the_documented_status_transitions() {
+        use UserStatus::*;
+
+        let allowed = [
+            (Active, Suspended),
+            (PendingVerification, Suspended),
+            (Active, Deactivated),
+            (Suspended, Deactivated),
+            (PendingVerification, Deactivated),
+            (Suspended, Active),
+            (Deactivated, Active),
+        ];
+
+        // Todos os pares, inclusive de um status para ele mesmo
+        for from in STATUSES {
+            for to in STATUSES {
+                let expected = allowed.contains(&(from.clone(), to.clone()));
+                assert_eq!(from.can_change_to(&to), expected, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn should_let_only_active_or_pending_users_sign_in() {
+        assert!(UserStatus::Active.allows_sign_in());
+        assert!(UserStatus::PendingVerification.allows_sign_in());
+        assert!(!UserStatus::Suspended.allows_sign_in());
+        assert!(!UserStatus::Deactivated.allows_sign_in());
+    }
+}
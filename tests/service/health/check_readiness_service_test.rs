@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use axum_api_starter::model::health_model::HealthStatus;
+use axum_api_starter::service::health_service::check_readiness_service::{
+    CheckReadinessService, CheckReadinessServiceImpl,
+};
+use axum_api_starter::util::health_check::HealthCheck;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Mocks ---
+
+    enum Behavior {
+        Succeed,
+        Fail(&'static str),
+        Hang,
+        Panic,
+    }
+
+    struct MockHealthCheck {
+        name: &'static str,
+        critical: bool,
+        behavior: Behavior,
+    }
+
+    #[async_trait]
+    impl HealthCheck for MockHealthCheck {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn critical(&self) -> bool {
+            self.critical
+        }
+
+        async fn check(&self) -> Result<(), String> {
+            match self.behavior {
+                Behavior::Succeed => Ok(()),
+                Behavior::Fail(error) => Err(error.to_string()),
+                Behavior::Hang => {
+                    tokio::time::sleep(Duration::from_secs(30)).await;
+                    Ok(())
+                }
+                Behavior::Panic => panic!("check blew up"),
+            }
+        }
+    }
+
+    // --- Helpers ---
+
+    fn check(name: &'static str, critical: bool, behavior: Behavior) -> Arc<dyn HealthCheck + Send + Sync> {
+        Arc::new(MockHealthCheck {
+            name,
+            critical,
+            behavior,
+        })
+    }
+
+    fn setup_service(health_checks: Vec<Arc<dyn HealthCheck + Send + Sync>>) -> CheckReadinessServiceImpl {
+        CheckReadinessServiceImpl {
+            health_checks,
+            timeout: Duration::from_millis(50),
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_be_up_when_every_check_passes() {
+        let service = setup_service(vec![
+            check("database", true, Behavior::Succeed),
+            check("mail_sender", false, Behavior::Succeed),
+        ]);
+
+        let readiness = service.execute().await;
+
+        assert_eq!(readiness.status, HealthStatus::Up);
+        assert_eq!(readiness.components.len(), 2);
+        assert!(readiness.components.iter().all(|c| c.status == HealthStatus::Up && c.error.is_none()));
+    }
+
+    #[tokio::test]
+    async fn should_only_go_down_for_critical_failures() {
+        let service = setup_service(vec![check("mail_sender", false, Behavior::Fail("smtp down"))]);
+        let readiness = service.execute().await;
+        assert_eq!(readiness.status, HealthStatus::Up);
+        assert_eq!(readiness.components[0].error.as_deref(), Some("smtp down"));
+
+        let service = setup_service(vec![check("database", true, Behavior::Fail("pool exhausted"))]);
+        let readiness = service.execute().await;
+        assert_eq!(readiness.status, HealthStatus::Down);
+    }
+
+    #[tokio::test]
+    async fn should_time_out_hanging_checks_concurrently() {
+        let service = setup_service(vec![
+            check("database", true, Behavior::Hang),
+            check("cache", true, Behavior::Hang),
+            check("mail_sender", false, Behavior::Succeed),
+        ]);
+
+        let started_at = Instant::now();
+        let readiness = service.execute().await;
+
+        // As verificações correm em paralelo: o custo total é um timeout, não dois
+        assert!(started_at.elapsed() < Duration::from_millis(1000));
+        assert_eq!(readiness.status, HealthStatus::Down);
+        let names: Vec<_> = readiness.components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["database", "cache", "mail_sender"]);
+        assert_eq!(readiness.components[0].error.as_deref(), Some("Timed out after 50 ms"));
+        assert_eq!(readiness.components[2].status, HealthStatus::Up);
+    }
+
+    #[tokio::test]
+    async fn should_report_a_panicking_check_as_down() {
+        let service = setup_service(vec![check("database", true, Behavior::Panic)]);
+
+        let readiness = service.execute().await;
+
+        assert_eq!(readiness.status, HealthStatus::Down);
+        assert_eq!(readiness.components[0].name, "database");
+        assert_eq!(readiness.components[0].error.as_deref(), Some("Check panicked"));
+    }
+}
@@ -0,0 +1,139 @@
+use axum_api_starter::bootstrap::{
+    BootstrapAdminOutcome, Repositories, bootstrap_admin, build_create_user_service,
+};
+use axum_api_starter::config::app_config::{AppConfig, BootstrapConfig, Profile};
+use axum_api_starter::model::email_model::Email;
+use axum_api_starter::model::user_model::{UserForCreation, UserRole};
+use axum_api_starter::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use axum_api_starter::service::user_service::create_user_service::CreateUserService;
+use axum_api_starter::util::encryptor::{BcryptEncryptor, Encryptor};
+use axum_api_starter::util::secret::Secret;
+use metrics_exporter_prometheus::PrometheusBuilder;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    fn email(raw: &str) -> Email {
+        Email::parse(raw).unwrap()
+    }
+
+    fn secret(value: &str) -> Secret<String> {
+        Secret::new(value.to_string())
+    }
+
+    fn setup_config(admin_email: Option<&str>, password: &str) -> AppConfig {
+        AppConfig {
+            bootstrap: BootstrapConfig {
+                admin_email: admin_email.map(email),
+                admin_password: admin_email.map(|_| secret(password)),
+            },
+            ..AppConfig::defaults(Profile::Test)
+        }
+    }
+
+    async fn create_member(repositories: &Repositories, raw_email: &str, password: &str) {
+        build_create_user_service(&AppConfig::defaults(Profile::Test), repositories)
+            .execute(UserForCreation {
+                email: email(raw_email),
+                password: secret(password),
+                role: UserRole::User,
+            })
+            .await
+            .unwrap();
+    }
+
+    fn verify_password(hash: &Secret<String>, password: &str) -> bool {
+        BcryptEncryptor { cost: 4 }
+            .verify(password, hash.expose())
+            .unwrap()
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_do_nothing_when_not_configured() {
+        let repositories = Repositories::default();
+
+        let outcome = bootstrap_admin(&setup_config(None, "password123"), &repositories)
+            .await
+            .unwrap();
+
+        assert!(matches!(outcome, BootstrapAdminOutcome::NotConfigured));
+    }
+
+    #[tokio::test]
+    async fn should_create_the_admin_once_across_restarts() {
+        let config = setup_config(Some("Ops@Example.com"), "password123");
+        let repositories = Repositories::default();
+
+        let admin = match bootstrap_admin(&config, &repositories).await.unwrap() {
+            BootstrapAdminOutcome::Created(admin) => admin,
+            other => panic!("Expected the admin to be created, got {:?}", other),
+        };
+        assert_eq!(admin.role, UserRole::Admin);
+        assert_eq!(admin.email.as_str(), "ops@example.com");
+
+        // Um novo `serve` sobre o mesmo store não duplica nem altera a conta
+        let outcome = bootstrap_admin(&config, &repositories).await.unwrap();
+        assert!(matches!(outcome, BootstrapAdminOutcome::EmailTaken));
+
+        let stored = repositories
+            .user
+            .execute(email("ops@example.com"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.id, admin.id);
+        assert_eq!(stored.version, admin.version);
+        assert!(verify_password(&stored.password, "password123"));
+    }
+
+    #[tokio::test]
+    async fn should_leave_an_existing_account_unchanged() {
+        let repositories = Repositories::default();
+        // Alguém se cadastrou com o email do admin antes do primeiro boot
+        create_member(&repositories, "ops@example.com", "member-password").await;
+
+        let outcome = bootstrap_admin(
+            &setup_config(Some("ops@example.com"), "bootstrap-password"),
+            &repositories,
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(outcome, BootstrapAdminOutcome::EmailTaken));
+        let stored = repositories
+            .user
+            .execute(email("ops@example.com"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.role, UserRole::User);
+        assert_eq!(stored.version, 1);
+        assert!(verify_password(&stored.password, "member-password"));
+    }
+
+    #[tokio::test]
+    async fn should_count_the_admin_as_a_signup() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        bootstrap_admin(
+            &setup_config(Some("ops@example.com"), "password123"),
+            &Repositories::default(),
+        )
+        .await
+        .unwrap();
+
+        // Passa pelo serviço de cadastro, e não direto pelo repositório
+        assert!(
+            handle
+                .render()
+                .contains("user_signups_total{outcome=\"created\"} 1")
+        );
+    }
+}
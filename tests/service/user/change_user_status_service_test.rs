@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use axum_api_starter::model::email_model::Email;
+use axum_api_starter::model::principal_model::{CredentialKind, Principal};
+use axum_api_starter::model::user_model::{User, UserId, UserPublic, UserRole, UserStatus};
+use axum_api_starter::model::user_status_model::{UserStatusChange, UserStatusChangeRequest};
+use axum_api_starter::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use axum_api_starter::repository::user_repository::update_user_repository::{
+    UpdateUserError, UpdateUserRepository,
+};
+use axum_api_starter::repository::user_status_history_repository::create_user_status_change_repository::CreateUserStatusChangeRepository;
+use axum_api_starter::service::user_service::change_user_status_service::{
+    ChangeUserStatusService, ChangeUserStatusServiceImpl,
+};
+use axum_api_starter::util::app_error::ErrorType;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::secret::Secret;
+use axum_api_starter::util::uuid_generator::UuidGenerator;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Mocks ---
+
+    pub struct MockFindUserByIdRepository {
+        pub users: HashMap<UserId, User>,
+    }
+
+    #[async_trait]
+    impl FindUserByIdRepository for MockFindUserByIdRepository {
+        async fn execute(&self, id: UserId) -> Result<Option<User>, String> {
+            Ok(self.users.get(&id).cloned())
+        }
+    }
+
+    // Simula outra requisição gravando entre a leitura e a escrita
+    pub struct MockStaleUpdateUserRepository;
+
+    #[async_trait]
+    impl UpdateUserRepository for MockStaleUpdateUserRepository {
+        async fn execute(
+            &self,
+            _user: User,
+            _expected_version: u64,
+        ) -> Result<Option<UserPublic>, UpdateUserError> {
+            Ok(None)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    pub struct MockCreateUserStatusChangeRepository {
+        pub changes: Arc<Mutex<Vec<UserStatusChange>>>,
+    }
+
+    #[async_trait]
+    impl CreateUserStatusChangeRepository for MockCreateUserStatusChangeRepository {
+        async fn execute(&self, change: UserStatusChange) -> Result<UserStatusChange, String> {
+            self.changes.lock().unwrap().push(change.clone());
+            Ok(change)
+        }
+    }
+
+    pub struct MockUuidGenerator;
+
+    impl UuidGenerator for MockUuidGenerator {
+        fn generate(&self) -> Result<String, String> {
+            Ok("change-1".to_string())
+        }
+    }
+
+    // --- Helpers ---
+
+    fn setup_user(id: &str, role: UserRole) -> User {
+        User {
+            id: UserId::from(id),
+            email: Email::parse(&format!("{}@example.com", id)).unwrap(),
+            password: Secret::new("hash".to_string()),
+            role,
+            status: UserStatus::Active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_return_precondition_failed_when_losing_the_version_race() {
+        let history = MockCreateUserStatusChangeRepository::default();
+        let service = ChangeUserStatusServiceImpl {
+            find_user_by_id_repository: Box::new(MockFindUserByIdRepository {
+                users: [
+                    setup_user("admin-1", UserRole::Admin),
+                    setup_user("user-1", UserRole::User),
+                ]
+                .into_iter()
+                .map(|user| (user.id.clone(), user))
+                .collect(),
+            }),
+            update_user_repository: Box::new(MockStaleUpdateUserRepository),
+            create_user_status_change_repository: Box::new(history.clone()),
+            uuid_generator: Box::new(MockUuidGenerator),
+            clock: Box::new(SystemClock),
+        };
+
+        let error = service
+            .execute(
+                Principal {
+                    user_id: Some(UserId::from("admin-1")),
+                    client_id: None,
+                    scopes: vec!["users:write".to_string()],
+                    credential: CredentialKind::AccessToken,
+                },
+                UserId::from("user-1"),
+                UserStatus::Suspended,
+                UserStatusChangeRequest {
+                    reason: "Chargeback".to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::PreconditionFailed));
+        // Nenhuma entrada de histórico para uma mudança que não aconteceu
+        assert!(history.changes.lock().unwrap().is_empty());
+    }
+}
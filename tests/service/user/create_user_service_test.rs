@@ -1,40 +1,71 @@
-use axum_api_starter::model::user_model::{User, UserForCreation, UserPublic, UserRole};
-use axum_api_starter::repository::user_repository::create_user_repository::CreateUserRepository;
+use crate::support::captured_logs::CapturedLogs;
+use axum_api_starter::model::email_model::{Email, EmailConfig};
+use axum_api_starter::model::user_model::{
+    User, UserForCreation, UserId, UserPublic, UserRole, UserStatus,
+};
+use axum_api_starter::repository::user_repository::create_user_repository::{
+    CreateUserError, CreateUserRepository,
+};
+use axum_api_starter::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
 use axum_api_starter::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
 use axum_api_starter::service::user_service::create_user_service::{
     CreateUserService, CreateUserServiceImpl,
 };
+use axum_api_starter::util::clock::FakeClock;
 use axum_api_starter::util::encryptor::Encryptor;
-use axum_api_starter::util::error::app_error::AppError;
+use axum_api_starter::util::retention::{EmailReusePolicy, RetentionConfig};
+use axum_api_starter::util::secret::Secret;
+use axum_api_starter::util::app_error::ErrorType;
 use axum_api_starter::util::uuid_generator::UuidGenerator;
-use chrono::Utc;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use metrics_exporter_prometheus::PrometheusBuilder;
 use async_trait::async_trait;
 
 #[cfg(test)]
 mod tests {
     use super::*; // Importa as definições do arquivo principal (models, service, etc.)
-    use tokio; // Necessário para rodar testes assíncronos
 
     // --- Mocks ---
 
     pub struct MockCreateUserRepository {
-        pub result: Result<UserPublic, String>,
+        pub result: Result<UserPublic, CreateUserError>,
     }
 
     #[async_trait]
     impl CreateUserRepository for MockCreateUserRepository {
-        async fn execute(&self, _user: User) -> Result<UserPublic, String> {
+        async fn execute(&self, _user: User) -> Result<UserPublic, CreateUserError> {
             self.result.clone()
         }
     }
 
+    // Devolve o próprio usuário recebido, para inspecionar os timestamps gerados pelo serviço
+    pub struct MockEchoCreateUserRepository;
+
+    #[async_trait]
+    impl CreateUserRepository for MockEchoCreateUserRepository {
+        async fn execute(&self, user: User) -> Result<UserPublic, CreateUserError> {
+            Ok(UserPublic::from(user))
+        }
+    }
+
     pub struct MockFindUserByEmailRepository {
         pub result: Result<Option<User>, String>,
     }
 
     #[async_trait]
     impl FindUserByEmailRepository for MockFindUserByEmailRepository {
-        async fn execute(&self, _email: String) -> Result<Option<User>, String> {
+        async fn execute(&self, _email: Email) -> Result<Option<User>, String> {
+            self.result.clone()
+        }
+    }
+
+    pub struct MockFindDeletedUserByEmailRepository {
+        pub result: Result<Option<User>, String>,
+    }
+
+    #[async_trait]
+    impl FindDeletedUserByEmailRepository for MockFindDeletedUserByEmailRepository {
+        async fn execute(&self, _email: Email) -> Result<Option<User>, String> {
             self.result.clone()
         }
     }
@@ -69,19 +100,57 @@ mod tests {
 
     fn setup_user_for_creation() -> UserForCreation {
         UserForCreation {
-            email: "test@example.com".to_string(),
-            password: "password123".to_string(),
+            email: Email::parse("test@example.com").unwrap(),
+            password: Secret::new("password123".to_string()),
             role: UserRole::User,
         }
     }
 
     fn setup_user_public(id: String) -> UserPublic {
         UserPublic {
-            id,
-            email: "test@example.com".to_string(),
+            id: UserId::from(id),
+            email: Email::parse("test@example.com").unwrap(),
             role: UserRole::User,
+            status: UserStatus::Active,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    fn setup_retention_config(email_reuse: EmailReusePolicy) -> RetentionConfig {
+        RetentionConfig {
+            restore_window_days: 30,
+            email_reuse,
+        }
+    }
+
+    fn frozen_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap()
+    }
+
+    fn setup_clock() -> FakeClock {
+        FakeClock::new(frozen_time())
+    }
+
+    fn setup_service_with_clock(clock: FakeClock) -> CreateUserServiceImpl {
+        CreateUserServiceImpl {
+            create_user_repository: Box::new(MockEchoCreateUserRepository),
+            find_user_by_email_repository: Box::new(MockFindUserByEmailRepository { result: Ok(None) }),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
+            encryptor: Box::new(MockEncryptor {
+                encrypt_result: Ok("mock_hashed_password".to_string()),
+                verify_result: Ok(true),
+            }),
+            uuid_generator: Box::new(MockUuidGenerator {
+                result: Ok("mock-uuid".to_string()),
+            }),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(clock),
         }
     }
 
@@ -113,8 +182,14 @@ mod tests {
         let service = CreateUserServiceImpl {
             create_user_repository: Box::new(create_repo_mock),
             find_user_by_email_repository: Box::new(find_repo_mock),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
             encryptor: Box::new(encryptor_mock),
             uuid_generator: Box::new(uuid_mock),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
         };
 
         // 3. Executar e Assert
@@ -130,17 +205,20 @@ mod tests {
     async fn should_return_bad_request_if_user_already_exists() {
         let user_creation_data = setup_user_for_creation();
         let existing_user = User {
-            id: "existing-id".to_string(),
+            id: UserId::from("existing-id"),
             email: user_creation_data.email.clone(),
-            password: "old-hash".to_string(),
+            password: Secret::new("old-hash".to_string()),
             role: UserRole::User,
+            status: UserStatus::Active,
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
         };
 
         // 1. Configurar Mocks para Falha (Usuário Existente)
         let create_repo_mock = MockCreateUserRepository {
-            result: Err("Should not be called".to_string()), // Não deve ser chamado
+            result: Err(CreateUserError::Storage("Should not be called".to_string())), // Não deve ser chamado
         };
         let find_repo_mock = MockFindUserByEmailRepository {
             result: Ok(Some(existing_user)), // Usuário JÁ existe!
@@ -157,8 +235,14 @@ mod tests {
         let service = CreateUserServiceImpl {
             create_user_repository: Box::new(create_repo_mock),
             find_user_by_email_repository: Box::new(find_repo_mock),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
             encryptor: Box::new(encryptor_mock),
             uuid_generator: Box::new(uuid_mock),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
         };
 
         // 3. Executar e Assert
@@ -166,10 +250,12 @@ mod tests {
 
         assert!(result.is_err());
         let error = result.unwrap_err();
-        // Verifica se o erro é o AppError::BadRequest correto
-        match error {
-            AppError::BadRequest(msg) => assert_eq!(msg, "Email already in use"),
-            _ => panic!("Expected BadRequest error, got {:?}", error),
+        // Verifica se o erro é o ClientError correto
+        match error.error_type {
+            ErrorType::ClientError => {
+                assert_eq!(error.detail, "User with this email already exists")
+            }
+            _ => panic!("Expected ClientError, got {:?}", error),
         }
     }
 
@@ -179,7 +265,7 @@ mod tests {
 
         // 1. Configurar Mocks para Falha (Encryptor)
         let create_repo_mock = MockCreateUserRepository {
-            result: Err("Should not be called".to_string()),
+            result: Err(CreateUserError::Storage("Should not be called".to_string())),
         };
         let find_repo_mock = MockFindUserByEmailRepository { result: Ok(None) };
         let encryptor_mock = MockEncryptor {
@@ -194,8 +280,14 @@ mod tests {
         let service = CreateUserServiceImpl {
             create_user_repository: Box::new(create_repo_mock),
             find_user_by_email_repository: Box::new(find_repo_mock),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
             encryptor: Box::new(encryptor_mock),
             uuid_generator: Box::new(uuid_mock),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
         };
 
         // 3. Executar e Assert
@@ -203,10 +295,10 @@ mod tests {
 
         assert!(result.is_err());
         let error = result.unwrap_err();
-        // Verifica se o erro é o AppError::Internal correto
-        match error {
-            AppError::Internal(msg) => assert!(msg.contains("Encryption error")),
-            _ => panic!("Expected Internal error, got {:?}", error),
+        // Verifica se o erro é o ServerError correto (o detalhe interno não é exposto)
+        match error.error_type {
+            ErrorType::ServerError => assert_eq!(error.detail, "Internal error"),
+            _ => panic!("Expected ServerError, got {:?}", error),
         }
     }
 
@@ -217,7 +309,7 @@ mod tests {
 
         // 1. Configurar Mocks para Falha (UuidGenerator)
         let create_repo_mock = MockCreateUserRepository {
-            result: Err("Should not be called".to_string()),
+            result: Err(CreateUserError::Storage("Should not be called".to_string())),
         };
         let find_repo_mock = MockFindUserByEmailRepository {
             result: Ok(None),
@@ -235,8 +327,14 @@ mod tests {
         let service = CreateUserServiceImpl {
             create_user_repository: Box::new(create_repo_mock),
             find_user_by_email_repository: Box::new(find_repo_mock),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
             encryptor: Box::new(encryptor_mock),
             uuid_generator: Box::new(uuid_mock),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
         };
 
         // 3. Executar e Assert
@@ -245,13 +343,13 @@ mod tests {
         assert!(result.is_err());
         let error = result.unwrap_err();
         
-        // Verifica se o erro é o AppError::Internal correto com a mensagem de UUID
-        match error {
-            AppError::Internal(msg) => {
-                assert!(msg.contains("UUID error"));
-                assert!(msg.contains(&uuid_error_message));
+        // Verifica se o erro é o ServerError correto, sem vazar a mensagem do gerador de UUID
+        match error.error_type {
+            ErrorType::ServerError => {
+                assert_eq!(error.detail, "Internal error");
+                assert!(!error.detail.contains(&uuid_error_message));
             }
-            _ => panic!("Expected Internal error for UUID, got {:?}", error),
+            _ => panic!("Expected ServerError for UUID, got {:?}", error),
         }
     }
 
@@ -263,7 +361,7 @@ mod tests {
         // 1. Configurar Mocks para Falha (CreateUserRepository)
         let create_repo_mock = MockCreateUserRepository {
             // FALHA aqui, simulando um erro do DB
-            result: Err(repo_error_message.clone()), 
+            result: Err(CreateUserError::Storage(repo_error_message.clone())),
         };
         let find_repo_mock = MockFindUserByEmailRepository {
             result: Ok(None), // Passa na verificação de email
@@ -280,8 +378,14 @@ mod tests {
         let service = CreateUserServiceImpl {
             create_user_repository: Box::new(create_repo_mock),
             find_user_by_email_repository: Box::new(find_repo_mock),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
             encryptor: Box::new(encryptor_mock),
             uuid_generator: Box::new(uuid_mock),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
         };
 
         // 3. Executar e Assert
@@ -290,13 +394,202 @@ mod tests {
         assert!(result.is_err());
         let error = result.unwrap_err();
         
-        // Verifica se o erro é o AppError::Internal correto com a mensagem do repositório
-        match error {
-            AppError::Internal(msg) => {
-                assert!(msg.contains("Repository error"));
-                assert!(msg.contains(&repo_error_message));
+        // Verifica se o erro é o ServerError correto, sem vazar a mensagem do repositório
+        match error.error_type {
+            ErrorType::ServerError => {
+                assert_eq!(error.detail, "Internal error");
+                assert!(!error.detail.contains(&repo_error_message));
+            }
+            _ => panic!("Expected ServerError for Repository failure, got {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_keep_email_of_deleted_user_reserved_until_purge() {
+        let user_creation_data = setup_user_for_creation();
+        let deleted_user = User {
+            id: UserId::from("deleted-id"),
+            email: user_creation_data.email.clone(),
+            password: Secret::new("old-hash".to_string()),
+            role: UserRole::User,
+            status: UserStatus::Active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: Some(Utc::now()),
+            version: 1,
+        };
+
+        // 1. Configurar Mocks: só existe um usuário removido (soft delete) com o mesmo email
+        let service = CreateUserServiceImpl {
+            create_user_repository: Box::new(MockCreateUserRepository {
+                result: Err(CreateUserError::Storage("Should not be called".to_string())),
+            }),
+            find_user_by_email_repository: Box::new(MockFindUserByEmailRepository { result: Ok(None) }),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(Some(deleted_user)),
+            }),
+            encryptor: Box::new(MockEncryptor {
+                encrypt_result: Ok("mock_hashed_password".to_string()),
+                verify_result: Ok(true),
+            }),
+            uuid_generator: Box::new(MockUuidGenerator {
+                result: Ok("mock-uuid".to_string()),
+            }),
+            retention_config: setup_retention_config(EmailReusePolicy::AfterPurge),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
+        };
+
+        // 2. Executar e Assert
+        let result = service.execute(user_creation_data).await;
+
+        let error = result.unwrap_err();
+        match error.error_type {
+            ErrorType::ClientError => {
+                assert_eq!(error.detail, "User with this email already exists")
             }
-            _ => panic!("Expected Internal error for Repository failure, got {:?}", error),
+            _ => panic!("Expected ClientError, got {:?}", error),
         }
     }
+
+    #[tokio::test]
+    async fn should_return_bad_request_when_repository_reports_duplicate_email() {
+        let user_creation_data = setup_user_for_creation();
+
+        // 1. Configurar Mocks: a verificação prévia passa, mas outro cadastro gravou o email antes
+        let service = CreateUserServiceImpl {
+            create_user_repository: Box::new(MockCreateUserRepository {
+                result: Err(CreateUserError::DuplicateEmail),
+            }),
+            find_user_by_email_repository: Box::new(MockFindUserByEmailRepository { result: Ok(None) }),
+            find_deleted_user_by_email_repository: Box::new(MockFindDeletedUserByEmailRepository {
+                result: Ok(None),
+            }),
+            encryptor: Box::new(MockEncryptor {
+                encrypt_result: Ok("mock_hashed_password".to_string()),
+                verify_result: Ok(true),
+            }),
+            uuid_generator: Box::new(MockUuidGenerator {
+                result: Ok("mock-uuid".to_string()),
+            }),
+            retention_config: setup_retention_config(EmailReusePolicy::Immediate),
+            email_config: EmailConfig::default(),
+            clock: Box::new(setup_clock()),
+        };
+
+        // 2. Executar e Assert
+        let error = service.execute(user_creation_data).await.unwrap_err();
+
+        match error.error_type {
+            ErrorType::ClientError => {
+                assert_eq!(error.detail, "User with this email already exists")
+            }
+            _ => panic!("Expected ClientError, got {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_store_the_email_as_the_local_part_setting_dictates() {
+        let user_for_creation = UserForCreation {
+            email: Email::parse("Ana.Silva@Example.com").unwrap(),
+            ..setup_user_for_creation()
+        };
+
+        let lowercased = setup_service_with_clock(setup_clock())
+            .execute(user_for_creation.clone())
+            .await
+            .unwrap();
+        assert_eq!(lowercased.email.as_str(), "ana.silva@example.com");
+
+        // Com a opção desligada só o domínio é normalizado
+        let service = CreateUserServiceImpl {
+            email_config: EmailConfig {
+                lowercase_local_part: false,
+            },
+            ..setup_service_with_clock(setup_clock())
+        };
+        let preserved = service.execute(user_for_creation).await.unwrap();
+        assert_eq!(preserved.email.as_str(), "Ana.Silva@example.com");
+    }
+
+    #[tokio::test]
+    async fn should_stamp_created_and_updated_at_with_the_same_frozen_instant() {
+        let service = setup_service_with_clock(setup_clock());
+
+        let created_user = service.execute(setup_user_for_creation()).await.unwrap();
+
+        assert_eq!(created_user.created_at, frozen_time());
+        assert_eq!(created_user.updated_at, frozen_time());
+    }
+
+    #[tokio::test]
+    async fn should_stamp_users_with_the_time_after_the_clock_advances() {
+        // O serviço recebe um clone; os clones compartilham o mesmo instante
+        let clock = setup_clock();
+        let service = setup_service_with_clock(clock.clone());
+
+        let first_user = service.execute(setup_user_for_creation()).await.unwrap();
+        clock.advance(Duration::hours(1));
+        let second_user = service.execute(setup_user_for_creation()).await.unwrap();
+
+        assert_eq!(first_user.created_at, frozen_time());
+        assert_eq!(second_user.created_at, frozen_time() + Duration::hours(1));
+        assert_eq!(second_user.updated_at, second_user.created_at);
+    }
+
+    #[tokio::test]
+    async fn should_log_storage_failure_inside_the_insert_span() {
+        let (logs, _guard) = CapturedLogs::install();
+
+        let service = CreateUserServiceImpl {
+            create_user_repository: Box::new(MockCreateUserRepository {
+                result: Err(CreateUserError::Storage("Database connection failed".to_string())),
+            }),
+            ..setup_service_with_clock(setup_clock())
+        };
+
+        let result = service.execute(setup_user_for_creation()).await;
+        assert!(result.is_err());
+
+        // O detalhe escondido do cliente aparece no log, com a cadeia de spans até o insert
+        let line = logs.find("Internal error");
+        assert_eq!(line["level"], "ERROR");
+        assert_eq!(line["detail"], "Database connection failed");
+        let spans: Vec<_> = line["spans"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|span| span["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(spans, vec!["create_user", "insert_user"]);
+    }
+
+    #[tokio::test]
+    async fn should_count_only_taken_emails_as_duplicate_signups() {
+        // Recorder local à thread do teste, para não disputar o global com os testes de API
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let duplicate = CreateUserServiceImpl {
+            create_user_repository: Box::new(MockCreateUserRepository {
+                result: Err(CreateUserError::DuplicateEmail),
+            }),
+            ..setup_service_with_clock(setup_clock())
+        };
+        let failing = CreateUserServiceImpl {
+            create_user_repository: Box::new(MockCreateUserRepository {
+                result: Err(CreateUserError::Storage("Database connection failed".to_string())),
+            }),
+            ..setup_service_with_clock(setup_clock())
+        };
+
+        duplicate.execute(setup_user_for_creation()).await.unwrap_err();
+        failing.execute(setup_user_for_creation()).await.unwrap_err();
+        failing.execute(setup_user_for_creation()).await.unwrap_err();
+
+        let rendered = handle.render();
+        assert!(rendered.contains("user_signups_total{outcome=\"duplicate\"} 1"));
+        assert!(rendered.contains("user_signups_total{outcome=\"error\"} 2"));
+    }
 }
@@ -1 +1,5 @@
-pub mod create_user_service_test;
\ No newline at end of file
+pub mod change_user_status_service_test;
+pub mod create_user_service_test;
+pub mod update_user_service_test;
+pub mod bootstrap_admin_service_test;
+pub mod purge_deleted_users_service_test;
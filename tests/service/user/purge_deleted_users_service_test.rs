@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use axum_api_starter::model::user_model::UserId;
+use axum_api_starter::repository::oidc_identity_repository::delete_oidc_identities_by_user_id_repository::DeleteOidcIdentitiesByUserIdRepository;
+use axum_api_starter::repository::passkey_repository::delete_passkeys_by_user_id_repository::DeletePasskeysByUserIdRepository;
+use axum_api_starter::repository::personal_access_token_repository::delete_personal_access_tokens_by_user_id_repository::DeletePersonalAccessTokensByUserIdRepository;
+use axum_api_starter::repository::user_repository::find_users_to_purge_repository::FindUsersToPurgeRepository;
+use axum_api_starter::repository::user_repository::purge_deleted_user_repository::PurgeDeletedUserRepository;
+use axum_api_starter::service::user_service::purge_deleted_users_service::{
+    PurgeDeletedUsersService, PurgeDeletedUsersServiceImpl,
+};
+use axum_api_starter::util::app_error::ErrorType;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::retention::{EmailReusePolicy, RetentionConfig};
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Mocks ---
+
+    /// Registra cada exclusão como "<repositório>:<usuário>", na ordem em que acontece.
+    #[derive(Clone, Default)]
+    pub struct MockStore {
+        pub calls: Arc<Mutex<Vec<String>>>,
+        pub failing_call: Option<String>,
+    }
+
+    impl MockStore {
+        fn record(&self, repository: &str, user_id: &UserId) -> Result<(), String> {
+            let call = format!("{}:{}", repository, user_id);
+
+            if self.failing_call.as_deref() == Some(call.as_str()) {
+                return Err(format!("{} unavailable", repository));
+            }
+
+            self.calls.lock().unwrap().push(call);
+            Ok(())
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    pub struct MockFindUsersToPurgeRepository {
+        pub user_ids: Vec<UserId>,
+    }
+
+    #[async_trait]
+    impl FindUsersToPurgeRepository for MockFindUsersToPurgeRepository {
+        async fn execute(&self, _deleted_before: DateTime<Utc>) -> Result<Vec<UserId>, String> {
+            Ok(self.user_ids.clone())
+        }
+    }
+
+    #[async_trait]
+    impl DeletePasskeysByUserIdRepository for MockStore {
+        async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+            self.record("passkeys", &user_id).map(|_| 1)
+        }
+    }
+
+    #[async_trait]
+    impl DeletePersonalAccessTokensByUserIdRepository for MockStore {
+        async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+            self.record("tokens", &user_id).map(|_| 1)
+        }
+    }
+
+    #[async_trait]
+    impl DeleteOidcIdentitiesByUserIdRepository for MockStore {
+        async fn execute(&self, user_id: UserId) -> Result<usize, String> {
+            self.record("oidc", &user_id).map(|_| 1)
+        }
+    }
+
+    #[async_trait]
+    impl PurgeDeletedUserRepository for MockStore {
+        async fn execute(&self, user_id: UserId) -> Result<bool, String> {
+            self.record("user", &user_id).map(|_| true)
+        }
+    }
+
+    // --- Helpers ---
+
+    fn setup_service(user_ids: &[&str], store: &MockStore) -> PurgeDeletedUsersServiceImpl {
+        PurgeDeletedUsersServiceImpl {
+            find_users_to_purge_repository: Box::new(MockFindUsersToPurgeRepository {
+                user_ids: user_ids.iter().map(|id| UserId::from(*id)).collect(),
+            }),
+            purge_deleted_user_repository: Box::new(store.clone()),
+            delete_passkeys_by_user_id_repository: Box::new(store.clone()),
+            delete_personal_access_tokens_by_user_id_repository: Box::new(store.clone()),
+            delete_oidc_identities_by_user_id_repository: Box::new(store.clone()),
+            retention_config: RetentionConfig {
+                restore_window_days: 30,
+                email_reuse: EmailReusePolicy::Immediate,
+            },
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_delete_credentials_before_purging_each_user() {
+        let store = MockStore::default();
+
+        let result = setup_service(&["user-1"], &store).execute().await.unwrap();
+
+        assert_eq!(result.purged_users, 1);
+        assert_eq!(
+            store.calls(),
+            [
+                "passkeys:user-1",
+                "tokens:user-1",
+                "oidc:user-1",
+                "user:user-1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_user_when_deleting_a_credential_fails() {
+        let store = MockStore {
+            failing_call: Some("tokens:user-2".to_string()),
+            ..MockStore::default()
+        };
+
+        let error = setup_service(&["user-1", "user-2", "user-3"], &store)
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::ServerError));
+        // user-2 continua soft-deleted e volta na próxima execução; user-3 nem foi tocado
+        assert_eq!(
+            store.calls(),
+            [
+                "passkeys:user-1",
+                "tokens:user-1",
+                "oidc:user-1",
+                "user:user-1",
+                "passkeys:user-2",
+            ]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use async_trait::async_trait;
+use axum_api_starter::model::email_model::{Email, EmailConfig};
+use axum_api_starter::model::principal_model::{CredentialKind, Principal};
+use axum_api_starter::model::user_model::{
+    User, UserForUpdate, UserId, UserPublic, UserRole, UserStatus,
+};
+use axum_api_starter::repository::user_repository::find_deleted_user_by_email_repository::FindDeletedUserByEmailRepository;
+use axum_api_starter::repository::user_repository::find_user_by_email_repository::FindUserByEmailRepository;
+use axum_api_starter::repository::user_repository::find_user_by_id_repository::FindUserByIdRepository;
+use axum_api_starter::repository::user_repository::update_user_repository::{
+    UpdateUserError, UpdateUserRepository,
+};
+use axum_api_starter::service::user_service::update_user_service::{
+    UpdateUserService, UpdateUserServiceImpl,
+};
+use axum_api_starter::util::app_error::ErrorType;
+use axum_api_starter::util::clock::SystemClock;
+use axum_api_starter::util::encryptor::Encryptor;
+use axum_api_starter::util::retention::{EmailReusePolicy, RetentionConfig};
+use axum_api_starter::util::secret::Secret;
+use chrono::{Duration, Utc};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Mocks ---
+
+    pub struct MockFindUserByIdRepository {
+        pub users: HashMap<UserId, User>,
+    }
+
+    #[async_trait]
+    impl FindUserByIdRepository for MockFindUserByIdRepository {
+        async fn execute(&self, id: UserId) -> Result<Option<User>, String> {
+            Ok(self.users.get(&id).cloned())
+        }
+    }
+
+    pub struct MockFindUserByEmailRepository {
+        pub result: Result<Option<User>, String>,
+    }
+
+    #[async_trait]
+    impl FindUserByEmailRepository for MockFindUserByEmailRepository {
+        async fn execute(&self, _email: Email) -> Result<Option<User>, String> {
+            self.result.clone()
+        }
+    }
+
+    #[async_trait]
+    impl FindDeletedUserByEmailRepository for MockFindUserByEmailRepository {
+        async fn execute(&self, _email: Email) -> Result<Option<User>, String> {
+            Ok(None)
+        }
+    }
+
+    pub struct MockUpdateUserRepository {
+        pub current_version: u64,
+    }
+
+    #[async_trait]
+    impl UpdateUserRepository for MockUpdateUserRepository {
+        async fn execute(
+            &self,
+            user: User,
+            expected_version: u64,
+        ) -> Result<Option<UserPublic>, UpdateUserError> {
+            if expected_version != self.current_version {
+                return Ok(None);
+            }
+
+            Ok(Some(UserPublic::from(User {
+                version: expected_version + 1,
+                ..user
+            })))
+        }
+    }
+
+    pub struct MockEncryptor;
+
+    impl Encryptor for MockEncryptor {
+        fn encrypt(&self, data: &str) -> Result<String, String> {
+            Ok(format!("hashed:{}", data))
+        }
+
+        fn verify(&self, _data: &str, _hash: &str) -> Result<bool, String> {
+            Ok(true)
+        }
+    }
+
+    // --- Helpers ---
+
+    fn setup_user(id: &str, role: UserRole) -> User {
+        let created_at = Utc::now() - Duration::days(1);
+
+        User {
+            id: UserId::from(id),
+            email: Email::parse(&format!("{}@example.com", id)).unwrap(),
+            password: Secret::new("old-hash".to_string()),
+            role,
+            status: UserStatus::Active,
+            created_at,
+            updated_at: created_at,
+            deleted_at: None,
+            version: 1,
+        }
+    }
+
+    fn setup_principal(user_id: &str) -> Principal {
+        Principal {
+            user_id: Some(UserId::from(user_id)),
+            client_id: None,
+            scopes: vec!["users:write".to_string()],
+            credential: CredentialKind::AccessToken,
+        }
+    }
+
+    fn setup_if_match() -> Option<String> {
+        Some("\"1\"".to_string())
+    }
+
+    fn setup_service(users: Vec<User>) -> UpdateUserServiceImpl {
+        UpdateUserServiceImpl {
+            find_user_by_id_repository: Box::new(MockFindUserByIdRepository {
+                users: users
+                    .into_iter()
+                    .map(|user| (user.id.clone(), user))
+                    .collect(),
+            }),
+            find_user_by_email_repository: Box::new(MockFindUserByEmailRepository {
+                result: Ok(None),
+            }),
+            find_deleted_user_by_email_repository: Box::new(MockFindUserByEmailRepository {
+                result: Ok(None),
+            }),
+            update_user_repository: Box::new(MockUpdateUserRepository { current_version: 1 }),
+            encryptor: Box::new(MockEncryptor),
+            retention_config: RetentionConfig {
+                restore_window_days: 30,
+                email_reuse: EmailReusePolicy::Immediate,
+            },
+            email_config: EmailConfig::default(),
+            clock: Box::new(SystemClock),
+        }
+    }
+
+    // --- Casos de Teste ---
+
+    #[tokio::test]
+    async fn should_keep_updated_at_when_nothing_changes() {
+        let user = setup_user("user-1", UserRole::User);
+        let service = setup_service(vec![user.clone()]);
+
+        let update = UserForUpdate {
+            email: Some(user.email.clone()),
+            ..UserForUpdate::default()
+        };
+
+        let result = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                setup_if_match(),
+                update,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.updated_at, user.updated_at);
+    }
+
+    #[tokio::test]
+    async fn should_bump_updated_at_and_hash_new_password() {
+        let user = setup_user("user-1", UserRole::User);
+        let service = setup_service(vec![user.clone()]);
+
+        let update = UserForUpdate {
+            password: Some(Secret::new("new-password".to_string())),
+            current_password: Some(Secret::new("old-password".to_string())),
+            ..UserForUpdate::default()
+        };
+
+        let result = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                setup_if_match(),
+                update,
+            )
+            .await
+            .unwrap();
+
+        assert!(result.updated_at > user.updated_at);
+        assert_eq!(result.created_at, user.created_at);
+    }
+
+    #[tokio::test]
+    async fn should_forbid_role_change_by_non_admin() {
+        let service = setup_service(vec![setup_user("user-1", UserRole::User)]);
+
+        let update = UserForUpdate {
+            role: Some(UserRole::Admin),
+            ..UserForUpdate::default()
+        };
+
+        let error = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                setup_if_match(),
+                update,
+            )
+            .await
+            .unwrap_err();
+
+        match error.error_type {
+            ErrorType::Forbidden => assert_eq!(error.detail, "Only admins can change roles"),
+            _ => panic!("Expected Forbidden, got {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_let_admin_edit_another_user() {
+        let service = setup_service(vec![
+            setup_user("admin-1", UserRole::Admin),
+            setup_user("user-1", UserRole::User),
+        ]);
+
+        let update = UserForUpdate {
+            role: Some(UserRole::Admin),
+            ..UserForUpdate::default()
+        };
+
+        let result = service
+            .execute(
+                setup_principal("admin-1"),
+                UserId::from("user-1"),
+                setup_if_match(),
+                update,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.id.as_str(), "user-1");
+        assert_eq!(result.role, UserRole::Admin);
+    }
+
+    #[tokio::test]
+    async fn should_forbid_editing_another_user() {
+        let service = setup_service(vec![
+            setup_user("user-1", UserRole::User),
+            setup_user("user-2", UserRole::User),
+        ]);
+
+        let error = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-2"),
+                setup_if_match(),
+                UserForUpdate::default(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::Forbidden));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_for_missing_user() {
+        let service = setup_service(vec![setup_user("admin-1", UserRole::Admin)]);
+
+        let error = service
+            .execute(
+                setup_principal("admin-1"),
+                UserId::from("missing"),
+                setup_if_match(),
+                UserForUpdate::default(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::NotFound));
+    }
+
+    #[tokio::test]
+    async fn should_require_if_match() {
+        let service = setup_service(vec![setup_user("user-1", UserRole::User)]);
+
+        let error = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                None,
+                UserForUpdate::default(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::PreconditionRequired));
+    }
+
+    #[tokio::test]
+    async fn should_reject_stale_if_match() {
+        let service = setup_service(vec![setup_user("user-1", UserRole::User)]);
+
+        let error = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                Some("\"0\"".to_string()),
+                UserForUpdate::default(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::PreconditionFailed));
+    }
+
+    #[tokio::test]
+    async fn should_reject_update_when_version_changes_before_write() {
+        // Outra requisição gravou entre a leitura e a escrita: o repositório já está na versão 2
+        let mut service = setup_service(vec![setup_user("user-1", UserRole::User)]);
+        service.update_user_repository = Box::new(MockUpdateUserRepository { current_version: 2 });
+
+        let update = UserForUpdate {
+            password: Some(Secret::new("new-password".to_string())),
+            current_password: Some(Secret::new("old-password".to_string())),
+            ..UserForUpdate::default()
+        };
+
+        let error = service
+            .execute(
+                setup_principal("user-1"),
+                UserId::from("user-1"),
+                setup_if_match(),
+                update,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error.error_type, ErrorType::PreconditionFailed));
+    }
+}
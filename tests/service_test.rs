@@ -1,4 +1,5 @@
 mod service;
+mod support;
 
 #[cfg(test)]
 mod tests {
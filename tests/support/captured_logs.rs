@@ -0,0 +1,63 @@
+use axum_api_starter::config::app_config::{LogConfig, LogFormat};
+use axum_api_starter::util::logging;
+use serde_json::Value;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing::Level;
+use tracing::subscriber::DefaultGuard;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Coleta as linhas JSON emitidas pelo subscriber da aplicação enquanto o guard estiver vivo.
+#[derive(Clone, Default)]
+pub struct CapturedLogs {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl CapturedLogs {
+    /// Instala o subscriber JSON apenas na thread atual (os testes tokio rodam em uma só thread).
+    pub fn install() -> (Self, DefaultGuard) {
+        let logs = Self::default();
+        let config = LogConfig {
+            level: Level::INFO,
+            format: LogFormat::Json,
+        };
+        let guard =
+            tracing::subscriber::set_default(logging::subscriber(&config, logs.clone(), None));
+
+        (logs, guard)
+    }
+
+    pub fn lines(&self) -> Vec<Value> {
+        let buffer = self.buffer.lock().unwrap();
+        String::from_utf8_lossy(&buffer)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    pub fn find(&self, message: &str) -> Value {
+        self.lines()
+            .into_iter()
+            .find(|line| line["message"] == message)
+            .unwrap_or_else(|| panic!("no log line with message {:?}", message))
+    }
+}
+
+impl Write for CapturedLogs {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.buffer.lock().unwrap().extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'writer> MakeWriter<'writer> for CapturedLogs {
+    type Writer = Self;
+
+    fn make_writer(&'writer self) -> Self::Writer {
+        self.clone()
+    }
+}
@@ -0,0 +1,2 @@
+// Utilitários usados por mais de um binário de teste (api_test e service_test)
+pub mod captured_logs;
@@ -0,0 +1,73 @@
+use axum_api_starter::util::deprecation::{Deprecation, find_deprecation, parse_deprecations};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_parse_entries_and_render_standard_headers() {
+        let deprecations = parse_deprecations(
+            "/v1;deprecated=2026-10-01;sunset=2027-04-01;link=https://docs.example.com/v2, \
+             /v1/users/purge/;deprecated=2026-09-01T12:00:00Z",
+        )
+        .unwrap();
+
+        assert_eq!(deprecations.len(), 2);
+        let version = &deprecations[0];
+        assert_eq!(version.route_prefix, "/v1");
+        assert_eq!(version.deprecation_header(), "@1790812800");
+        assert_eq!(
+            version.sunset_header().as_deref(),
+            Some("Thu, 01 Apr 2027 00:00:00 GMT")
+        );
+        assert_eq!(
+            version.link_header().as_deref(),
+            Some("<https://docs.example.com/v2>; rel=\"deprecation\"; type=\"text/html\"")
+        );
+
+        // Barra final é ignorada e sunset é opcional
+        let route = &deprecations[1];
+        assert_eq!(route.route_prefix, "/v1/users/purge");
+        assert_eq!(route.sunset_header(), None);
+
+        assert!(parse_deprecations("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_reject_incomplete_or_inconsistent_entries() {
+        let cases = [
+            ("v1;deprecated=2026-10-01", "must start with a route"),
+            ("/v1", "needs a deprecated=<date>"),
+            ("/v1;deprecated=01/10/2026", "invalid date"),
+            ("/v1;deprecated=2026-10-01;retired=yes", "unknown parameter"),
+            (
+                "/v1;deprecated=2026-10-01;sunset=2026-09-01",
+                "must be after",
+            ),
+        ];
+
+        for (entry, expected) in cases {
+            let error = Deprecation::parse(entry).unwrap_err();
+            assert!(error.contains(expected), "{}: {}", entry, error);
+        }
+    }
+
+    #[test]
+    fn should_match_route_templates_by_segment_and_prefer_the_most_specific() {
+        let deprecations = parse_deprecations(
+            "/v1;deprecated=2026-10-01, /v1/users/{id}/suspend;deprecated=2026-06-01",
+        )
+        .unwrap();
+
+        let version = find_deprecation(&deprecations, "/v1/users/{id}").unwrap();
+        assert_eq!(version.route_prefix, "/v1");
+
+        let route = find_deprecation(&deprecations, "/v1/users/{id}/suspend").unwrap();
+        assert_eq!(route.route_prefix, "/v1/users/{id}/suspend");
+
+        assert!(find_deprecation(&deprecations, "/v10/users").is_none());
+        assert!(find_deprecation(&deprecations, "/health/live").is_none());
+    }
+}
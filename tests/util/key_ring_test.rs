@@ -0,0 +1,187 @@
+use axum_api_starter::model::token_model::AccessTokenClaims;
+use axum_api_starter::util::clock::{Clock, FakeClock};
+use axum_api_starter::util::key_ring::KeyRing;
+use axum_api_starter::util::secret::Secret;
+use axum_api_starter::util::token_issuer::{TokenConfig, TokenIssuer};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use jsonwebtoken::decode_header;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROTATION_SECONDS: i64 = 3600;
+    const TTL_SECONDS: i64 = 900;
+
+    // --- Helpers ---
+
+    fn setup_clock() -> FakeClock {
+        FakeClock::new(Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap())
+    }
+
+    fn setup_key_ring(clock: &FakeClock) -> KeyRing {
+        setup_key_ring_with_secret(clock, None)
+    }
+
+    fn setup_key_ring_with_secret(clock: &FakeClock, secret: Option<&str>) -> KeyRing {
+        KeyRing::new(
+            TokenConfig {
+                issuer: "key-ring-test".to_string(),
+                access_token_ttl_seconds: TTL_SECONDS,
+                signing_key_rotation_seconds: ROTATION_SECONDS,
+                signing_secret: secret.map(|secret| Secret::new(secret.to_string())),
+            },
+            clock.clone(),
+        )
+        .unwrap()
+    }
+
+    fn published_kids(key_ring: &KeyRing) -> Vec<String> {
+        key_ring
+            .public_jwks()
+            .unwrap()
+            .keys
+            .into_iter()
+            .map(|key| key.kid)
+            .collect()
+    }
+
+    fn claims_issued_at(issued_at: DateTime<Utc>) -> AccessTokenClaims {
+        AccessTokenClaims {
+            iss: "key-ring-test".to_string(),
+            sub: "service-account".to_string(),
+            client_id: Some("service-account".to_string()),
+            scope: "users:read".to_string(),
+            iat: issued_at.timestamp(),
+            exp: (issued_at + Duration::seconds(TTL_SECONDS)).timestamp(),
+            jti: "token-id".to_string(),
+        }
+    }
+
+    fn kid(token: &str) -> String {
+        decode_header(token).unwrap().kid.unwrap()
+    }
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_sign_with_a_published_key() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+
+        let token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+
+        let jwks = key_ring.public_jwks().unwrap();
+        assert!(jwks.keys.iter().any(|key| key.kid == kid(&token)));
+        assert_eq!(key_ring.verify(&token).unwrap().sub, "service-account");
+    }
+
+    #[test]
+    fn should_switch_to_the_prepublished_key_after_rotation() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+
+        // Último minuto da janela da primeira chave
+        clock.advance(Duration::seconds(ROTATION_SECONDS - 60));
+        key_ring.rotate().unwrap();
+        let old_token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+        let next_kid_before_rotation: Vec<String> = key_ring
+            .public_jwks()
+            .unwrap()
+            .keys
+            .into_iter()
+            .map(|key| key.kid)
+            .filter(|published| *published != kid(&old_token))
+            .collect();
+
+        clock.advance(Duration::seconds(120));
+        key_ring.rotate().unwrap();
+        let new_token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+
+        assert_ne!(kid(&new_token), kid(&old_token));
+        // A chave nova já estava no JWKS antes de começar a assinar
+        assert_eq!(next_kid_before_rotation, vec![kid(&new_token)]);
+        // Tokens da chave aposentada (mas não expirada) continuam válidos
+        assert!(key_ring.verify(&old_token).is_ok());
+        assert!(key_ring.verify(&new_token).is_ok());
+    }
+
+    #[test]
+    fn should_drop_keys_once_their_tokens_cannot_be_valid() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+        let old_token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+
+        clock.advance(Duration::seconds(ROTATION_SECONDS + TTL_SECONDS + 1));
+        key_ring.rotate().unwrap();
+
+        let jwks = key_ring.public_jwks().unwrap();
+        assert!(jwks.keys.iter().all(|key| key.kid != kid(&old_token)));
+        assert!(key_ring.verify(&old_token).is_err());
+    }
+
+    #[test]
+    fn should_reject_tokens_once_the_clock_passes_their_expiry() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+        let token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+
+        clock.advance(Duration::seconds(TTL_SECONDS - 1));
+        assert!(key_ring.verify(&token).is_ok());
+
+        clock.advance(Duration::seconds(1));
+        assert!(key_ring.verify(&token).is_err());
+    }
+
+    #[test]
+    fn should_report_no_active_key_when_rotation_stops() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+        assert!(key_ring.check_active_key().is_ok());
+
+        // A próxima chave cobre só mais um período; sem rotação, nada assina depois dele
+        clock.advance(Duration::seconds(2 * ROTATION_SECONDS));
+        assert_eq!(
+            key_ring.check_active_key().unwrap_err(),
+            "No active signing key"
+        );
+
+        key_ring.rotate().unwrap();
+        assert!(key_ring.check_active_key().is_ok());
+    }
+
+    #[test]
+    fn should_reject_tokens_signed_by_another_key_ring() {
+        let clock = setup_clock();
+        let key_ring = setup_key_ring(&clock);
+        let foreign_token = setup_key_ring(&clock)
+            .issue(&claims_issued_at(clock.now()))
+            .unwrap();
+
+        assert!(key_ring.verify(&foreign_token).is_err());
+    }
+
+    #[test]
+    fn should_derive_the_same_keys_from_the_same_secret() {
+        const SECRET: &str = "0123456789abcdef0123456789abcdef";
+        let clock = setup_clock();
+        let key_ring = setup_key_ring_with_secret(&clock, Some(SECRET));
+        let token = key_ring.issue(&claims_issued_at(clock.now())).unwrap();
+
+        // Uma réplica (ou o mesmo processo reiniciado) no meio da janela
+        clock.advance(Duration::seconds(TTL_SECONDS / 2));
+        let replica = setup_key_ring_with_secret(&clock, Some(SECRET));
+        assert_eq!(published_kids(&replica), published_kids(&key_ring));
+        assert_eq!(replica.verify(&token).unwrap().sub, "service-account");
+
+        // As duas rotacionam para a mesma chave seguinte
+        clock.advance(Duration::seconds(ROTATION_SECONDS));
+        key_ring.rotate().unwrap();
+        replica.rotate().unwrap();
+        let rotated_token = replica.issue(&claims_issued_at(clock.now())).unwrap();
+        assert!(key_ring.verify(&rotated_token).is_ok());
+
+        let other_secret = setup_key_ring_with_secret(&clock, Some(&SECRET.repeat(2)));
+        assert!(other_secret.verify(&rotated_token).is_err());
+    }
+}
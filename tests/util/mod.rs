@@ -0,0 +1,6 @@
+pub mod key_ring_test;
+pub mod uuid_generator_test;
+pub mod secret_test;
+pub mod request_id_test;
+pub mod deprecation_test;
+pub mod oidc_test;
@@ -0,0 +1,96 @@
+use axum_api_starter::util::clock::FakeClock;
+use axum_api_starter::util::oidc::verify_id_token;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde_json::json;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "https://id.example.com";
+    const CLIENT_ID: &str = "oidc-test";
+    const NONCE: &str = "nonce-123";
+
+    // --- Helpers ---
+
+    /// Token emitido há anos, bem antes do relógio do sistema, com validade de 5 minutos.
+    fn issued_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap()
+    }
+
+    fn setup_signed_token() -> (String, JwkSet) {
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some("test-key".to_string());
+
+        let id_token = encode(
+            &header,
+            &json!({
+                "iss": ISSUER,
+                "sub": "subject-1",
+                "aud": CLIENT_ID,
+                "iat": issued_at().timestamp(),
+                "exp": (issued_at() + Duration::minutes(5)).timestamp(),
+                "nonce": NONCE,
+            }),
+            &EncodingKey::from_ed_der(pkcs8.as_ref()),
+        )
+        .unwrap();
+
+        let jwks = serde_json::from_value(json!({
+            "keys": [{
+                "kty": "OKP",
+                "crv": "Ed25519",
+                "use": "sig",
+                "alg": "EdDSA",
+                "kid": "test-key",
+                "x": URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref()),
+            }]
+        }))
+        .unwrap();
+
+        (id_token, jwks)
+    }
+
+    fn verify(id_token: &str, jwks: &JwkSet, clock: &FakeClock) -> Result<String, String> {
+        verify_id_token(id_token, jwks, ISSUER, CLIENT_ID, NONCE, clock).map(|claims| claims.sub)
+    }
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_check_expiry_against_the_injected_clock() {
+        let (id_token, jwks) = setup_signed_token();
+        let clock = FakeClock::new(issued_at() + Duration::minutes(1));
+
+        // Expirado pelo relógio do sistema, mas válido pelo relógio injetado
+        assert_eq!(verify(&id_token, &jwks, &clock).unwrap(), "subject-1");
+
+        // A mesma tolerância de 60s do jsonwebtoken para diferença de relógio com o issuer
+        clock.set(issued_at() + Duration::minutes(6));
+        assert!(verify(&id_token, &jwks, &clock).is_ok());
+
+        clock.set(issued_at() + Duration::minutes(6) + Duration::seconds(1));
+        let error = verify(&id_token, &jwks, &clock).unwrap_err();
+        assert!(error.contains("Expired"), "{}", error);
+    }
+
+    #[test]
+    fn should_reject_a_token_for_another_nonce() {
+        let (id_token, jwks) = setup_signed_token();
+        let clock = FakeClock::new(issued_at());
+
+        let error = verify_id_token(&id_token, &jwks, ISSUER, CLIENT_ID, "other-nonce", &clock)
+            .unwrap_err();
+
+        assert_eq!(error, "ID token nonce mismatch");
+    }
+}
@@ -0,0 +1,33 @@
+use axum_api_starter::util::request_id::{
+    MAX_REQUEST_ID_LENGTH, current_request_id, is_valid_request_id, scope,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_accept_only_short_header_safe_request_ids() {
+        assert!(is_valid_request_id("0192f1c4-7d1e-7c52-9a4b-3f1f0e6c2d11"));
+        assert!(is_valid_request_id("01J9Z3KX5QW8D2R7M4N6P0T1VB"));
+        assert!(is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LENGTH)));
+
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LENGTH + 1)));
+        assert!(!is_valid_request_id("two words"));
+        assert!(!is_valid_request_id("line\nbreak"));
+        assert!(!is_valid_request_id("ação"));
+    }
+
+    #[tokio::test]
+    async fn should_expose_request_id_only_inside_its_scope() {
+        assert_eq!(current_request_id(), None);
+
+        let inside = scope("req-1".to_string(), async { current_request_id() }).await;
+
+        assert_eq!(inside, Some("req-1".to_string()));
+        assert_eq!(current_request_id(), None);
+    }
+}
@@ -0,0 +1,69 @@
+use axum_api_starter::model::email_model::Email;
+use axum_api_starter::model::user_model::{User, UserForCreation, UserId, UserRole, UserStatus};
+use axum_api_starter::util::secret::{Secret, expose};
+use chrono::Utc;
+use serde::Serialize;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    // Campo que opta explicitamente por enviar o segredo, como uma credencial exibida uma única vez
+    #[derive(Serialize)]
+    struct Handover {
+        #[serde(serialize_with = "expose")]
+        secret: Secret<String>,
+    }
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_redact_debug_and_display() {
+        let secret = Secret::new("hunter2".to_string());
+
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(format!("{}", secret), "[REDACTED]");
+        assert_eq!(secret.expose(), "hunter2");
+    }
+
+    #[test]
+    fn should_keep_passwords_out_of_debug_output() {
+        let user = User {
+            id: UserId::from("user-1"),
+            email: Email::parse("user@example.com").unwrap(),
+            password: Secret::new("$2b$04$hash".to_string()),
+            role: UserRole::User,
+            status: UserStatus::Active,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+        };
+
+        let debug = format!("{:?}", user);
+        assert!(debug.contains("password: [REDACTED]"));
+        assert!(!debug.contains("$2b$04$hash"));
+    }
+
+    #[test]
+    fn should_deserialize_plain_values_and_serialize_only_on_opt_in() {
+        let user_for_creation: UserForCreation = serde_json::from_value(serde_json::json!({
+            "email": "user@example.com",
+            "password": "password123",
+            "role": "User"
+        }))
+        .unwrap();
+        assert_eq!(user_for_creation.password.expose(), "password123");
+        assert!(!format!("{:?}", user_for_creation).contains("password123"));
+
+        let handover = Handover {
+            secret: Secret::new("client-secret".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_value(&handover).unwrap(),
+            serde_json::json!({ "secret": "client-secret" })
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use axum_api_starter::util::uuid_generator::{
+    IdStrategy, UlidGenerator, UuidGenerator, UuidV7Generator,
+};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // --- Helpers ---
+
+    // Gera um id, espera o relógio avançar e gera outro
+    fn generate_pair(generator: &dyn UuidGenerator) -> (String, String) {
+        let first = generator.generate().unwrap();
+        std::thread::sleep(Duration::from_millis(2));
+        let second = generator.generate().unwrap();
+        (first, second)
+    }
+
+    // --- Casos de Teste ---
+
+    #[test]
+    fn should_generate_time_ordered_uuid_v7() {
+        let (first, second) = generate_pair(&UuidV7Generator);
+
+        let parsed = uuid::Uuid::parse_str(&first).unwrap();
+        assert_eq!(parsed.get_version_num(), 7);
+        assert!(first < second);
+    }
+
+    #[test]
+    fn should_generate_time_ordered_ulid() {
+        let (first, second) = generate_pair(&UlidGenerator);
+
+        assert_eq!(first.len(), 26);
+        assert!(
+            first
+                .chars()
+                .all(|c| "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(c))
+        );
+        // O primeiro caractere só carrega 3 bits do timestamp
+        assert!(first.as_bytes()[0] <= b'7');
+        assert!(first < second);
+        assert_ne!(first, UlidGenerator.generate().unwrap());
+    }
+
+    #[test]
+    fn should_pick_generator_from_strategy() {
+        let uuid_v4 = IdStrategy::UuidV4.generator().generate().unwrap();
+        assert_eq!(
+            uuid::Uuid::parse_str(&uuid_v4).unwrap().get_version_num(),
+            4
+        );
+
+        let uuid_v7 = IdStrategy::UuidV7.generator().generate().unwrap();
+        assert_eq!(
+            uuid::Uuid::parse_str(&uuid_v7).unwrap().get_version_num(),
+            7
+        );
+
+        assert_eq!(IdStrategy::Ulid.generator().generate().unwrap().len(), 26);
+    }
+}